@@ -0,0 +1,587 @@
+#![no_std]
+
+use soroban_sdk::{
+    contract, contracterror, contractevent, contractimpl, contracttype, panic_with_error, token,
+    Address, Env, Vec,
+};
+
+/// Game Hub contract
+///
+/// A working implementation of the interface games report to (start_game,
+/// end_game, draw_game, abort_game), unlike `mock-game-hub`. The admin
+/// registers each game contract, with the token its points are paid in or
+/// none to only track its sessions. Starting a session escrows both
+/// players' points here; only the game that started a session may end it,
+/// paying the pot to the winner or refunding both players.
+///
+/// Escrow pulls each player's points with a token transfer, so both
+/// players must authorize the game call that starts the session, as they
+/// do for my-game's `start_room`. A game that also starts sessions in a
+/// call only one player signs, such as my-game's `enter_queue` and
+/// `accept_challenge`, must be registered without a token; my-game checks
+/// `get_game_token` and refuses those calls otherwise.
+///
+/// Session IDs are per game, so two games may each run a session 1. The
+/// standard `end_game`, `draw_game` and `abort_game` carry no game
+/// address and settle the only open session with that ID; while several
+/// games have it open, the game settles through `end_session`,
+/// `draw_session` or `abort_session` instead. my-game always does, once
+/// the hub is set to its `Session` kind.
+#[contract]
+pub struct GameHub;
+
+const DAY_IN_LEDGERS: u32 = 17_280;
+const TTL_BUMP: u32 = 30 * DAY_IN_LEDGERS;
+const TTL_THRESHOLD: u32 = TTL_BUMP - DAY_IN_LEDGERS;
+
+#[contracterror]
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum Error {
+    /// Calling game is not registered
+    GameNotRegistered = 1,
+    /// Session with that ID is already running
+    SessionExists = 2,
+    /// Session not found
+    SessionNotFound = 3,
+    /// Points cannot be negative
+    InvalidAmount = 4,
+    /// Several games have a session with that ID open; name the game
+    AmbiguousSession = 5,
+}
+
+/// A running game session and the points escrowed for it.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Session {
+    /// Game contract that started the session, the only one that may end it.
+    pub game: Address,
+    /// Token the points are escrowed in; `None` when the game's sessions
+    /// are only tracked.
+    pub token: Option<Address>,
+    pub player1: Address,
+    pub player2: Address,
+    pub player1_points: i128,
+    pub player2_points: i128,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Admin,
+    /// Game(game) → Option<Address> (token the game's points are paid
+    /// in, if escrowed)
+    Game(Address),
+    /// Session(game, session_id) → Session (persistent, removed once
+    /// settled)
+    Session(Address, u32),
+    /// OpenSessions(session_id) → Vec<Address> (games with that session
+    /// open, persistent)
+    OpenSessions(u32),
+}
+
+#[contractevent]
+pub struct GameStarted {
+    pub session_id: u32,
+    pub game_id: Address,
+    pub player1: Address,
+    pub player2: Address,
+    pub player1_points: i128,
+    pub player2_points: i128,
+}
+
+#[contractevent]
+pub struct GameEnded {
+    pub session_id: u32,
+    pub game_id: Address,
+    pub player1_won: bool,
+}
+
+#[contractevent]
+pub struct GameDrawn {
+    pub session_id: u32,
+    pub game_id: Address,
+}
+
+#[contractevent]
+pub struct GameAborted {
+    pub session_id: u32,
+    pub game_id: Address,
+}
+
+#[contractimpl]
+impl GameHub {
+    pub fn __constructor(env: Env, admin: Address) {
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().extend_ttl(TTL_THRESHOLD, TTL_BUMP);
+    }
+
+    /// Let `game` start sessions, with points escrowed in `token`, or only
+    /// tracked when `None`. Admin only.
+    pub fn register_game(env: Env, game: Address, token: Option<Address>) {
+        Self::require_admin(&env);
+        env.storage().instance().set(&DataKey::Game(game), &token);
+        env.storage().instance().extend_ttl(TTL_THRESHOLD, TTL_BUMP);
+    }
+
+    /// Stop `game` from starting sessions. Its running sessions can still
+    /// be ended. Admin only.
+    pub fn unregister_game(env: Env, game: Address) {
+        Self::require_admin(&env);
+        env.storage().instance().remove(&DataKey::Game(game));
+        env.storage().instance().extend_ttl(TTL_THRESHOLD, TTL_BUMP);
+    }
+
+    /// Start a game session, escrowing both players' points if the game
+    /// is registered with a token. Escrow needs both players' authorization
+    /// of the calling game's invocation.
+    ///
+    /// # Arguments
+    /// * `game_id` - Address of the game contract calling this method
+    /// * `session_id` - Unique identifier for this game session
+    /// * `player1` - Address of first player
+    /// * `player2` - Address of second player
+    /// * `player1_points` - Points player 1 puts up
+    /// * `player2_points` - Points player 2 puts up
+    pub fn start_game(
+        env: Env,
+        game_id: Address,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+    ) {
+        game_id.require_auth();
+
+        let token: Option<Address> = env
+            .storage()
+            .instance()
+            .get(&DataKey::Game(game_id.clone()))
+            .unwrap_or_else(|| panic_with_error!(&env, Error::GameNotRegistered));
+        let key = DataKey::Session(game_id.clone(), session_id);
+        if env.storage().persistent().has(&key) {
+            panic_with_error!(&env, Error::SessionExists);
+        }
+        if player1_points < 0 || player2_points < 0 {
+            panic_with_error!(&env, Error::InvalidAmount);
+        }
+
+        if let Some(token) = &token {
+            let client = token::Client::new(&env, token);
+            for (player, points) in [(&player1, player1_points), (&player2, player2_points)] {
+                if points > 0 {
+                    client.transfer(player, env.current_contract_address(), &points);
+                }
+            }
+        }
+
+        let session = Session {
+            game: game_id.clone(),
+            token,
+            player1: player1.clone(),
+            player2: player2.clone(),
+            player1_points,
+            player2_points,
+        };
+        env.storage().persistent().set(&key, &session);
+        env.storage().persistent().extend_ttl(&key, TTL_THRESHOLD, TTL_BUMP);
+        let mut games = Self::open_sessions(&env, session_id);
+        games.push_back(game_id.clone());
+        Self::set_open_sessions(&env, session_id, &games);
+        env.storage().instance().extend_ttl(TTL_THRESHOLD, TTL_BUMP);
+
+        GameStarted {
+            session_id,
+            game_id,
+            player1,
+            player2,
+            player1_points,
+            player2_points,
+        }
+        .publish(&env);
+    }
+
+    /// End a game session and pay both players' points to the winner
+    ///
+    /// # Arguments
+    /// * `session_id` - The game session being ended
+    /// * `player1_won` - True if player1 won, false if player2 won
+    pub fn end_game(env: Env, session_id: u32, player1_won: bool) {
+        let game_id = Self::only_open_game(&env, session_id);
+        Self::end_session(env, game_id, session_id, player1_won);
+    }
+
+    /// End a game session with no winner, refunding both players
+    ///
+    /// # Arguments
+    /// * `session_id` - The game session being ended
+    pub fn draw_game(env: Env, session_id: u32) {
+        let game_id = Self::only_open_game(&env, session_id);
+        Self::draw_session(env, game_id, session_id);
+    }
+
+    /// Void a game session that never got under way, refunding both
+    /// players
+    ///
+    /// # Arguments
+    /// * `session_id` - The game session being aborted
+    pub fn abort_game(env: Env, session_id: u32) {
+        let game_id = Self::only_open_game(&env, session_id);
+        Self::abort_session(env, game_id, session_id);
+    }
+
+    /// `end_game` for `game_id`'s session, whichever other games share
+    /// its ID.
+    pub fn end_session(env: Env, game_id: Address, session_id: u32, player1_won: bool) {
+        let session = Self::take_session(&env, &game_id, session_id);
+        let winner = if player1_won {
+            &session.player1
+        } else {
+            &session.player2
+        };
+        let pot = session.player1_points + session.player2_points;
+        Self::pay(&env, &session.token, winner, pot);
+
+        GameEnded {
+            session_id,
+            game_id,
+            player1_won,
+        }
+        .publish(&env);
+    }
+
+    /// `draw_game` for `game_id`'s session.
+    pub fn draw_session(env: Env, game_id: Address, session_id: u32) {
+        Self::refund(&env, &Self::take_session(&env, &game_id, session_id));
+        GameDrawn {
+            session_id,
+            game_id,
+        }
+        .publish(&env);
+    }
+
+    /// `abort_game` for `game_id`'s session.
+    pub fn abort_session(env: Env, game_id: Address, session_id: u32) {
+        Self::refund(&env, &Self::take_session(&env, &game_id, session_id));
+        GameAborted {
+            session_id,
+            game_id,
+        }
+        .publish(&env);
+    }
+
+    /// The token `game`'s points are escrowed in, or `None` when its
+    /// sessions are only tracked.
+    pub fn get_game_token(env: Env, game: Address) -> Option<Address> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Game(game))
+            .unwrap_or_else(|| panic_with_error!(&env, Error::GameNotRegistered))
+    }
+
+    pub fn get_session(env: Env, game_id: Address, session_id: u32) -> Session {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Session(game_id, session_id))
+            .unwrap_or_else(|| panic_with_error!(&env, Error::SessionNotFound))
+    }
+
+    /// The game whose session `session_id` the standard entrypoints
+    /// settle: the only one with it open.
+    fn only_open_game(env: &Env, session_id: u32) -> Address {
+        let games = Self::open_sessions(env, session_id);
+        match games.len() {
+            0 => panic_with_error!(env, Error::SessionNotFound),
+            1 => games.get(0).unwrap(),
+            _ => panic_with_error!(env, Error::AmbiguousSession),
+        }
+    }
+
+    /// Remove a running session once the game that started it authorizes
+    /// its end.
+    fn take_session(env: &Env, game_id: &Address, session_id: u32) -> Session {
+        let session = Self::get_session(env.clone(), game_id.clone(), session_id);
+        session.game.require_auth();
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Session(game_id.clone(), session_id));
+        let mut games = Self::open_sessions(env, session_id);
+        if let Some(i) = games.first_index_of(game_id) {
+            games.remove(i);
+        }
+        Self::set_open_sessions(env, session_id, &games);
+        session
+    }
+
+    fn open_sessions(env: &Env, session_id: u32) -> Vec<Address> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::OpenSessions(session_id))
+            .unwrap_or_else(|| Vec::new(env))
+    }
+
+    fn set_open_sessions(env: &Env, session_id: u32, games: &Vec<Address>) {
+        let key = DataKey::OpenSessions(session_id);
+        if games.is_empty() {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, games);
+            env.storage().persistent().extend_ttl(&key, TTL_THRESHOLD, TTL_BUMP);
+        }
+    }
+
+    fn refund(env: &Env, session: &Session) {
+        Self::pay(env, &session.token, &session.player1, session.player1_points);
+        Self::pay(env, &session.token, &session.player2, session.player2_points);
+    }
+
+    fn pay(env: &Env, token: &Option<Address>, to: &Address, amount: i128) {
+        if let Some(token) = token {
+            if amount > 0 {
+                let contract = env.current_contract_address();
+                token::Client::new(env, token).transfer(&contract, to, &amount);
+            }
+        }
+    }
+
+    fn require_admin(env: &Env) {
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::testutils::{Address as _, MockAuth, MockAuthInvoke};
+    use soroban_sdk::token::{StellarAssetClient, TokenClient};
+    use soroban_sdk::{IntoVal, Val};
+
+    /// A game that starts sessions the two ways my-game does.
+    #[contract]
+    pub struct TestGame;
+
+    #[contractimpl]
+    impl TestGame {
+        /// Both players sign, as for my-game's `start_room`.
+        pub fn start(
+            env: Env,
+            hub: Address,
+            session_id: u32,
+            player1: Address,
+            player2: Address,
+            player1_points: i128,
+            player2_points: i128,
+        ) {
+            player1.require_auth();
+            player2.require_auth();
+            GameHubClient::new(&env, &hub).start_game(
+                &env.current_contract_address(),
+                &session_id,
+                &player1,
+                &player2,
+                &player1_points,
+                &player2_points,
+            );
+        }
+
+        /// Only `player2` signs; `player1` consented earlier, as when
+        /// my-game matches a queued player.
+        pub fn start_matched(
+            env: Env,
+            hub: Address,
+            session_id: u32,
+            player1: Address,
+            player2: Address,
+            points: i128,
+        ) {
+            player2.require_auth();
+            GameHubClient::new(&env, &hub).start_game(
+                &env.current_contract_address(),
+                &session_id,
+                &player1,
+                &player2,
+                &points,
+                &points,
+            );
+        }
+    }
+
+    fn setup() -> (Env, GameHubClient<'static>, TokenClient<'static>, Address, Address, Address) {
+        let env = Env::default();
+        env.mock_all_auths();
+        let admin = Address::generate(&env);
+        let contract_id = env.register(GameHub, (&admin,));
+        let client = GameHubClient::new(&env, &contract_id);
+
+        let sac = env.register_stellar_asset_contract_v2(admin);
+        let token = TokenClient::new(&env, &sac.address());
+        let game = env.register(TestGame, ());
+        client.register_game(&game, &Some(token.address.clone()));
+
+        let player1 = Address::generate(&env);
+        let player2 = Address::generate(&env);
+        let mint = StellarAssetClient::new(&env, &token.address);
+        mint.mint(&player1, &1000);
+        mint.mint(&player2, &1000);
+        (env, client, token, game, player1, player2)
+    }
+
+    #[test]
+    fn test_start_and_end_game() {
+        let (env, client, token, game, player1, player2) = setup();
+        let game_client = TestGameClient::new(&env, &game);
+        game_client.start(&client.address, &1, &player1, &player2, &100, &300);
+        assert_eq!(token.balance(&client.address), 400);
+        assert_eq!(client.get_session(&game, &1).player2_points, 300);
+
+        let res = game_client.try_start(&client.address, &1, &player1, &player2, &100, &300);
+        assert_eq!(res, Err(Ok(Error::SessionExists.into())));
+
+        client.end_game(&1, &true);
+        assert_eq!(token.balance(&player1), 1300);
+        assert_eq!(token.balance(&player2), 700);
+        assert_eq!(client.try_get_session(&game, &1), Err(Ok(Error::SessionNotFound.into())));
+        assert_eq!(client.try_end_game(&1, &true), Err(Ok(Error::SessionNotFound.into())));
+    }
+
+    #[test]
+    fn test_sessions_are_per_game() {
+        let (env, client, token, game, player1, player2) = setup();
+        let other = env.register(TestGame, ());
+        client.register_game(&other, &Some(token.address.clone()));
+
+        // Both games number their sessions from 1.
+        TestGameClient::new(&env, &game).start(&client.address, &1, &player1, &player2, &100, &100);
+        TestGameClient::new(&env, &other).start(&client.address, &1, &player1, &player2, &200, &200);
+        assert_eq!(token.balance(&client.address), 600);
+        let res = client.try_end_game(&1, &true);
+        assert_eq!(res, Err(Ok(Error::AmbiguousSession.into())));
+
+        client.end_session(&other, &1, &false);
+        assert_eq!(token.balance(&player2), 1100);
+        assert_eq!(client.get_session(&game, &1).player1_points, 100);
+
+        // With one left open, the standard entrypoint settles it.
+        client.draw_game(&1);
+        assert_eq!(token.balance(&player1), 800);
+        assert_eq!(token.balance(&client.address), 0);
+    }
+
+    #[test]
+    fn test_draw_and_abort_refund() {
+        let (env, client, token, game, player1, player2) = setup();
+        let game_client = TestGameClient::new(&env, &game);
+        game_client.start(&client.address, &1, &player1, &player2, &100, &300);
+        client.draw_game(&1);
+        game_client.start(&client.address, &2, &player1, &player2, &50, &0);
+        client.abort_game(&2);
+        assert_eq!(token.balance(&player1), 1000);
+        assert_eq!(token.balance(&player2), 1000);
+        assert_eq!(token.balance(&client.address), 0);
+    }
+
+    #[test]
+    fn test_unregistered_game_rejected() {
+        let (env, client, _token, game, player1, player2) = setup();
+        let other = Address::generate(&env);
+        let res = client.try_start_game(&other, &1, &player1, &player2, &100, &100);
+        assert_eq!(res, Err(Ok(Error::GameNotRegistered.into())));
+
+        client.unregister_game(&game);
+        let res = client.try_start_game(&game, &1, &player1, &player2, &100, &100);
+        assert_eq!(res, Err(Ok(Error::GameNotRegistered.into())));
+        let res = client.try_get_game_token(&game);
+        assert_eq!(res, Err(Ok(Error::GameNotRegistered.into())));
+    }
+
+    /// Only the signatures a real transaction would carry: escrow fails
+    /// when one player is not signing, and tracking needs no player at all.
+    #[test]
+    fn test_escrow_needs_both_players() {
+        let (env, client, token, game, player1, player2) = setup();
+        let tracked = env.register(TestGame, ());
+        client.register_game(&tracked, &None);
+        let hub = client.address.clone();
+
+        let matched_by_player2 = |game: &Address, session_id: u32| {
+            let args = (&hub, session_id, &player1, &player2, 100_i128).into_val(&env);
+            let transfer = (&player2, &hub, 100_i128).into_val(&env);
+            let escrow = [MockAuthInvoke {
+                contract: &token.address,
+                fn_name: "transfer",
+                args: transfer,
+                sub_invokes: &[],
+            }];
+            let sub_invokes: &[MockAuthInvoke] = if *game == tracked { &[] } else { &escrow };
+            env.mock_auths(&[MockAuth {
+                address: &player2,
+                invoke: &MockAuthInvoke {
+                    contract: game,
+                    fn_name: "start_matched",
+                    args,
+                    sub_invokes,
+                },
+            }]);
+            TestGameClient::new(&env, game).try_start_matched(
+                &hub,
+                &session_id,
+                &player1,
+                &player2,
+                &100,
+            )
+        };
+
+        // player1 never signed, so their points cannot be pulled.
+        assert!(matched_by_player2(&game, 1).is_err());
+        assert_eq!(token.balance(&hub), 0);
+
+        // A tracked game starts the same session without escrow.
+        assert_eq!(client.get_game_token(&game), Some(token.address.clone()));
+        assert_eq!(client.get_game_token(&tracked), None);
+        assert!(matched_by_player2(&tracked, 1).is_ok());
+        let session = client.get_session(&tracked, &1);
+        assert_eq!(session.token, None);
+        assert_eq!(token.balance(&player1), 1000);
+
+        // With both players signing, escrow goes through.
+        let start_args: Vec<Val> =
+            (&hub, 2_u32, &player1, &player2, 100_i128, 100_i128).into_val(&env);
+        let transfer = |player: &Address| -> Vec<Val> { (player, &hub, 100_i128).into_val(&env) };
+        let escrow1 = [MockAuthInvoke {
+            contract: &token.address,
+            fn_name: "transfer",
+            args: transfer(&player1),
+            sub_invokes: &[],
+        }];
+        let escrow2 = [MockAuthInvoke {
+            contract: &token.address,
+            fn_name: "transfer",
+            args: transfer(&player2),
+            sub_invokes: &[],
+        }];
+        env.mock_auths(&[
+            MockAuth {
+                address: &player1,
+                invoke: &MockAuthInvoke {
+                    contract: &game,
+                    fn_name: "start",
+                    args: start_args.clone(),
+                    sub_invokes: &escrow1,
+                },
+            },
+            MockAuth {
+                address: &player2,
+                invoke: &MockAuthInvoke {
+                    contract: &game,
+                    fn_name: "start",
+                    args: start_args,
+                    sub_invokes: &escrow2,
+                },
+            },
+        ]);
+        TestGameClient::new(&env, &game).start(&hub, &2, &player1, &player2, &100, &100);
+        assert_eq!(token.balance(&hub), 200);
+    }
+}
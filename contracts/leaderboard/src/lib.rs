@@ -0,0 +1,214 @@
+#![no_std]
+
+use soroban_sdk::{
+    contract, contracterror, contractimpl, contracttype, panic_with_error, Address, Env, Vec,
+};
+
+/// Leaderboard contract for Pirate's Treasure
+///
+/// Keeps every player's wins and points won, as reported by the one game
+/// contract the admin has registered, and the top `TOP_N` players ranked
+/// by each. Frontends read the rankings directly.
+#[contract]
+pub struct Leaderboard;
+
+const DAY_IN_LEDGERS: u32 = 17_280;
+const TTL_BUMP: u32 = 30 * DAY_IN_LEDGERS;
+const TTL_THRESHOLD: u32 = TTL_BUMP - DAY_IN_LEDGERS;
+
+/// Length of each ranking.
+pub const TOP_N: u32 = 10;
+
+#[contracterror]
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum Error {
+    /// No game contract registered yet
+    GameNotSet = 1,
+}
+
+/// One player's record.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Entry {
+    pub player: Address,
+    pub wins: u32,
+    /// Sum of the opponent's points over every win.
+    pub points: i128,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Admin,
+    /// Game contract allowed to record results
+    Game,
+    /// Entry(player) → Entry (persistent)
+    Entry(Address),
+    /// Top players by wins → Vec<Entry>
+    TopByWins,
+    /// Top players by points → Vec<Entry>
+    TopByPoints,
+}
+
+#[contractimpl]
+impl Leaderboard {
+    pub fn __constructor(env: Env, admin: Address) {
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().extend_ttl(TTL_THRESHOLD, TTL_BUMP);
+    }
+
+    /// Register the game contract whose results are recorded. Admin only.
+    pub fn set_game(env: Env, game: Address) {
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        env.storage().instance().set(&DataKey::Game, &game);
+        env.storage().instance().extend_ttl(TTL_THRESHOLD, TTL_BUMP);
+    }
+
+    /// Record a finished game. Only the registered game contract may call
+    /// this.
+    ///
+    /// # Arguments
+    /// * `winner` - Player who won the game
+    /// * `loser` - Player who lost it
+    /// * `points` - The loser's points, credited to the winner
+    pub fn record_result(env: Env, winner: Address, loser: Address, points: i128) {
+        let game: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Game)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::GameNotSet));
+        game.require_auth();
+
+        let mut entry = Self::get_entry(env.clone(), winner.clone());
+        entry.wins += 1;
+        entry.points += points;
+        Self::store_entry(&env, &entry);
+        // The loser gets an entry too, so every player can look themselves up.
+        let loss = Self::get_entry(env.clone(), loser);
+        Self::store_entry(&env, &loss);
+
+        Self::rank(&env, DataKey::TopByWins, &entry, |a, b| a.wins > b.wins);
+        Self::rank(&env, DataKey::TopByPoints, &entry, |a, b| a.points > b.points);
+        env.storage().instance().extend_ttl(TTL_THRESHOLD, TTL_BUMP);
+    }
+
+    /// `player`'s record; all zero if they have never finished a game.
+    pub fn get_entry(env: Env, player: Address) -> Entry {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Entry(player.clone()))
+            .unwrap_or(Entry {
+                player,
+                wins: 0,
+                points: 0,
+            })
+    }
+
+    /// Up to `TOP_N` players with the most wins, best first.
+    pub fn get_top_by_wins(env: Env) -> Vec<Entry> {
+        Self::ranking(&env, DataKey::TopByWins)
+    }
+
+    /// Up to `TOP_N` players with the most points won, best first.
+    pub fn get_top_by_points(env: Env) -> Vec<Entry> {
+        Self::ranking(&env, DataKey::TopByPoints)
+    }
+
+    fn store_entry(env: &Env, entry: &Entry) {
+        let key = DataKey::Entry(entry.player.clone());
+        env.storage().persistent().set(&key, entry);
+        env.storage().persistent().extend_ttl(&key, TTL_THRESHOLD, TTL_BUMP);
+    }
+
+    fn ranking(env: &Env, key: DataKey) -> Vec<Entry> {
+        env.storage()
+            .instance()
+            .get(&key)
+            .unwrap_or_else(|| Vec::new(env))
+    }
+
+    /// Move `entry` to its place in the ranking under `key`, behind any
+    /// player it does not beat, and trim the ranking to `TOP_N`.
+    fn rank(env: &Env, key: DataKey, entry: &Entry, beats: fn(&Entry, &Entry) -> bool) {
+        let mut ranking = Vec::new(env);
+        for other in Self::ranking(env, key.clone()).iter() {
+            if other.player != entry.player {
+                ranking.push_back(other);
+            }
+        }
+        let at = ranking
+            .iter()
+            .position(|other| beats(entry, &other))
+            .unwrap_or(ranking.len() as usize);
+        ranking.insert(at as u32, entry.clone());
+        while ranking.len() > TOP_N {
+            ranking.pop_back();
+        }
+        env.storage().instance().set(&key, &ranking);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+
+    #[test]
+    fn test_record_and_rank() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let admin = Address::generate(&env);
+        let contract_id = env.register(Leaderboard, (&admin,));
+        let client = LeaderboardClient::new(&env, &contract_id);
+        let game = Address::generate(&env);
+        client.set_game(&game);
+
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+        let carol = Address::generate(&env);
+        client.record_result(&alice, &bob, &100);
+        client.record_result(&bob, &carol, &500);
+        client.record_result(&alice, &carol, &100);
+
+        let wins = client.get_top_by_wins();
+        assert_eq!(wins.len(), 2);
+        assert_eq!(wins.get(0).unwrap().player, alice);
+        assert_eq!(wins.get(0).unwrap().wins, 2);
+        let points = client.get_top_by_points();
+        assert_eq!(points.get(0).unwrap().player, bob);
+        assert_eq!(points.get(0).unwrap().points, 500);
+        assert_eq!(client.get_entry(&carol).wins, 0);
+    }
+
+    #[test]
+    fn test_ranking_is_capped() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let admin = Address::generate(&env);
+        let contract_id = env.register(Leaderboard, (&admin,));
+        let client = LeaderboardClient::new(&env, &contract_id);
+        client.set_game(&Address::generate(&env));
+
+        let loser = Address::generate(&env);
+        for _ in 0..TOP_N + 2 {
+            client.record_result(&Address::generate(&env), &loser, &1);
+        }
+        assert_eq!(client.get_top_by_wins().len(), TOP_N);
+    }
+
+    #[test]
+    fn test_record_requires_game() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let admin = Address::generate(&env);
+        let contract_id = env.register(Leaderboard, (&admin,));
+        let client = LeaderboardClient::new(&env, &contract_id);
+
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+        let res = client.try_record_result(&alice, &bob, &1);
+        assert_eq!(res, Err(Ok(Error::GameNotSet.into())));
+    }
+}
@@ -5,7 +5,7 @@ use soroban_sdk::{contract, contractevent, contractimpl, Address, Env};
 /// Mock Game Hub contract for game studio development
 ///
 /// This contract provides the same external interface that games expect
-/// (start_game, end_game) but does nothing internally. It exists purely
+/// (start_game, end_game, draw_game, abort_game) but does nothing internally. It exists purely
 /// for game contracts to compile and integrate during development.
 #[contract]
 pub struct MockGameHub;
@@ -26,6 +26,16 @@ pub struct GameEnded {
     pub player1_won: bool,
 }
 
+#[contractevent]
+pub struct GameDrawn {
+    pub session_id: u32,
+}
+
+#[contractevent]
+pub struct GameAborted {
+    pub session_id: u32,
+}
+
 #[contractimpl]
 impl MockGameHub {
     /// Start a game session
@@ -77,6 +87,24 @@ impl MockGameHub {
         }
         .publish(&env);
     }
+
+    /// End a game session with no winner
+    ///
+    /// # Arguments
+    /// * `session_id` - The game session being ended
+    pub fn draw_game(env: Env, session_id: u32) {
+        // No auth required for mock
+        GameDrawn { session_id }.publish(&env);
+    }
+
+    /// Void a game session that never got under way
+    ///
+    /// # Arguments
+    /// * `session_id` - The game session being aborted
+    pub fn abort_game(env: Env, session_id: u32) {
+        // No auth required for mock
+        GameAborted { session_id }.publish(&env);
+    }
 }
 
 #[cfg(test)]
@@ -86,7 +114,7 @@ mod test {
     #[test]
     fn test_start_and_end_game() {
         let env = Env::default();
-        let contract_id = env.register_contract(None, MockGameHub);
+        let contract_id = env.register(MockGameHub, ());
         let client = MockGameHubClient::new(&env, &contract_id);
         let game_id = Address::generate(&env);
         let player1 = Address::generate(&env);
@@ -94,4 +122,28 @@ mod test {
         client.start_game(&game_id, &1, &player1, &player2, &1000, &1000);
         client.end_game(&1, &true);
     }
+
+    #[test]
+    fn test_draw_game() {
+        let env = Env::default();
+        let contract_id = env.register(MockGameHub, ());
+        let client = MockGameHubClient::new(&env, &contract_id);
+        let game_id = Address::generate(&env);
+        let player1 = Address::generate(&env);
+        let player2 = Address::generate(&env);
+        client.start_game(&game_id, &1, &player1, &player2, &1000, &1000);
+        client.draw_game(&1);
+    }
+
+    #[test]
+    fn test_abort_game() {
+        let env = Env::default();
+        let contract_id = env.register(MockGameHub, ());
+        let client = MockGameHubClient::new(&env, &contract_id);
+        let game_id = Address::generate(&env);
+        let player1 = Address::generate(&env);
+        let player2 = Address::generate(&env);
+        client.start_game(&game_id, &1, &player1, &player2, &1000, &1000);
+        client.abort_game(&1);
+    }
 }
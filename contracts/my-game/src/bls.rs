@@ -0,0 +1,74 @@
+//! BLS12-381 helpers for aggregate co-signing.
+//!
+//! Public keys live in G1 and signatures in G2 (the "minimal pubkey size"
+//! variant). Keys are registered with a proof of possession so that summing
+//! two registered keys cannot be used for a rogue-key attack.
+
+use soroban_sdk::{
+    bytesn,
+    crypto::bls12_381::{G1Affine, G2Affine},
+    Bytes, BytesN, Env, Vec,
+};
+
+/// Domain separation tag for signatures over contract messages.
+const SIG_DST: &[u8] = b"PIRATES-TREASURE-BLS-SIG-BLS12381G2_XMD:SHA-256_SSWU_RO_";
+/// Domain separation tag for proofs of possession.
+const POP_DST: &[u8] = b"PIRATES-TREASURE-BLS-POP-BLS12381G2_XMD:SHA-256_SSWU_RO_";
+
+fn g1_generator(env: &Env) -> G1Affine {
+    G1Affine::from_bytes(bytesn!(
+        env,
+        0x17f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb08b3f481e3aaa0f1a09e30ed741d8ae4fcf5e095d5d00af600db18cb2c04b3edd03cc744a2888ae40caa232946c5e7e1
+    ))
+}
+
+/// Hash a message onto G2 for signing.
+pub fn hash_message(env: &Env, msg: &Bytes) -> G2Affine {
+    env.crypto()
+        .bls12_381()
+        .hash_to_g2(msg, &Bytes::from_slice(env, SIG_DST))
+}
+
+/// Hash a public key onto G2 for its proof of possession.
+pub fn hash_pop(env: &Env, public_key: &BytesN<96>) -> G2Affine {
+    env.crypto()
+        .bls12_381()
+        .hash_to_g2(&public_key.clone().into(), &Bytes::from_slice(env, POP_DST))
+}
+
+/// e(-G, sig) · e(pk, h) == 1, i.e. `sig` is a signature by `pk` over `h`.
+fn pairing_verifies(env: &Env, public_key: G1Affine, signature: G2Affine, hashed: G2Affine) -> bool {
+    let vp1 = Vec::from_array(env, [-g1_generator(env), public_key]);
+    let vp2 = Vec::from_array(env, [signature, hashed]);
+    env.crypto().bls12_381().pairing_check(vp1, vp2)
+}
+
+/// Verify a proof of possession for a G1 public key.
+pub fn verify_pop(env: &Env, public_key: &BytesN<96>, proof: &BytesN<192>) -> bool {
+    pairing_verifies(
+        env,
+        G1Affine::from_bytes(public_key.clone()),
+        G2Affine::from_bytes(proof.clone()),
+        hash_pop(env, public_key),
+    )
+}
+
+/// Verify an aggregate signature over `msg` by the sum of `public_keys`.
+pub fn verify_aggregate(
+    env: &Env,
+    public_keys: &[BytesN<96>],
+    msg: &Bytes,
+    signature: &BytesN<192>,
+) -> bool {
+    let bls = env.crypto().bls12_381();
+    let mut aggregate = G1Affine::from_bytes(public_keys[0].clone());
+    for pk in &public_keys[1..] {
+        aggregate = bls.g1_add(&aggregate, &G1Affine::from_bytes(pk.clone()));
+    }
+    pairing_verifies(
+        env,
+        aggregate,
+        G2Affine::from_bytes(signature.clone()),
+        hash_message(env, msg),
+    )
+}
@@ -0,0 +1,3784 @@
+//! The `PiratesTreasure` contract implementation, compiled out under the
+//! `interface` feature.
+
+use soroban_sdk::{
+    contract, contractimpl, panic_with_error, symbol_short, token, vec,
+    Address, Bytes, BytesN, Env, IntoVal, Map, Symbol, TryFromVal, Val, Vec, U256,
+    crypto::Hash,
+    xdr::ToXdr,
+};
+
+use crate::events::{
+    emit_room_event, publish_detail, PlayerJoined, RoomCreated, TileDug, TreasureBuried,
+    TreasureRevealed,
+};
+use crate::{
+    bls, poseidon, AdminBackup, Attestation, Bet, BetBook, BurialLedgers, CommitmentScheme, Config,
+    Cursor, DataKey, DigMode, DigPage, DigRecord, Error, EventVerbosity, FullView, GameHubClient,
+    GameReport, HubKind, HubReport, LeaderboardClient, MigrationPreview, PendingDig,
+    PendingHubReport, PiratesTreasureInterface, PlayerStats, PublishedCommitments, QueueEntry,
+    ReportPage, ReportWindow, Reputation, Reveal, Roles, Room, RoomConfig, RoomEvent, RoomIdPage,
+    RoomPage, RoomRequest, RoomV1, RoundState, Series, StorageReport, Tip, TipPage, TreasureLeaf,
+};
+
+// ---------------------------------------------------------------------------
+// TTL helpers (30-day temporary storage)
+// ---------------------------------------------------------------------------
+
+const DAY_IN_LEDGERS: u32 = 17_280;
+const TTL_BUMP: u32 = 30 * DAY_IN_LEDGERS;        // 518 400
+const TTL_THRESHOLD: u32 = TTL_BUMP - DAY_IN_LEDGERS; // 501 120
+
+pub(crate) fn bump_temp(env: &Env, key: &DataKey) {
+    env.storage()
+        .temporary()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_BUMP);
+}
+
+fn bump_persistent(env: &Env, key: &DataKey) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_BUMP);
+}
+
+fn bump_instance(env: &Env) {
+    env.storage()
+        .instance()
+        .extend_ttl(TTL_THRESHOLD, TTL_BUMP);
+}
+
+// ---------------------------------------------------------------------------
+// Limits
+// ---------------------------------------------------------------------------
+
+/// Number of events kept per room for pollers, unless configured.
+pub(crate) const DEFAULT_RECENT_EVENTS: u32 = 16;
+/// Upper bound on the configurable outbox length.
+const RECENT_EVENTS_CAP: u32 = 64;
+
+/// Number of reports one address may file per `REPORT_WINDOW_LEDGERS`,
+/// unless configured.
+pub(crate) const DEFAULT_REPORTS_PER_REPORTER: u32 = 10;
+/// Upper bound on the configurable per-reporter limit, and on the reports
+/// a single room keeps.
+const REPORTS_CAP: u32 = 100;
+/// Length of the window a reporter's limit applies to.
+pub(crate) const REPORT_WINDOW_LEDGERS: u32 = DAY_IN_LEDGERS;
+
+/// Upper bound on the protocol fee, in basis points (10%).
+pub(crate) const MAX_FEE_BPS: u32 = 1_000;
+
+/// How long either player may still reveal once every tile is dug,
+/// unless configured.
+pub(crate) const DEFAULT_REVEAL_WINDOW_LEDGERS: u32 = DAY_IN_LEDGERS;
+
+/// How long the player on turn has to move before the opponent may
+/// claim the game, unless configured.
+pub(crate) const DEFAULT_TURN_TIMEOUT_LEDGERS: u32 = DAY_IN_LEDGERS;
+
+/// How long both players have to bury once a room starts, unless
+/// configured.
+pub(crate) const DEFAULT_BURY_TIMEOUT_LEDGERS: u32 = DAY_IN_LEDGERS;
+
+/// Upper bound on every configurable timeout, well inside a room's
+/// storage lifetime.
+pub(crate) const MAX_TIMEOUT_LEDGERS: u32 = 7 * DAY_IN_LEDGERS;
+
+/// Most treasures a player can bury in one room.
+const MAX_TREASURES: u32 = 8;
+
+/// Elo rating of a player's first game.
+pub(crate) const INITIAL_RATING: u32 = 1_200;
+/// Most rating points a single game can move.
+const ELO_K: i64 = 32;
+
+/// Most observers a single room notifies.
+const MAX_OBSERVERS: u32 = 8;
+
+/// Layout version of the state this wasm reads and writes. Version 1 is
+/// the original `Room` layout, kept as `RoomV1`.
+pub(crate) const STATE_VERSION: u32 = 2;
+
+/// Most items a paged list endpoint returns at once.
+const MAX_PAGE_SIZE: u32 = 50;
+
+/// Most rooms the lobby lists; opening one more drops the oldest, which
+/// can still be joined by id. Opening a room reads every listed one, so
+/// this stays well inside a transaction's footprint.
+pub(crate) const MAX_OPEN_ROOMS: u32 = 50;
+
+/// Action points a dig costs on an island of `tiles` tiles: one per ten
+/// tiles, so 1, 2 and 3 on the standard board.
+fn dig_cost(tiles: u32) -> u32 {
+    tiles.div_ceil(10).max(1)
+}
+
+/// Settings of a room created without a config: a single-treasure
+/// alternating duel with SHA-256 commitments and no entry requirements.
+fn default_room_config() -> RoomConfig {
+    RoomConfig {
+        commitment_scheme: CommitmentScheme::Sha256,
+        dig_mode: DigMode::Alternating,
+        roles: Roles::Duel,
+        dig_budget: 0,
+        hider_odds_bps: 0,
+        turn_timeout_ledgers: 0,
+        bury_timeout_ledgers: 0,
+        reveal_window_ledgers: 0,
+        action_points: 0,
+        min_completed: 0,
+        min_score_bps: 0,
+        event_verbosity: EventVerbosity::Default,
+        signed_burials: false,
+        derived_salts: false,
+        stake_token: None,
+        treasures: 0,
+        guess_mode: false,
+        invite_hash: None,
+        hub: None,
+        hubless: false,
+    }
+}
+
+/// Matchmaking bucket of a stake: its power of two, so players are
+/// matched with stakes less than twice apart.
+fn queue_bucket(env: &Env, points: i128) -> u32 {
+    if points <= 0 {
+        panic_with_error!(env, Error::InvalidAmount);
+    }
+    points.ilog2()
+}
+
+/// Elo expected score, in basis points, of a player rated `diff` above
+/// their opponent. Interpolated from a table in steps of 50 points,
+/// since the logistic curve needs floating point.
+fn elo_expected_bps(diff: i64) -> i64 {
+    const TABLE: [i64; 17] = [
+        5000, 5715, 6401, 7034, 7597, 8083, 8490, 8823, 9091, 9302, 9468, 9595, 9693, 9768,
+        9825, 9868, 9901,
+    ];
+    let d = diff.clamp(-800, 800).abs();
+    let i = (d / 50) as usize;
+    let e = match TABLE.get(i + 1) {
+        Some(next) => TABLE[i] + (next - TABLE[i]) * (d % 50) / 50,
+        None => TABLE[i],
+    };
+    if diff < 0 {
+        10_000 - e
+    } else {
+        e
+    }
+}
+
+/// Read a `u32` setting from instance storage, falling back to `default`.
+pub(crate) fn instance_u32(env: &Env, key: &DataKey, default: u32) -> u32 {
+    env.storage().instance().get(key).unwrap_or(default)
+}
+
+// ---------------------------------------------------------------------------
+// Contract
+// ---------------------------------------------------------------------------
+
+#[contract]
+pub struct PiratesTreasure;
+
+#[contractimpl]
+impl PiratesTreasure {
+    // ── Constructor ────────────────────────────────────────────────────
+    pub fn __constructor(env: Env, admin: Address, game_hub: Option<Address>) {
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        if let Some(game_hub) = game_hub {
+            env.storage().instance().set(&DataKey::GameHubAddress, &game_hub);
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::AdminLastActive, &env.ledger().sequence());
+        env.storage().instance().set(&DataKey::StateVersion, &STATE_VERSION);
+        bump_instance(&env);
+    }
+}
+
+#[contractimpl]
+impl PiratesTreasureInterface for PiratesTreasure {
+    // ── Admin helpers ──────────────────────────────────────────────────
+
+    fn get_admin(env: Env) -> Address {
+        env.storage().instance().get(&DataKey::Admin).unwrap()
+    }
+
+    /// Offer the admin role to `new_admin`. Nothing changes until they
+    /// `accept_admin`, so a mistyped address cannot lock the admin out; a
+    /// later proposal replaces this one.
+    fn propose_admin(env: Env, new_admin: Address) {
+        Self::require_admin(&env);
+        env.storage().instance().set(&DataKey::PendingAdmin, &new_admin);
+        bump_instance(&env);
+    }
+
+    /// Take up the admin role as the proposed admin.
+    fn accept_admin(env: Env) {
+        let instance = env.storage().instance();
+        let new_admin: Address = instance
+            .get(&DataKey::PendingAdmin)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::NoPendingAdmin));
+        new_admin.require_auth();
+
+        instance.set(&DataKey::Admin, &new_admin);
+        instance.remove(&DataKey::PendingAdmin);
+        instance.set(&DataKey::AdminLastActive, &env.ledger().sequence());
+        bump_instance(&env);
+    }
+
+    fn get_pending_admin(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::PendingAdmin)
+    }
+
+    fn get_hub(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::GameHubAddress)
+    }
+
+    fn set_hub(env: Env, new_hub: Address) {
+        Self::require_admin(&env);
+        env.storage().instance().set(&DataKey::GameHubAddress, &new_hub);
+        bump_instance(&env);
+    }
+
+    /// Let rooms choose `hub` to report to, through their config.
+    fn approve_hub(env: Env, hub: Address) {
+        Self::require_admin(&env);
+        let mut hubs = Self::get_approved_hubs(env.clone());
+        if !hubs.contains(&hub) {
+            hubs.push_back(hub);
+            env.storage().instance().set(&DataKey::ApprovedHubs, &hubs);
+        }
+        bump_instance(&env);
+    }
+
+    /// Take `hub` off the approved list. Rooms already created with it
+    /// keep reporting to it.
+    fn revoke_hub(env: Env, hub: Address) {
+        Self::require_admin(&env);
+        let mut hubs = Self::get_approved_hubs(env.clone());
+        if let Some(i) = hubs.first_index_of(&hub) {
+            hubs.remove(i);
+            env.storage().instance().set(&DataKey::ApprovedHubs, &hubs);
+        }
+        bump_instance(&env);
+    }
+
+    fn get_approved_hubs(env: Env) -> Vec<Address> {
+        env.storage()
+            .instance()
+            .get(&DataKey::ApprovedHubs)
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    /// Record which interface `hub` implements, so draws and aborts reach
+    /// a legacy hub through `end_game`. Applies to pending reports too.
+    fn set_hub_kind(env: Env, hub: Address, kind: HubKind) {
+        Self::require_admin(&env);
+        env.storage().instance().set(&DataKey::HubKind(hub), &kind);
+        bump_instance(&env);
+    }
+
+    fn get_hub_kind(env: Env, hub: Address) -> HubKind {
+        env.storage()
+            .instance()
+            .get(&DataKey::HubKind(hub))
+            .unwrap_or(HubKind::Legacy)
+    }
+
+    /// The leaderboard decided games are reported to, if one is set.
+    fn get_leaderboard(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::LeaderboardAddress)
+    }
+
+    /// Report every decided game to `leaderboard` from now on. The
+    /// leaderboard must list this contract as its game.
+    fn set_leaderboard(env: Env, leaderboard: Address) {
+        Self::require_admin(&env);
+        env.storage().instance().set(&DataKey::LeaderboardAddress, &leaderboard);
+        bump_instance(&env);
+    }
+
+    /// The effective configuration, with defaults filled in for any limit
+    /// that was never set.
+    fn get_config(env: Env) -> Config {
+        Config {
+            game_hub: Self::get_hub(env.clone()),
+            max_recent_events: instance_u32(
+                &env,
+                &DataKey::MaxRecentEvents,
+                DEFAULT_RECENT_EVENTS,
+            ),
+            max_reports_per_reporter: instance_u32(
+                &env,
+                &DataKey::MaxReportsPerReporter,
+                DEFAULT_REPORTS_PER_REPORTER,
+            ),
+            event_verbosity: env
+                .storage()
+                .instance()
+                .get(&DataKey::EventVerbosity)
+                .unwrap_or(EventVerbosity::Full),
+            turn_timeout_ledgers: instance_u32(
+                &env,
+                &DataKey::TurnTimeout,
+                DEFAULT_TURN_TIMEOUT_LEDGERS,
+            ),
+            bury_timeout_ledgers: instance_u32(
+                &env,
+                &DataKey::BuryTimeout,
+                DEFAULT_BURY_TIMEOUT_LEDGERS,
+            ),
+            reveal_window_ledgers: instance_u32(
+                &env,
+                &DataKey::RevealWindow,
+                DEFAULT_REVEAL_WINDOW_LEDGERS,
+            ),
+            paused: Self::is_paused(env.clone()),
+            version: Self::get_version(env.clone()),
+            fee_bps: Self::get_fee_bps(env.clone()),
+            treasury: Self::get_treasury(env.clone()),
+        }
+    }
+
+    /// Replace every admin-tunable setting in one call. Limits outside
+    /// their allowed range, or a change to a fixed setting, are rejected
+    /// with `InvalidConfig`; a fee above `MAX_FEE_BPS` with `FeeTooHigh`.
+    fn set_config(env: Env, config: Config) {
+        Self::require_admin(&env);
+
+        if !(1..=RECENT_EVENTS_CAP).contains(&config.max_recent_events)
+            || !(1..=REPORTS_CAP).contains(&config.max_reports_per_reporter)
+            || !(1..=MAX_TIMEOUT_LEDGERS).contains(&config.turn_timeout_ledgers)
+            || !(1..=MAX_TIMEOUT_LEDGERS).contains(&config.bury_timeout_ledgers)
+            || !(1..=MAX_TIMEOUT_LEDGERS).contains(&config.reveal_window_ledgers)
+            || config.version != Self::get_version(env.clone())
+        {
+            panic_with_error!(&env, Error::InvalidConfig);
+        }
+        if config.fee_bps > MAX_FEE_BPS {
+            panic_with_error!(&env, Error::FeeTooHigh);
+        }
+
+        let instance = env.storage().instance();
+        match &config.game_hub {
+            Some(hub) => instance.set(&DataKey::GameHubAddress, hub),
+            None => instance.remove(&DataKey::GameHubAddress),
+        }
+        instance.set(&DataKey::MaxRecentEvents, &config.max_recent_events);
+        instance.set(&DataKey::MaxReportsPerReporter, &config.max_reports_per_reporter);
+        instance.set(&DataKey::EventVerbosity, &config.event_verbosity);
+        instance.set(&DataKey::TurnTimeout, &config.turn_timeout_ledgers);
+        instance.set(&DataKey::BuryTimeout, &config.bury_timeout_ledgers);
+        instance.set(&DataKey::RevealWindow, &config.reveal_window_ledgers);
+        if config.paused {
+            instance.set(&DataKey::Paused, &true);
+        } else {
+            instance.remove(&DataKey::Paused);
+        }
+        instance.set(&DataKey::FeeBps, &config.fee_bps);
+        match &config.treasury {
+            Some(treasury) => instance.set(&DataKey::Treasury, treasury),
+            None => instance.remove(&DataKey::Treasury),
+        }
+        bump_instance(&env);
+    }
+
+    /// Name a backup that may take over the admin role once the admin has
+    /// gone `inactivity_ledgers` without an authorized admin action.
+    fn set_admin_backup(env: Env, backup: Address, inactivity_ledgers: u32) {
+        Self::require_admin(&env);
+        if inactivity_ledgers == 0 {
+            panic_with_error!(&env, Error::InvalidConfig);
+        }
+        let entry = AdminBackup {
+            backup,
+            inactivity_ledgers,
+        };
+        env.storage().instance().set(&DataKey::AdminBackup, &entry);
+        bump_instance(&env);
+    }
+
+    fn get_admin_backup(env: Env) -> Option<AdminBackup> {
+        env.storage().instance().get(&DataKey::AdminBackup)
+    }
+
+    /// Take over the admin role as the registered backup. Only allowed once
+    /// the admin's inactivity threshold has passed; clears the backup.
+    fn claim_admin(env: Env, backup: Address) {
+        backup.require_auth();
+
+        let entry: AdminBackup = env
+            .storage()
+            .instance()
+            .get(&DataKey::AdminBackup)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::Unauthorized));
+        if entry.backup != backup {
+            panic_with_error!(&env, Error::Unauthorized);
+        }
+        let last_active: u32 = instance_u32(&env, &DataKey::AdminLastActive, 0);
+        if env.ledger().sequence() < last_active.saturating_add(entry.inactivity_ledgers) {
+            panic_with_error!(&env, Error::AdminStillActive);
+        }
+
+        let instance = env.storage().instance();
+        instance.set(&DataKey::Admin, &backup);
+        instance.remove(&DataKey::AdminBackup);
+        instance.remove(&DataKey::PendingAdmin);
+        instance.set(&DataKey::AdminLastActive, &env.ledger().sequence());
+        bump_instance(&env);
+    }
+
+    /// Settle a started room without the hub, once an outage is confirmed.
+    /// The game plays on as normal, but its result stays local: the hub's
+    /// `end_game` is skipped and the hub-side points are left for the hub
+    /// operator to void.
+    fn set_room_hubless(env: Env, room_id: u32) {
+        let admin = Self::require_admin(&env);
+
+        let key = DataKey::Room(room_id);
+        let mut room = Self::get_room(env.clone(), room_id);
+        if room.phase == 0 {
+            Self::require_phase(&env, &room, 1);
+        }
+        if !room.game_active {
+            panic_with_error!(&env, Error::GameEnded);
+        }
+
+        room.hubless = true;
+        env.storage().temporary().set(&key, &room);
+        bump_temp(&env, &key);
+        emit_room_event(&env, &room, symbol_short!("hubless"), &admin);
+    }
+
+    /// Freeze a room that has not ended, or thaw it again. While frozen
+    /// every player action fails with `RoomFrozen`; admin actions such as
+    /// `set_room_hubless` still go through. The clock stops too: on thaw
+    /// the turn deadline and reveal window resume where they left off.
+    fn set_room_frozen(env: Env, room_id: u32, frozen: bool) {
+        let admin = Self::require_admin(&env);
+
+        let key = DataKey::Room(room_id);
+        let mut room = Self::get_room(env.clone(), room_id);
+        if room.phase == 3 || room.phase == 4 {
+            panic_with_error!(&env, Error::GameEnded);
+        }
+
+        let now = env.ledger().sequence();
+        if frozen && !room.frozen {
+            room.frozen_at = now;
+        } else if !frozen && room.frozen {
+            let elapsed = now - room.frozen_at;
+            if room.turn_deadline != 0 {
+                room.turn_deadline = room.turn_deadline.saturating_add(elapsed);
+            }
+            room.exhausted_at = room.exhausted_at.map(|at| at.saturating_add(elapsed));
+        }
+        room.frozen = frozen;
+        env.storage().temporary().set(&key, &room);
+        bump_temp(&env, &key);
+        let kind = if frozen {
+            symbol_short!("frozen")
+        } else {
+            symbol_short!("unfrozen")
+        };
+        emit_room_event(&env, &room, kind, &admin);
+    }
+
+    /// Stop every new room from being created or started, for incident
+    /// response. Rooms already started play on to the end.
+    fn pause(env: Env) {
+        Self::require_admin(&env);
+        env.storage().instance().set(&DataKey::Paused, &true);
+        bump_instance(&env);
+    }
+
+    /// Lift a `pause`.
+    fn unpause(env: Env) {
+        Self::require_admin(&env);
+        env.storage().instance().remove(&DataKey::Paused);
+        bump_instance(&env);
+    }
+
+    fn is_paused(env: Env) -> bool {
+        env.storage().instance().has(&DataKey::Paused)
+    }
+
+    /// Take `fee_bps` basis points of every won pot from now on, up to
+    /// `MAX_FEE_BPS`. Refunds after draws and aborts are never charged.
+    fn set_fee_bps(env: Env, fee_bps: u32) {
+        Self::require_admin(&env);
+        if fee_bps > MAX_FEE_BPS {
+            panic_with_error!(&env, Error::FeeTooHigh);
+        }
+        env.storage().instance().set(&DataKey::FeeBps, &fee_bps);
+        bump_instance(&env);
+    }
+
+    fn get_fee_bps(env: Env) -> u32 {
+        instance_u32(&env, &DataKey::FeeBps, 0)
+    }
+
+    /// Hand fee withdrawals to `treasury`, or back to the admin when
+    /// `None`.
+    fn set_treasury(env: Env, treasury: Option<Address>) {
+        Self::require_admin(&env);
+        match &treasury {
+            Some(treasury) => env.storage().instance().set(&DataKey::Treasury, treasury),
+            None => env.storage().instance().remove(&DataKey::Treasury),
+        }
+        bump_instance(&env);
+    }
+
+    fn get_treasury(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Treasury)
+    }
+
+    /// Fees collected in `token` and not yet withdrawn.
+    fn get_fees(env: Env, token: Address) -> i128 {
+        Self::fee_balances(&env).get(token).unwrap_or(0)
+    }
+
+    /// Send every fee collected so far, in each token, to `to`. Needs the
+    /// treasury's authorization when one is set, the admin's otherwise.
+    fn withdraw_fees(env: Env, to: Address) {
+        match Self::get_treasury(env.clone()) {
+            Some(treasury) => treasury.require_auth(),
+            None => {
+                Self::require_admin(&env);
+            }
+        }
+        let contract = env.current_contract_address();
+        for (token, amount) in Self::fee_balances(&env).iter() {
+            token::Client::new(&env, &token).transfer(&contract, &to, &amount);
+        }
+        env.storage().instance().remove(&DataKey::Fees);
+        bump_instance(&env);
+    }
+
+    // ── Room lifecycle ─────────────────────────────────────────────────
+
+    /// Create a new room. Caller becomes Player A.
+    fn create_room(
+        env: Env,
+        room_id: u32,
+        player_a: Address,
+        player_a_points: i128,
+    ) -> Room {
+        Self::create_room_with_config(
+            env,
+            room_id,
+            player_a,
+            player_a_points,
+            default_room_config(),
+        )
+    }
+
+    /// Create a room under the next free sequential id and return it, so
+    /// creators need not pick ids that may collide.
+    fn create_room_auto(env: Env, player_a: Address, player_a_points: i128) -> u32 {
+        let room_id = Self::next_room_id(&env);
+        Self::create_room(env, room_id, player_a, player_a_points);
+        room_id
+    }
+
+    /// Create a new room with the given commitment scheme, dig mode and
+    /// roles. Hider/seeker rooms cannot dig simultaneously and must set a
+    /// budget.
+    fn create_room_with_config(
+        env: Env,
+        room_id: u32,
+        player_a: Address,
+        player_a_points: i128,
+        config: RoomConfig,
+    ) -> Room {
+        player_a.require_auth();
+
+        Self::new_room(env, room_id, player_a, player_a_points, config)
+    }
+
+    /// Create a round of tournament rooms in one transaction, so they all
+    /// open on the same ledger. Admin only. Each `player_a` is seated
+    /// without signing and authorizes their points with their opponent in
+    /// `start_room`; a stake token would have to be escrowed from them
+    /// here, so such configs are rejected with `InvalidConfig`.
+    /// All-or-nothing: one bad entry rejects the batch.
+    fn create_rooms_batch(env: Env, rooms: Vec<RoomRequest>) -> Vec<u32> {
+        Self::require_admin(&env);
+
+        let mut ids = Vec::new(&env);
+        for req in rooms.iter() {
+            if req.config.stake_token.is_some() {
+                panic_with_error!(&env, Error::InvalidConfig);
+            }
+            Self::new_room(
+                env.clone(),
+                req.room_id,
+                req.player_a,
+                req.player_a_points,
+                req.config,
+            );
+            ids.push_back(req.room_id);
+        }
+        ids
+    }
+
+    /// Player B joins an existing public room.
+    fn join_room(
+        env: Env,
+        room_id: u32,
+        player_b: Address,
+        player_b_points: i128,
+    ) -> Room {
+        player_b.require_auth();
+
+        Self::join(env, room_id, player_b, player_b_points, None)
+    }
+
+    /// Player B joins a private room with its invite code.
+    fn join_private_room(
+        env: Env,
+        room_id: u32,
+        player_b: Address,
+        player_b_points: i128,
+        invite_code: Bytes,
+    ) -> Room {
+        player_b.require_auth();
+
+        Self::join(env, room_id, player_b, player_b_points, Some(invite_code))
+    }
+
+    /// Play an ended room again: a fresh room under the next sequential id
+    /// with the same players, points and config, started straight away.
+    /// Both players authorize. The new room records its predecessor in
+    /// `rematch_of`.
+    fn rematch(env: Env, room_id: u32) -> Room {
+        let prev = Self::get_room(env.clone(), room_id);
+        prev.player_a.require_auth();
+        prev.player_b.require_auth();
+        if prev.phase < 3 {
+            Self::require_phase(&env, &prev, 3);
+        }
+
+        // Both players are seated here, so a private room's invite is moot.
+        let mut config = prev.config;
+        config.invite_hash = None;
+        let new_id = Self::next_room_id(&env);
+        Self::new_room(env.clone(), new_id, prev.player_a.clone(), prev.player_a_points, config);
+        let mut room =
+            Self::join(env.clone(), new_id, prev.player_b.clone(), prev.player_b_points, None);
+        room.rematch_of = Some(room_id);
+        let key = DataKey::Room(new_id);
+        env.storage().temporary().set(&key, &room);
+        bump_temp(&env, &key);
+
+        Self::start_room_authorized(
+            &env,
+            new_id,
+            &prev.player_a,
+            &prev.player_b,
+            prev.player_a_points,
+            prev.player_b_points,
+        )
+    }
+
+    /// Withdraw a room that has not started yet. Only its creator may do
+    /// this; escrowed stakes are refunded and every entry the room holds
+    /// is removed, so the id can be reused.
+    fn cancel_room(env: Env, room_id: u32, player_a: Address) {
+        player_a.require_auth();
+
+        let room = Self::get_room(env.clone(), room_id);
+        Self::require_phase(&env, &room, 0);
+        if player_a != room.player_a {
+            panic_with_error!(&env, Error::NotPlayerA);
+        }
+
+        if let Some(token) = &room.config.stake_token {
+            let client = token::Client::new(&env, token);
+            let contract = env.current_contract_address();
+            client.transfer(&contract, &room.player_a, &room.player_a_points);
+            if room.player_b != room.player_a {
+                client.transfer(&contract, &room.player_b, &room.player_b_points);
+            }
+        }
+
+        // Publish while the room's settings still apply, then clean up.
+        emit_room_event(&env, &room, symbol_short!("cancelled"), &player_a);
+        for key in Self::room_keys(room_id) {
+            env.storage().temporary().remove(&key);
+        }
+        Self::set_room_open(&env, room_id, false);
+        Self::untrack_room(&env, &room);
+    }
+
+    /// Both players co-sign to start. Calls Game Hub `start_game`.
+    fn start_room(
+        env: Env,
+        room_id: u32,
+        player_a: Address,
+        player_b: Address,
+        player_a_points: i128,
+        player_b_points: i128,
+    ) -> Room {
+        // Each player's signature covers the room and exactly the points
+        // they are putting up, so it can be collected independently.
+        player_a.require_auth_for_args(vec![
+            &env,
+            room_id.into_val(&env),
+            player_a_points.into_val(&env),
+        ]);
+        player_b.require_auth_for_args(vec![
+            &env,
+            room_id.into_val(&env),
+            player_b_points.into_val(&env),
+        ]);
+
+        Self::start_room_authorized(
+            &env,
+            room_id,
+            &player_a,
+            &player_b,
+            player_a_points,
+            player_b_points,
+        )
+    }
+
+    /// Register a BLS12-381 G1 public key for aggregate co-signing.
+    ///
+    /// `proof_of_possession` is the player's signature over their own public
+    /// key, which rules out rogue-key attacks on aggregated signatures.
+    fn register_bls_key(
+        env: Env,
+        player: Address,
+        public_key: BytesN<96>,
+        proof_of_possession: BytesN<192>,
+    ) {
+        player.require_auth();
+        if !bls::verify_pop(&env, &public_key, &proof_of_possession) {
+            panic_with_error!(&env, Error::BadSignature);
+        }
+        let key = DataKey::BlsKey(player);
+        env.storage().persistent().set(&key, &public_key);
+        bump_persistent(&env, &key);
+    }
+
+    /// Register the ed25519 key that signs this player's burials in
+    /// `signed_burials` rooms and derives their salts in `derived_salts`
+    /// rooms. Registering again replaces the key.
+    fn register_burial_key(env: Env, player: Address, public_key: BytesN<32>) {
+        player.require_auth();
+        let key = DataKey::BurialKey(player);
+        env.storage().persistent().set(&key, &public_key);
+        bump_persistent(&env, &key);
+    }
+
+    /// The message both players sign for `start_room_aggregated`:
+    /// SHA-256( xdr(contract) ‖ room_id ‖ created_nonce ‖ xdr(player_a) ‖
+    /// xdr(player_b) ‖ player_a_points ‖ player_b_points ). Ask for it once
+    /// Player B has joined.
+    fn get_start_message(
+        env: Env,
+        room_id: u32,
+        player_a_points: i128,
+        player_b_points: i128,
+    ) -> BytesN<32> {
+        let room = Self::get_room(env.clone(), room_id);
+        let mut buf = env.current_contract_address().to_xdr(&env);
+        buf.extend_from_array(&room_id.to_be_bytes());
+        buf.extend_from_slice(&room.created_nonce.to_array());
+        buf.append(&room.player_a.to_xdr(&env));
+        buf.append(&room.player_b.to_xdr(&env));
+        buf.extend_from_array(&player_a_points.to_be_bytes());
+        buf.extend_from_array(&player_b_points.to_be_bytes());
+        env.crypto().sha256(&buf).to_bytes()
+    }
+
+    /// Start a room with one aggregated BLS signature from both players in
+    /// place of two separate Soroban authorizations. Lets a relayer submit
+    /// the start with a single 192-byte blob.
+    fn start_room_aggregated(
+        env: Env,
+        room_id: u32,
+        player_a_points: i128,
+        player_b_points: i128,
+        signature: BytesN<192>,
+    ) -> Room {
+        let room = Self::get_room(env.clone(), room_id);
+        if room.player_b == room.player_a {
+            panic_with_error!(&env, Error::NoOpponent);
+        }
+
+        let key_a = Self::bls_key(&env, &room.player_a);
+        let key_b = Self::bls_key(&env, &room.player_b);
+        let msg = Self::get_start_message(env.clone(), room_id, player_a_points, player_b_points);
+        if !bls::verify_aggregate(&env, &[key_a, key_b], &msg.into(), &signature) {
+            panic_with_error!(&env, Error::BadSignature);
+        }
+
+        Self::start_room_authorized(
+            &env,
+            room_id,
+            &room.player_a,
+            &room.player_b,
+            player_a_points,
+            player_b_points,
+        )
+    }
+
+    // ── Bury phase ─────────────────────────────────────────────────────
+
+    /// Submit a commitment computed under the room's `CommitmentScheme`
+    /// (by default SHA-256(room_id ‖ nonce ‖ rules_hash ‖ island_id ‖
+    /// tile_id ‖ salt), with `rules_hash` from `get_rules_hash`).
+    /// The pre-image is kept secret; only the hash is stored on-chain.
+    fn bury_treasure(
+        env: Env,
+        room_id: u32,
+        player: Address,
+        commitment: BytesN<32>,
+    ) {
+        player.require_auth();
+        Self::bury(&env, room_id, player, commitment, false);
+    }
+
+    /// Bury with an ed25519 signature over (room_id ‖ commitment) from the
+    /// player's registered burial key. Required in rooms with
+    /// `signed_burials`, so a compromised session key alone cannot bury.
+    fn bury_treasure_signed(
+        env: Env,
+        room_id: u32,
+        player: Address,
+        commitment: BytesN<32>,
+        signature: BytesN<64>,
+    ) {
+        player.require_auth();
+
+        let public_key: BytesN<32> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::BurialKey(player.clone()))
+            .unwrap_or_else(|| panic_with_error!(&env, Error::BurialKeyMissing));
+        let mut msg = Bytes::from_array(&env, &room_id.to_be_bytes());
+        msg.extend_from_array(&commitment.to_array());
+        env.crypto().ed25519_verify(&public_key, &msg, &signature);
+
+        Self::bury(&env, room_id, player, commitment, true);
+    }
+
+    /// Replace an already-buried commitment. Only allowed while the opponent
+    /// has not buried yet (the room is still in the Burying phase), so a
+    /// mistyped commitment can be fixed before it matters. Not available in
+    /// rooms with `signed_burials`.
+    fn rebury_treasure(
+        env: Env,
+        room_id: u32,
+        player: Address,
+        commitment: BytesN<32>,
+    ) {
+        player.require_auth();
+
+        let room = Self::get_room(env.clone(), room_id);
+        Self::require_phase(&env, &room, 1);
+        if room.config.signed_burials {
+            panic_with_error!(&env, Error::WrongMode);
+        }
+
+        let is_a = player == room.player_a;
+        let is_b = player == room.player_b;
+        if !is_a && !is_b {
+            panic_with_error!(&env, Error::NotAPlayer);
+        }
+        if (is_a && !room.has_commitment_a) || (is_b && !room.has_commitment_b) {
+            panic_with_error!(&env, Error::NotBuried);
+        }
+
+        Self::store_commitment(&env, room_id, is_a, &commitment);
+        emit_room_event(&env, &room, symbol_short!("reburied"), &player);
+    }
+
+    // ── Dig phase ──────────────────────────────────────────────────────
+
+    /// Dig a tile. Must be the caller's turn. Alternating rooms only; see
+    /// `commit_dig` and `commit_round_dig` for the other dig modes.
+    fn dig(
+        env: Env,
+        room_id: u32,
+        player: Address,
+        island_id: u32,
+        tile_id: u32,
+    ) {
+        player.require_auth();
+
+        let mut room = Self::room_on_turn(&env, room_id, &player, &[DigMode::Alternating]);
+        Self::apply_dig(&env, &mut room, &player, island_id, tile_id);
+    }
+
+    /// Undo the most recent dig when both players authorize it, e.g. after
+    /// a UI glitch sent the wrong tile. The turn returns to the digger.
+    /// Not available in simultaneous or action-point rooms, where the
+    /// turn before the dig cannot be recovered from the dig log alone.
+    fn rollback_dig(env: Env, room_id: u32) {
+        let key = DataKey::Room(room_id);
+        let mut room = Self::get_room(env.clone(), room_id);
+        room.player_a.require_auth();
+        room.player_b.require_auth();
+
+        Self::require_phase(&env, &room, 2);
+        if room.config.dig_mode == DigMode::Simultaneous || room.config.action_points != 0 {
+            panic_with_error!(&env, Error::WrongMode);
+        }
+        let dig = room
+            .digs
+            .pop_back()
+            .unwrap_or_else(|| panic_with_error!(&env, Error::NoDigToRollBack));
+        // The tile is free again, so the board is no longer exhausted.
+        room.exhausted_at = None;
+
+        // The opponent may have committed against the rolled-back board.
+        room.turn_is_a = dig.digger == room.player_a;
+        Self::start_turn(&env, &mut room);
+        env.storage()
+            .temporary()
+            .remove(&DataKey::RoundDig(room_id, !room.turn_is_a));
+
+        env.storage().temporary().set(&key, &room);
+        bump_temp(&env, &key);
+        emit_room_event(&env, &room, symbol_short!("rollback"), &dig.digger);
+    }
+
+    /// Pass the turn with action points left over. Only in rooms that
+    /// spend action points on digs.
+    fn end_turn(env: Env, room_id: u32, player: Address) {
+        player.require_auth();
+
+        let mut room = Self::room_on_turn(
+            &env,
+            room_id,
+            &player,
+            &[DigMode::Alternating, DigMode::Committed],
+        );
+        if room.config.action_points == 0 {
+            panic_with_error!(&env, Error::WrongMode);
+        }
+        env.storage()
+            .temporary()
+            .remove(&DataKey::RoundDig(room_id, room.turn_is_a));
+
+        room.turn_is_a = !room.turn_is_a;
+        room.actions_left = room.config.action_points;
+        Self::start_turn(&env, &mut room);
+        let key = DataKey::Room(room_id);
+        env.storage().temporary().set(&key, &room);
+        bump_temp(&env, &key);
+    }
+
+    /// Commit to this turn's dig in a committed-dig room, hiding the target
+    /// until `reveal_dig` lands it. A later commit on the same turn replaces
+    /// an unrevealed one.
+    fn commit_dig(env: Env, room_id: u32, player: Address, commitment: BytesN<32>) {
+        player.require_auth();
+
+        let room = Self::room_on_turn(&env, room_id, &player, &[DigMode::Committed]);
+        let dig_key = DataKey::RoundDig(room_id, player == room.player_a);
+        let pending = PendingDig {
+            commitment,
+            revealed: false,
+            island_id: 0,
+            tile_id: 0,
+        };
+        env.storage().temporary().set(&dig_key, &pending);
+        bump_temp(&env, &dig_key);
+    }
+
+    /// Open the dig committed with `commit_dig` and apply it.
+    fn reveal_dig(
+        env: Env,
+        room_id: u32,
+        player: Address,
+        island_id: u32,
+        tile_id: u32,
+        salt: BytesN<32>,
+    ) {
+        player.require_auth();
+
+        let mut room = Self::room_on_turn(&env, room_id, &player, &[DigMode::Committed]);
+        let dig_key = DataKey::RoundDig(room_id, player == room.player_a);
+        let pending: PendingDig = env
+            .storage()
+            .temporary()
+            .get(&dig_key)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::NoPendingDig));
+        let opened = Self::round_dig_commitment(&env, &room, &player, island_id, tile_id, &salt);
+        if opened != pending.commitment {
+            panic_with_error!(&env, Error::CommitmentMismatch);
+        }
+
+        env.storage().temporary().remove(&dig_key);
+        Self::apply_dig(&env, &mut room, &player, island_id, tile_id);
+    }
+
+    /// Commit to this round's dig in a simultaneous room. Once both players
+    /// have committed, each opens their choice with `reveal_round_dig`.
+    fn commit_round_dig(env: Env, room_id: u32, player: Address, commitment: BytesN<32>) {
+        player.require_auth();
+
+        let room = Self::get_room(env.clone(), room_id);
+        Self::require_phase(&env, &room, 2);
+        if room.config.dig_mode != DigMode::Simultaneous {
+            panic_with_error!(&env, Error::WrongMode);
+        }
+        let is_a = if player == room.player_a {
+            true
+        } else if player == room.player_b {
+            false
+        } else {
+            panic_with_error!(&env, Error::NotAPlayer);
+        };
+
+        let dig_key = DataKey::RoundDig(room_id, is_a);
+        if env.storage().temporary().has(&dig_key) {
+            panic_with_error!(&env, Error::AlreadyCommitted);
+        }
+        let pending = PendingDig {
+            commitment,
+            revealed: false,
+            island_id: 0,
+            tile_id: 0,
+        };
+        env.storage().temporary().set(&dig_key, &pending);
+        bump_temp(&env, &dig_key);
+    }
+
+    /// Open this round's dig commitment. When the second player reveals,
+    /// both digs are applied, Player A's first. A tile that is already dug
+    /// is wasted rather than rejected, since it was chosen blind; if both
+    /// players pick the same fresh tile, both digs are recorded.
+    fn reveal_round_dig(
+        env: Env,
+        room_id: u32,
+        player: Address,
+        island_id: u32,
+        tile_id: u32,
+        salt: BytesN<32>,
+    ) {
+        player.require_auth();
+
+        let key = DataKey::Room(room_id);
+        let mut room = Self::get_room(env.clone(), room_id);
+        Self::require_phase(&env, &room, 2);
+        if room.config.dig_mode != DigMode::Simultaneous {
+            panic_with_error!(&env, Error::WrongMode);
+        }
+        let is_a = if player == room.player_a {
+            true
+        } else if player == room.player_b {
+            false
+        } else {
+            panic_with_error!(&env, Error::NotAPlayer);
+        };
+
+        let own_key = DataKey::RoundDig(room_id, is_a);
+        let other_key = DataKey::RoundDig(room_id, !is_a);
+        let mut own: PendingDig = env
+            .storage()
+            .temporary()
+            .get(&own_key)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::RoundNotReady));
+        let other: PendingDig = env
+            .storage()
+            .temporary()
+            .get(&other_key)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::RoundNotReady));
+        if own.revealed {
+            panic_with_error!(&env, Error::AlreadyCommitted);
+        }
+
+        if island_id >= room.island_tile_counts.len() {
+            panic_with_error!(&env, Error::InvalidIsland);
+        }
+        if tile_id >= room.island_tile_counts.get(island_id).unwrap() {
+            panic_with_error!(&env, Error::InvalidTile);
+        }
+        let opened = Self::round_dig_commitment(&env, &room, &player, island_id, tile_id, &salt);
+        if opened != own.commitment {
+            panic_with_error!(&env, Error::CommitmentMismatch);
+        }
+
+        if !other.revealed {
+            own.revealed = true;
+            own.island_id = island_id;
+            own.tile_id = tile_id;
+            env.storage().temporary().set(&own_key, &own);
+            bump_temp(&env, &own_key);
+            return;
+        }
+
+        // Second reveal closes the round: apply both digs, A first.
+        let (a_dig, b_dig) = if is_a {
+            ((island_id, tile_id), (other.island_id, other.tile_id))
+        } else {
+            ((other.island_id, other.tile_id), (island_id, tile_id))
+        };
+        let already_dug = room.digs.clone();
+        for (digger, (island_id, tile_id)) in [
+            (room.player_a.clone(), a_dig),
+            (room.player_b.clone(), b_dig),
+        ] {
+            if already_dug
+                .iter()
+                .any(|d| d.island_id == island_id && d.tile_id == tile_id)
+            {
+                continue;
+            }
+            room.digs.push_back(DigRecord {
+                digger: digger.clone(),
+                island_id,
+                tile_id,
+            });
+            publish_detail(
+                &env,
+                &room,
+                TileDug {
+                    room_id,
+                    digger: digger.clone(),
+                    island_id,
+                    tile_id,
+                },
+            );
+            emit_room_event(&env, &room, symbol_short!("dug"), &digger);
+        }
+
+        Self::note_exhaustion(&env, &mut room);
+        Self::start_turn(&env, &mut room);
+        env.storage().temporary().remove(&own_key);
+        env.storage().temporary().remove(&other_key);
+        env.storage().temporary().set(&key, &room);
+        bump_temp(&env, &key);
+    }
+
+    /// Settle an exhausted board as a draw once its reveal window has
+    /// closed without a winning reveal. Anyone may call this.
+    ///
+    /// In a multi-treasure room every burial must first be opened through
+    /// `open_treasures`, within a second window as long as the first. A
+    /// player who leaves theirs unopened forfeits to one who opened.
+    fn finalize_draw(env: Env, room_id: u32, caller: Address) {
+        caller.require_auth();
+
+        let key = DataKey::Room(room_id);
+        let mut room = Self::get_room(env.clone(), room_id);
+        Self::require_phase(&env, &room, 2);
+        let exhausted_at = room
+            .exhausted_at
+            .unwrap_or_else(|| panic_with_error!(&env, Error::BoardNotExhausted));
+        let window = room.config.reveal_window_ledgers;
+        let now = env.ledger().sequence();
+        if now < exhausted_at.saturating_add(window) {
+            panic_with_error!(&env, Error::RevealWindowOpen);
+        }
+
+        if room.config.treasures > 1 {
+            let opened = |is_a: bool, buried: bool| {
+                !buried || env.storage().temporary().has(&DataKey::Opened(room_id, is_a))
+            };
+            let a_opened = opened(true, room.has_commitment_a);
+            let b_opened = opened(false, room.has_commitment_b);
+            if !(a_opened && b_opened) && now < exhausted_at.saturating_add(2 * window) {
+                panic_with_error!(&env, Error::RevealWindowOpen);
+            }
+            if a_opened != b_opened {
+                let winner = if a_opened { room.player_a.clone() } else { room.player_b.clone() };
+
+                // Notify Game Hub BEFORE mutating local state.
+                Self::report_result(&env, &room, a_opened);
+
+                Self::record_game(&env, &room.player_a, true);
+                Self::record_game(&env, &room.player_b, true);
+
+                Self::settle_stakes(&env, &room, Some(&winner));
+                room.winner = winner.clone();
+                Self::untrack_room(&env, &room);
+                room.game_active = false;
+                room.phase = 3;
+
+                env.storage().temporary().set(&key, &room);
+                bump_temp(&env, &key);
+                bump_instance(&env);
+                emit_room_event(&env, &room, symbol_short!("unopened"), &winner);
+                return;
+            }
+        }
+
+        // Notify Game Hub BEFORE mutating local state. A drawn series
+        // game counts for nobody and the series session stays open.
+        if room.series_id.is_none() {
+            Self::notify_hub(&env, &room, HubReport::Drawn(room_id));
+        }
+
+        Self::update_ratings(&env, &room, 5_000);
+        Self::update_stats(&env, &room, 5_000);
+        Self::record_game(&env, &room.player_a, true);
+        Self::record_game(&env, &room.player_b, true);
+
+        Self::settle_stakes(&env, &room, None);
+        Self::untrack_room(&env, &room);
+        room.game_active = false;
+        room.phase = 4;
+
+        env.storage().temporary().set(&key, &room);
+        bump_temp(&env, &key);
+        bump_instance(&env);
+        emit_room_event(&env, &room, symbol_short!("draw"), &caller);
+    }
+
+    /// Win a game the opponent has stalled: they are on turn (or, in a
+    /// simultaneous room, behind in the current round) and have not moved
+    /// by the turn deadline. Once the board is exhausted the reveal window
+    /// and `finalize_draw` take over instead.
+    fn claim_timeout(env: Env, room_id: u32, claimant: Address) {
+        claimant.require_auth();
+
+        let key = DataKey::Room(room_id);
+        let mut room = Self::get_room(env.clone(), room_id);
+        Self::require_phase(&env, &room, 2);
+        let claimant_is_a = if claimant == room.player_a {
+            true
+        } else if claimant == room.player_b {
+            false
+        } else {
+            panic_with_error!(&env, Error::NotAPlayer);
+        };
+        if room.exhausted_at.is_some() {
+            panic_with_error!(&env, Error::RevealWindowOpen);
+        }
+
+        let stalled = if room.config.dig_mode == DigMode::Simultaneous {
+            // The claimant must have done this round's step the opponent
+            // still owes: commit, then reveal.
+            let read = |is_a: bool| -> Option<PendingDig> {
+                env.storage().temporary().get(&DataKey::RoundDig(room_id, is_a))
+            };
+            match (read(claimant_is_a), read(!claimant_is_a)) {
+                (Some(_), None) => true,
+                (Some(own), Some(other)) => own.revealed && !other.revealed,
+                _ => false,
+            }
+        } else {
+            room.turn_is_a != claimant_is_a
+        };
+        if !stalled || env.ledger().sequence() <= room.turn_deadline {
+            panic_with_error!(&env, Error::TurnNotExpired);
+        }
+
+        // Notify Game Hub BEFORE mutating local state.
+        Self::report_result(&env, &room, claimant_is_a);
+
+        // The staller's game stays started but never completed.
+        Self::record_game(&env, &claimant, true);
+
+        Self::settle_stakes(&env, &room, Some(&claimant));
+        room.winner = claimant.clone();
+        Self::untrack_room(&env, &room);
+        room.game_active = false;
+        room.phase = 3;
+
+        env.storage().temporary().set(&key, &room);
+        bump_temp(&env, &key);
+        bump_instance(&env);
+        emit_room_event(&env, &room, symbol_short!("timeout"), &claimant);
+    }
+
+    /// Concede a started game, during burial or play. The opponent wins.
+    fn forfeit(env: Env, room_id: u32, player: Address) {
+        player.require_auth();
+
+        let key = DataKey::Room(room_id);
+        let mut room = Self::get_room(env.clone(), room_id);
+        if room.phase != 1 {
+            Self::require_phase(&env, &room, 2);
+        } else if room.frozen {
+            panic_with_error!(&env, Error::RoomFrozen);
+        }
+        let opponent_is_a = if player == room.player_a {
+            false
+        } else if player == room.player_b {
+            true
+        } else {
+            panic_with_error!(&env, Error::NotAPlayer);
+        };
+        let opponent = if opponent_is_a { room.player_a.clone() } else { room.player_b.clone() };
+
+        // Notify Game Hub BEFORE mutating local state.
+        Self::report_result(&env, &room, opponent_is_a);
+
+        Self::record_game(&env, &room.player_a, true);
+        Self::record_game(&env, &room.player_b, true);
+
+        Self::settle_stakes(&env, &room, Some(&opponent));
+        room.winner = opponent;
+        Self::untrack_room(&env, &room);
+        room.game_active = false;
+        room.phase = 3;
+
+        env.storage().temporary().set(&key, &room);
+        bump_temp(&env, &key);
+        bump_instance(&env);
+        emit_room_event(&env, &room, symbol_short!("forfeited"), &player);
+    }
+
+    /// Win a game whose opponent never buried: `player` has committed,
+    /// the opponent has not, and the burial deadline has passed.
+    fn claim_abandoned(env: Env, room_id: u32, player: Address) {
+        player.require_auth();
+
+        let key = DataKey::Room(room_id);
+        let mut room = Self::get_room(env.clone(), room_id);
+        Self::require_phase(&env, &room, 1);
+        let (is_a, own, other) = if player == room.player_a {
+            (true, room.has_commitment_a, room.has_commitment_b)
+        } else if player == room.player_b {
+            (false, room.has_commitment_b, room.has_commitment_a)
+        } else {
+            panic_with_error!(&env, Error::NotAPlayer);
+        };
+        if !own || other {
+            panic_with_error!(&env, Error::CommitmentMissing);
+        }
+        if env.ledger().sequence() <= room.turn_deadline {
+            panic_with_error!(&env, Error::BurialWindowOpen);
+        }
+
+        // Notify Game Hub BEFORE mutating local state.
+        Self::report_result(&env, &room, is_a);
+
+        // The absent player's game stays started but never completed.
+        Self::record_game(&env, &player, true);
+
+        Self::settle_stakes(&env, &room, Some(&player));
+        room.winner = player.clone();
+        Self::untrack_room(&env, &room);
+        room.game_active = false;
+        room.phase = 3;
+
+        env.storage().temporary().set(&key, &room);
+        bump_temp(&env, &key);
+        bump_instance(&env);
+        emit_room_event(&env, &room, symbol_short!("abandoned"), &player);
+    }
+
+    /// Call off a started game neither player buried in by the burial
+    /// deadline. There is no result: the hub session is aborted, escrowed
+    /// stakes are refunded, and the room ends without a winner, leaving
+    /// ratings and stats untouched. An aborted series game counts for
+    /// nobody and the series session stays open.
+    fn abort_room(env: Env, room_id: u32, player: Address) {
+        player.require_auth();
+
+        let key = DataKey::Room(room_id);
+        let mut room = Self::get_room(env.clone(), room_id);
+        Self::require_phase(&env, &room, 1);
+        if player != room.player_a && player != room.player_b {
+            panic_with_error!(&env, Error::NotAPlayer);
+        }
+        if room.has_commitment_a || room.has_commitment_b {
+            panic_with_error!(&env, Error::AlreadyBuried);
+        }
+        if env.ledger().sequence() <= room.turn_deadline {
+            panic_with_error!(&env, Error::BurialWindowOpen);
+        }
+
+        // Notify Game Hub BEFORE mutating local state.
+        if room.series_id.is_none() {
+            Self::notify_hub(&env, &room, HubReport::Aborted(room_id));
+        }
+
+        Self::settle_stakes(&env, &room, None);
+        Self::untrack_room(&env, &room);
+        room.game_active = false;
+        room.phase = 4;
+
+        env.storage().temporary().set(&key, &room);
+        bump_temp(&env, &key);
+        bump_instance(&env);
+        emit_room_event(&env, &room, symbol_short!("aborted"), &player);
+    }
+
+    /// Resend a room's hub report that failed when the game finished, to
+    /// the hub it was meant for. Anyone may call this.
+    fn retry_hub_report(env: Env, room_id: u32) {
+        let key = DataKey::PendingHubReport(room_id);
+        let pending: PendingHubReport = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::NoPendingReport));
+
+        let hub = GameHubClient::new(&env, &pending.hub);
+        if !Self::send_hub_report(&env, &hub, &pending.report) {
+            panic_with_error!(&env, Error::HubUnavailable);
+        }
+        env.storage().temporary().remove(&key);
+        // The pending report is a room key, so the room is still there.
+        let room = Self::get_room(env.clone(), room_id);
+        emit_room_event(&env, &room, symbol_short!("hubsent"), &pending.hub);
+    }
+
+    fn get_pending_hub_report(env: Env, room_id: u32) -> Option<PendingHubReport> {
+        env.storage()
+            .temporary()
+            .get(&DataKey::PendingHubReport(room_id))
+    }
+
+    /// Commit/reveal progress of the current simultaneous-dig round.
+    fn get_round_state(env: Env, room_id: u32) -> RoundState {
+        let read = |is_a: bool| -> Option<PendingDig> {
+            env.storage().temporary().get(&DataKey::RoundDig(room_id, is_a))
+        };
+        let a = read(true);
+        let b = read(false);
+        RoundState {
+            committed_a: a.is_some(),
+            committed_b: b.is_some(),
+            revealed_a: a.is_some_and(|d| d.revealed),
+            revealed_b: b.is_some_and(|d| d.revealed),
+        }
+    }
+
+    /// Claim victory as the hider once the seeker has spent their whole dig
+    /// budget without revealing the treasure. `leaves` open the hider's
+    /// burial, to show there were treasures on the board to find.
+    fn claim_hider_win(env: Env, room_id: u32, hider: Address, leaves: Vec<TreasureLeaf>) {
+        hider.require_auth();
+
+        let key = DataKey::Room(room_id);
+        let mut room = Self::get_room(env.clone(), room_id);
+        Self::require_phase(&env, &room, 2);
+        let hider_is_a = Self::hider_is_a(&room)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::WrongMode));
+        let expected = if hider_is_a { &room.player_a } else { &room.player_b };
+        if hider != *expected {
+            panic_with_error!(&env, Error::NotAPlayer);
+        }
+        if room.digs.len() < room.config.dig_budget {
+            panic_with_error!(&env, Error::BudgetRemaining);
+        }
+        Self::require_opening(&env, &room, hider_is_a, &leaves);
+
+        // Notify Game Hub BEFORE mutating local state.
+        Self::report_result(&env, &room, hider_is_a);
+
+        Self::record_game(&env, &room.player_a, true);
+        Self::record_game(&env, &room.player_b, true);
+
+        Self::settle_stakes(&env, &room, Some(&hider));
+        room.winner = hider.clone();
+        Self::untrack_room(&env, &room);
+        room.game_active = false;
+        room.phase = 3;
+
+        env.storage().temporary().set(&key, &room);
+        bump_temp(&env, &key);
+        bump_instance(&env);
+        emit_room_event(&env, &room, symbol_short!("survived"), &hider);
+    }
+
+    // ── Reveal phase ───────────────────────────────────────────────────
+
+    /// Reveal the OPPONENT's treasure to claim victory.
+    ///
+    /// The caller provides (island_id, tile_id, salt). The contract
+    /// rehashes and checks against the **opponent's** stored commitment.
+    /// If the hash matches, the caller wins. In simultaneous rooms there is
+    /// no turn order, so either player may reveal at any time.
+    fn reveal_treasure(
+        env: Env,
+        room_id: u32,
+        player: Address,
+        island_id: u32,
+        tile_id: u32,
+        salt: BytesN<32>,
+    ) {
+        player.require_auth();
+
+        Self::reveal(&env, room_id, player, island_id, tile_id, salt, false, None);
+    }
+
+    /// Reveal one of the opponent's treasures in a multi-treasure room.
+    /// `proof` holds the sibling hashes from the treasure's commitment up
+    /// to the buried Merkle root, where each parent is SHA-256 of its two
+    /// children in ascending order. The caller wins on finding a majority
+    /// of the opponent's treasures; a find short of that keeps the turn.
+    ///
+    /// In a duel, the find that wins must also open all of the caller's
+    /// own treasures in `leaves` (see `open_treasures`). Pass an empty
+    /// list for any earlier find, as leaves sent then give them away.
+    #[allow(clippy::too_many_arguments)]
+    fn reveal_treasure_proof(
+        env: Env,
+        room_id: u32,
+        player: Address,
+        island_id: u32,
+        tile_id: u32,
+        salt: BytesN<32>,
+        proof: Vec<BytesN<32>>,
+        leaves: Vec<TreasureLeaf>,
+    ) {
+        player.require_auth();
+
+        let proof = Some((proof, leaves));
+        Self::reveal(&env, room_id, player, island_id, tile_id, salt, false, proof);
+    }
+
+    /// Open all of `player`'s treasures in a multi-treasure room, once the
+    /// exhausted board's reveal window has closed and they can no longer be
+    /// found. `leaves` are in the order the Merkle root was built from:
+    /// adjacent nodes paired level by level, an odd last node carried up.
+    /// They must be `treasures` distinct tiles on the board.
+    fn open_treasures(env: Env, room_id: u32, player: Address, leaves: Vec<TreasureLeaf>) {
+        player.require_auth();
+
+        let room = Self::get_room(env.clone(), room_id);
+        Self::require_phase(&env, &room, 2);
+        if room.config.treasures <= 1 {
+            panic_with_error!(&env, Error::WrongMode);
+        }
+        let is_a = if player == room.player_a {
+            true
+        } else if player == room.player_b {
+            false
+        } else {
+            panic_with_error!(&env, Error::NotAPlayer);
+        };
+        let exhausted_at = room
+            .exhausted_at
+            .unwrap_or_else(|| panic_with_error!(&env, Error::BoardNotExhausted));
+        let window = room.config.reveal_window_ledgers;
+        if env.ledger().sequence() < exhausted_at.saturating_add(window) {
+            panic_with_error!(&env, Error::RevealWindowOpen);
+        }
+        Self::require_opening(&env, &room, is_a, &leaves);
+
+        let key = DataKey::Opened(room_id, is_a);
+        env.storage().temporary().set(&key, &true);
+        bump_temp(&env, &key);
+        emit_room_event(&env, &room, symbol_short!("opened"), &player);
+    }
+
+    /// Reveal the opponent's treasure in a `derived_salts` room. Instead of
+    /// the salt, the caller passes the opponent's ed25519 signature over
+    /// `get_salt_message`, made with their registered burial key; the
+    /// salt is SHA-256 of that signature. Ed25519 signatures are
+    /// deterministic, so the scheme yields a strong salt a client can
+    /// always re-derive, and the contract can check it was followed.
+    fn reveal_treasure_derived(
+        env: Env,
+        room_id: u32,
+        player: Address,
+        island_id: u32,
+        tile_id: u32,
+        salt_signature: BytesN<64>,
+    ) {
+        player.require_auth();
+
+        let room = Self::get_room(env.clone(), room_id);
+        let owner = if player == room.player_a {
+            room.player_b
+        } else {
+            room.player_a
+        };
+        let public_key: BytesN<32> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::BurialKey(owner))
+            .unwrap_or_else(|| panic_with_error!(&env, Error::BurialKeyMissing));
+        let msg = Self::get_salt_message(env.clone(), room_id);
+        env.crypto().ed25519_verify(&public_key, &msg, &salt_signature);
+
+        let salt = env.crypto().sha256(&salt_signature.into()).to_bytes();
+        Self::reveal(&env, room_id, player, island_id, tile_id, salt, true, None);
+    }
+
+    // ── Post-game ──────────────────────────────────────────────────────
+
+    /// Send a voluntary tip of `amount` of `token` to the opponent after the
+    /// game has ended. The transfer goes directly from `from` to the
+    /// opponent and is recorded against the room.
+    fn tip(env: Env, room_id: u32, from: Address, token: Address, amount: i128) {
+        from.require_auth();
+
+        let room = Self::get_room(env.clone(), room_id);
+        Self::require_ended(&env, &room);
+        let to = if from == room.player_a {
+            room.player_b.clone()
+        } else if from == room.player_b {
+            room.player_a.clone()
+        } else {
+            panic_with_error!(&env, Error::NotAPlayer);
+        };
+        if amount <= 0 {
+            panic_with_error!(&env, Error::InvalidAmount);
+        }
+
+        token::Client::new(&env, &token).transfer(&from, &to, &amount);
+
+        let key = DataKey::Tips(room_id);
+        let mut tips: Vec<Tip> = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .unwrap_or_else(|| Vec::new(&env));
+        tips.push_back(Tip {
+            from: from.clone(),
+            to,
+            token,
+            amount,
+        });
+        env.storage().temporary().set(&key, &tips);
+        bump_temp(&env, &key);
+        emit_room_event(&env, &room, symbol_short!("tipped"), &from);
+    }
+
+    /// Report a finished game as suspicious (collusion, bots, ...).
+    ///
+    /// Anyone may report, once per room and at most
+    /// `max_reports_per_reporter` times (see `Config`) per
+    /// `REPORT_WINDOW_LEDGERS`. Rooms keep at most `REPORTS_CAP` reports.
+    /// Operators read them via `get_reports`.
+    fn report_game(env: Env, room_id: u32, reporter: Address, reason: Symbol) {
+        reporter.require_auth();
+
+        let room = Self::get_room(env.clone(), room_id);
+        Self::require_ended(&env, &room);
+
+        let key = DataKey::Reports(room_id);
+        let mut reports: Vec<GameReport> = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .unwrap_or_else(|| Vec::new(&env));
+        for r in reports.iter() {
+            if r.reporter == reporter {
+                panic_with_error!(&env, Error::AlreadyReported);
+            }
+        }
+        if reports.len() >= REPORTS_CAP {
+            panic_with_error!(&env, Error::TooManyReports);
+        }
+
+        let window_key = DataKey::ReportWindow(reporter.clone());
+        let now = env.ledger().sequence();
+        let mut window = env
+            .storage()
+            .temporary()
+            .get(&window_key)
+            .filter(|w: &ReportWindow| now < w.start.saturating_add(REPORT_WINDOW_LEDGERS))
+            .unwrap_or(ReportWindow { start: now, count: 0 });
+        let max =
+            instance_u32(&env, &DataKey::MaxReportsPerReporter, DEFAULT_REPORTS_PER_REPORTER);
+        if window.count >= max {
+            panic_with_error!(&env, Error::TooManyReports);
+        }
+        window.count += 1;
+        env.storage().temporary().set(&window_key, &window);
+        bump_temp(&env, &window_key);
+
+        reports.push_back(GameReport {
+            reporter: reporter.clone(),
+            reason,
+            ledger: env.ledger().sequence(),
+        });
+        env.storage().temporary().set(&key, &reports);
+        bump_temp(&env, &key);
+        emit_room_event(&env, &room, symbol_short!("reported"), &reporter);
+    }
+
+    // ── Matchmaking ────────────────────────────────────────────────────
+
+    /// Wait for an opponent staking within the same power of two as
+    /// `points`. If one is already waiting, a default room is created
+    /// under the next free id with them as Player A and `player` as Player
+    /// B, started at once, and its id returned; entering the queue is each
+    /// player's consent to that start. Otherwise `player` waits and `None`
+    /// is returned. The waiting player does not sign the match, so this
+    /// fails with `HubEscrows` while the contract's hub escrows points.
+    fn enter_queue(env: Env, player: Address, points: i128) -> Option<u32> {
+        player.require_auth();
+        Self::require_unescrowed_hub(&env);
+
+        let key = DataKey::Queue(queue_bucket(&env, points));
+        let Some(waiting) = env.storage().temporary().get::<_, QueueEntry>(&key) else {
+            env.storage().temporary().set(&key, &QueueEntry { player, points });
+            bump_temp(&env, &key);
+            return None;
+        };
+        if waiting.player == player {
+            panic_with_error!(&env, Error::AlreadyQueued);
+        }
+        env.storage().temporary().remove(&key);
+
+        let room_id = Self::next_room_id(&env);
+        Self::new_room(
+            env.clone(),
+            room_id,
+            waiting.player.clone(),
+            waiting.points,
+            default_room_config(),
+        );
+        Self::join(env.clone(), room_id, player.clone(), points, None);
+        Self::start_room_authorized(&env, room_id, &waiting.player, &player, waiting.points, points);
+        Some(room_id)
+    }
+
+    /// Stop waiting in the queue `points` falls into.
+    fn leave_queue(env: Env, player: Address, points: i128) {
+        player.require_auth();
+
+        let key = DataKey::Queue(queue_bucket(&env, points));
+        let waiting: Option<QueueEntry> = env.storage().temporary().get(&key);
+        if waiting.is_none_or(|w| w.player != player) {
+            panic_with_error!(&env, Error::NotQueued);
+        }
+        env.storage().temporary().remove(&key);
+    }
+
+    /// Who is waiting in the queue `points` falls into, if anyone.
+    fn get_queue(env: Env, points: i128) -> Option<QueueEntry> {
+        env.storage()
+            .temporary()
+            .get(&DataKey::Queue(queue_bucket(&env, points)))
+    }
+
+    /// Challenge `opponent` to a game at `points`. The challenge waits
+    /// until the opponent accepts or declines it.
+    fn challenge(env: Env, challenger: Address, opponent: Address, points: i128) {
+        challenger.require_auth();
+        Self::require_unescrowed_hub(&env);
+
+        if challenger == opponent {
+            panic_with_error!(&env, Error::SelfPlay);
+        }
+        if points <= 0 {
+            panic_with_error!(&env, Error::InvalidAmount);
+        }
+        let key = DataKey::Challenge(challenger, opponent);
+        if env.storage().temporary().has(&key) {
+            panic_with_error!(&env, Error::ChallengeExists);
+        }
+        env.storage().temporary().set(&key, &points);
+        bump_temp(&env, &key);
+    }
+
+    /// Accept a pending challenge, putting up `opponent_points`. A default
+    /// room is created under the next free id with the challenger as
+    /// Player A, and started at once; issuing the challenge was the
+    /// challenger's consent to that start. The challenger does not sign
+    /// here, so this fails with `HubEscrows` while the contract's hub
+    /// escrows points.
+    fn accept_challenge(
+        env: Env,
+        challenger: Address,
+        opponent: Address,
+        opponent_points: i128,
+    ) -> Room {
+        opponent.require_auth();
+        Self::require_unescrowed_hub(&env);
+
+        let key = DataKey::Challenge(challenger.clone(), opponent.clone());
+        let points: i128 = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::ChallengeNotFound));
+        env.storage().temporary().remove(&key);
+
+        let room_id = Self::next_room_id(&env);
+        Self::new_room(env.clone(), room_id, challenger.clone(), points, default_room_config());
+        Self::join(env.clone(), room_id, opponent.clone(), opponent_points, None);
+        Self::start_room_authorized(&env, room_id, &challenger, &opponent, points, opponent_points)
+    }
+
+    /// Turn down a pending challenge.
+    fn decline_challenge(env: Env, challenger: Address, opponent: Address) {
+        opponent.require_auth();
+
+        let key = DataKey::Challenge(challenger, opponent);
+        if !env.storage().temporary().has(&key) {
+            panic_with_error!(&env, Error::ChallengeNotFound);
+        }
+        env.storage().temporary().remove(&key);
+    }
+
+    /// Points of the pending challenge from `challenger` to `opponent`, if
+    /// any.
+    fn get_challenge(env: Env, challenger: Address, opponent: Address) -> Option<i128> {
+        env.storage()
+            .temporary()
+            .get(&DataKey::Challenge(challenger, opponent))
+    }
+
+    // ── Series ─────────────────────────────────────────────────────────
+
+    /// Open a series between two players, won by the first to
+    /// `wins_needed` games. Both players authorize.
+    fn create_series(
+        env: Env,
+        series_id: u32,
+        player_a: Address,
+        player_b: Address,
+        wins_needed: u32,
+    ) -> Series {
+        player_a.require_auth();
+        player_b.require_auth();
+
+        let key = DataKey::Series(series_id);
+        if env.storage().temporary().has(&key) {
+            panic_with_error!(&env, Error::SeriesExists);
+        }
+        if player_a == player_b {
+            panic_with_error!(&env, Error::SelfPlay);
+        }
+        if wins_needed == 0 {
+            panic_with_error!(&env, Error::InvalidConfig);
+        }
+
+        let series = Series {
+            series_id,
+            player_a,
+            player_b,
+            wins_needed,
+            wins_a: 0,
+            wins_b: 0,
+            rooms: Vec::new(&env),
+            session_id: None,
+            winner: None,
+        };
+        env.storage().temporary().set(&key, &series);
+        bump_temp(&env, &key);
+        series
+    }
+
+    /// Create the series' next game as a room with both players seated,
+    /// ready for `start_room`. The previous game must have ended. Series
+    /// rooms cannot be private, since both players are already seated.
+    fn create_series_room(
+        env: Env,
+        series_id: u32,
+        room_id: u32,
+        player_a_points: i128,
+        player_b_points: i128,
+        config: RoomConfig,
+    ) -> Room {
+        let key = DataKey::Series(series_id);
+        let mut series = Self::get_series(env.clone(), series_id);
+        series.player_a.require_auth();
+        series.player_b.require_auth();
+
+        if series.winner.is_some() {
+            panic_with_error!(&env, Error::SeriesOver);
+        }
+        if let Some(last) = series.rooms.last() {
+            let prev: Option<Room> = env.storage().temporary().get(&DataKey::Room(last));
+            if prev.as_ref().is_some_and(|r| r.phase < 3) {
+                panic_with_error!(&env, Error::SeriesGameLive);
+            }
+            // The series is a single hub session, so its games share a hub.
+            if prev.is_some_and(|r| {
+                r.config.hub != config.hub || r.config.hubless != config.hubless
+            }) {
+                panic_with_error!(&env, Error::InvalidConfig);
+            }
+        }
+        if config.invite_hash.is_some() {
+            panic_with_error!(&env, Error::InvalidConfig);
+        }
+
+        Self::new_room(env.clone(), room_id, series.player_a.clone(), player_a_points, config);
+        let mut room =
+            Self::join(env.clone(), room_id, series.player_b.clone(), player_b_points, None);
+        room.series_id = Some(series_id);
+        let room_key = DataKey::Room(room_id);
+        env.storage().temporary().set(&room_key, &room);
+        bump_temp(&env, &room_key);
+
+        series.rooms.push_back(room_id);
+        env.storage().temporary().set(&key, &series);
+        bump_temp(&env, &key);
+        room
+    }
+
+    fn get_series(env: Env, series_id: u32) -> Series {
+        env.storage()
+            .temporary()
+            .get(&DataKey::Series(series_id))
+            .unwrap_or_else(|| panic_with_error!(&env, Error::SeriesNotFound))
+    }
+
+    // ── Betting ────────────────────────────────────────────────────────
+
+    /// Back one player of a started room with `amount` of the room's
+    /// stake token, until the game ends. Rooms without a stake token take
+    /// no bets. A bettor may add to their bet but not switch sides.
+    fn place_bet(env: Env, room_id: u32, bettor: Address, on_player_a: bool, amount: i128) {
+        bettor.require_auth();
+
+        let room = Self::get_room(env.clone(), room_id);
+        if room.phase != 1 {
+            Self::require_phase(&env, &room, 2);
+        } else if room.frozen {
+            panic_with_error!(&env, Error::RoomFrozen);
+        }
+        let token = room
+            .config
+            .stake_token
+            .clone()
+            .unwrap_or_else(|| panic_with_error!(&env, Error::WrongMode));
+        if bettor == room.player_a || bettor == room.player_b {
+            panic_with_error!(&env, Error::BettorIsPlayer);
+        }
+        if amount <= 0 {
+            panic_with_error!(&env, Error::InvalidAmount);
+        }
+
+        let bet_key = DataKey::Bet(room_id, bettor.clone());
+        let mut bet: Bet = env.storage().temporary().get(&bet_key).unwrap_or(Bet {
+            on_player_a,
+            amount: 0,
+        });
+        if bet.on_player_a != on_player_a {
+            panic_with_error!(&env, Error::BetSideMismatch);
+        }
+        token::Client::new(&env, &token).transfer(
+            &bettor,
+            env.current_contract_address(),
+            &amount,
+        );
+
+        bet.amount += amount;
+        env.storage().temporary().set(&bet_key, &bet);
+        bump_temp(&env, &bet_key);
+        let book_key = DataKey::BetBook(room_id);
+        let mut book = Self::get_bet_book(env.clone(), room_id);
+        if on_player_a {
+            book.total_a += amount;
+        } else {
+            book.total_b += amount;
+        }
+        env.storage().temporary().set(&book_key, &book);
+        bump_temp(&env, &book_key);
+        emit_room_event(&env, &room, symbol_short!("bet"), &bettor);
+    }
+
+    /// Collect a bet once the game has ended. Backers of the winner split
+    /// the whole book pro rata to their stakes; after a draw, or when
+    /// nobody backed the winner, every bet is refunded. Returns the amount
+    /// paid.
+    fn claim_bet(env: Env, room_id: u32, bettor: Address) -> i128 {
+        bettor.require_auth();
+
+        let room = Self::get_room(env.clone(), room_id);
+        if room.phase != 4 {
+            Self::require_phase(&env, &room, 3);
+        }
+        let bet_key = DataKey::Bet(room_id, bettor.clone());
+        let bet: Bet = env
+            .storage()
+            .temporary()
+            .get(&bet_key)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::NothingToClaim));
+
+        let book = Self::get_bet_book(env.clone(), room_id);
+        let a_won = room.winner == room.player_a;
+        let winning = if a_won { book.total_a } else { book.total_b };
+        let payout = if room.phase == 4 || winning == 0 {
+            bet.amount
+        } else if bet.on_player_a == a_won {
+            bet.amount * (book.total_a + book.total_b) / winning
+        } else {
+            panic_with_error!(&env, Error::NothingToClaim);
+        };
+
+        env.storage().temporary().remove(&bet_key);
+        // Room stake tokens only ever come from the room's config.
+        let token = room.config.stake_token.clone().unwrap();
+        token::Client::new(&env, &token).transfer(
+            &env.current_contract_address(),
+            &bettor,
+            &payout,
+        );
+        emit_room_event(&env, &room, symbol_short!("betpaid"), &bettor);
+        payout
+    }
+
+    /// Total stakes on each player of a room.
+    fn get_bet_book(env: Env, room_id: u32) -> BetBook {
+        env.storage()
+            .temporary()
+            .get(&DataKey::BetBook(room_id))
+            .unwrap_or(BetBook {
+                total_a: 0,
+                total_b: 0,
+            })
+    }
+
+    // ── Observers ──────────────────────────────────────────────────────
+
+    /// Subscribe `observer` to a room's events. Watching a room twice is a
+    /// no-op.
+    fn watch_room(env: Env, room_id: u32, observer: Address) {
+        observer.require_auth();
+        Self::get_room(env.clone(), room_id);
+
+        let key = DataKey::Observers(room_id);
+        let mut observers = Self::get_observers(env.clone(), room_id);
+        if observers.contains(&observer) {
+            return;
+        }
+        if observers.len() >= MAX_OBSERVERS {
+            panic_with_error!(&env, Error::TooManyObservers);
+        }
+        observers.push_back(observer);
+        env.storage().temporary().set(&key, &observers);
+        bump_temp(&env, &key);
+    }
+
+    /// Stop sending `observer` a room's events.
+    fn unwatch_room(env: Env, room_id: u32, observer: Address) {
+        observer.require_auth();
+
+        let key = DataKey::Observers(room_id);
+        let mut observers = Self::get_observers(env.clone(), room_id);
+        if let Some(i) = observers.first_index_of(&observer) {
+            observers.remove(i);
+            env.storage().temporary().set(&key, &observers);
+            bump_temp(&env, &key);
+        }
+    }
+
+    /// Addresses currently watching a room.
+    fn get_observers(env: Env, room_id: u32) -> Vec<Address> {
+        env.storage()
+            .temporary()
+            .get(&DataKey::Observers(room_id))
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    // ── Read-only helpers ──────────────────────────────────────────────
+
+    /// Read room state (returns the Room struct or panics).
+    fn get_room(env: Env, room_id: u32) -> Room {
+        let key = DataKey::Room(room_id);
+        env.storage()
+            .temporary()
+            .get(&key)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::RoomNotFound))
+    }
+
+    /// Alias used by the frontend service layer.
+    fn get_game(env: Env, room_id: u32) -> Room {
+        Self::get_room(env, room_id)
+    }
+
+    /// Read room state in the original `Room` layout, for consumers that
+    /// have not migrated to the current one yet.
+    fn get_room_v1(env: Env, room_id: u32) -> RoomV1 {
+        Self::get_room(env, room_id).into()
+    }
+
+    /// A page of the tips sent in a room, in order.
+    fn get_tips(env: Env, room_id: u32, cursor: Cursor) -> TipPage {
+        let tips = env
+            .storage()
+            .temporary()
+            .get(&DataKey::Tips(room_id))
+            .unwrap_or_else(|| Vec::new(&env));
+        let (items, next_cursor) = Self::page(tips, &cursor);
+        TipPage { items, next_cursor }
+    }
+
+    /// When each commitment was buried. Does not expose the commitments.
+    fn get_burial_ledgers(env: Env, room_id: u32) -> BurialLedgers {
+        Self::get_room(env.clone(), room_id);
+        let storage = env.storage().temporary();
+        BurialLedgers {
+            player_a: storage.get(&DataKey::BuriedAt(room_id, true)),
+            player_b: storage.get(&DataKey::BuriedAt(room_id, false)),
+        }
+    }
+
+    /// Publish both stored commitments and any reveals once the room has
+    /// ended, so anyone can re-verify the outcome. Refuses while the game is
+    /// still running.
+    fn get_commitments(env: Env, room_id: u32) -> PublishedCommitments {
+        let room = Self::get_room(env.clone(), room_id);
+        Self::require_ended(&env, &room);
+        let storage = env.storage().temporary();
+        PublishedCommitments {
+            commitment_a: storage.get(&DataKey::Commitment(room_id, true)),
+            commitment_b: storage.get(&DataKey::Commitment(room_id, false)),
+            reveals: storage
+                .get(&DataKey::Reveals(room_id))
+                .unwrap_or_else(|| Vec::new(&env)),
+        }
+    }
+
+    /// A page of the reports filed against a room, in filing order.
+    fn get_reports(env: Env, room_id: u32, cursor: Cursor) -> ReportPage {
+        let reports = env
+            .storage()
+            .temporary()
+            .get(&DataKey::Reports(room_id))
+            .unwrap_or_else(|| Vec::new(&env));
+        let (items, next_cursor) = Self::page(reports, &cursor);
+        ReportPage { items, next_cursor }
+    }
+
+    /// A page of the room's dig log, oldest dig first.
+    fn get_digs(env: Env, room_id: u32, cursor: Cursor) -> DigPage {
+        let room = Self::get_room(env, room_id);
+        let (items, next_cursor) = Self::page(room.digs, &cursor);
+        DigPage { items, next_cursor }
+    }
+
+    /// A page of the lobby: rooms still waiting for an opponent, oldest
+    /// first.
+    fn get_open_rooms(env: Env, cursor: Cursor) -> RoomPage {
+        let ids: Vec<u32> = env
+            .storage()
+            .temporary()
+            .get(&DataKey::OpenRooms)
+            .unwrap_or_else(|| Vec::new(&env));
+        let (ids, next_cursor) = Self::page(ids, &cursor);
+        let mut items = Vec::new(&env);
+        for id in ids.iter() {
+            if let Some(room) = env.storage().temporary().get(&DataKey::Room(id)) {
+                items.push_back(room);
+            }
+        }
+        RoomPage { items, next_cursor }
+    }
+
+    /// A page of the ids of the rooms `player` sits in that have not ended
+    /// yet, in the order they were created or joined.
+    fn get_rooms_by_player(env: Env, player: Address, cursor: Cursor) -> RoomIdPage {
+        let ids: Vec<u32> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::PlayerRooms(player))
+            .unwrap_or_else(|| Vec::new(&env));
+        let (ids, next_cursor) = Self::page(ids, &cursor);
+        let mut items = Vec::new(&env);
+        for id in ids.iter() {
+            if env.storage().temporary().has(&DataKey::Room(id)) {
+                items.push_back(id);
+            }
+        }
+        RoomIdPage { items, next_cursor }
+    }
+
+    /// The most recent events for a room, oldest first (at most the
+    /// configured `max_recent_events`). Empty if the room has no recorded events.
+    fn get_recent_events(env: Env, room_id: u32) -> Vec<RoomEvent> {
+        env.storage()
+            .temporary()
+            .get(&DataKey::Events(room_id))
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    /// Check a claimed pre-image against `owner`'s stored commitment using the
+    /// room's scheme. Lets clients (and future proof verifiers) confirm a
+    /// reveal without submitting it.
+    fn verify_commitment(
+        env: Env,
+        room_id: u32,
+        owner: Address,
+        island_id: u32,
+        tile_id: u32,
+        salt: BytesN<32>,
+    ) -> bool {
+        let room = Self::get_room(env.clone(), room_id);
+        let is_a = owner == room.player_a;
+        if !is_a && owner != room.player_b {
+            panic_with_error!(&env, Error::NotAPlayer);
+        }
+        let stored: Option<BytesN<32>> = env
+            .storage()
+            .temporary()
+            .get(&DataKey::Commitment(room_id, is_a));
+        match stored {
+            Some(c) => Self::compute_commitment(&env, &room, &owner, island_id, tile_id, &salt) == c,
+            None => false,
+        }
+    }
+
+    /// Export the final result of an ended room as XDR-encoded `Attestation`.
+    ///
+    /// The encoding is deterministic, so both players (or any relayer) can
+    /// sign the returned bytes and a verifier can decode and check them.
+    fn export_attestation(env: Env, room_id: u32) -> Bytes {
+        let room = Self::get_room(env.clone(), room_id);
+        Self::require_ended(&env, &room);
+
+        let transcript_hash = Self::transcript_hash(&env, &room);
+        Attestation {
+            contract: env.current_contract_address(),
+            room_id,
+            player_a: room.player_a,
+            player_b: room.player_b,
+            player_a_points: room.player_a_points,
+            player_b_points: room.player_b_points,
+            winner: room.winner,
+            phase: room.phase,
+            transcript_hash,
+        }
+        .to_xdr(&env)
+    }
+
+    /// SHA-256( xdr(room) ‖ commitment_a ‖ commitment_b ).
+    ///
+    /// Covers players, points, board config, digs, turn and commitments, so
+    /// two parties can agree on the full room state with one comparison.
+    fn get_state_hash(env: Env, room_id: u32) -> BytesN<32> {
+        let room = Self::get_room(env.clone(), room_id);
+        let mut buf = room.to_xdr(&env);
+        Self::append_commitments(&env, room_id, &mut buf);
+        env.crypto().sha256(&buf).to_bytes()
+    }
+
+    /// The message whose ed25519 signature, hashed with SHA-256, is a
+    /// player's salt in a `derived_salts` room:
+    /// "pirate-salt" ‖ room_id ‖ nonce. It changes with every room, so no
+    /// two rooms share a salt.
+    fn get_salt_message(env: Env, room_id: u32) -> Bytes {
+        let room = Self::get_room(env.clone(), room_id);
+        let mut msg = Bytes::from_slice(&env, b"pirate-salt");
+        msg.extend_from_array(&room_id.to_be_bytes());
+        msg.extend_from_array(&room.nonce.to_array());
+        msg
+    }
+
+    /// The rules hash mixed into this room's burial commitments.
+    fn get_rules_hash(env: Env, room_id: u32) -> BytesN<32> {
+        let room = Self::get_room(env.clone(), room_id);
+        Self::rules_hash(&env, &room)
+    }
+
+    /// Everything a client needs to refresh a room, in one read.
+    fn get_full_view(env: Env, room_id: u32) -> FullView {
+        FullView {
+            room: Self::get_room(env.clone(), room_id),
+            burial: Self::get_burial_ledgers(env.clone(), room_id),
+            round: Self::get_round_state(env.clone(), room_id),
+            config: Self::get_config(env),
+        }
+    }
+
+    /// Started and completed game counts for `player`, for lobbies that
+    /// want to surface reliable opponents.
+    fn get_reputation(env: Env, player: Address) -> Reputation {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Reputation(player))
+            .unwrap_or(Reputation {
+                started: 0,
+                completed: 0,
+                score_bps: 0,
+            })
+    }
+
+    /// `player`'s Elo rating, updated after every decided or drawn game.
+    fn get_rating(env: Env, player: Address) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Rating(player))
+            .unwrap_or(INITIAL_RATING)
+    }
+
+    /// Lifetime results of `player`; they outlive the rooms themselves.
+    fn get_player_stats(env: Env, player: Address) -> PlayerStats {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Stats(player))
+            .unwrap_or(PlayerStats {
+                games: 0,
+                wins: 0,
+                losses: 0,
+                draws: 0,
+                total_points_won: 0,
+            })
+    }
+
+    /// Count and size the temporary entries kept for a room, to help
+    /// estimate its rent and tune TTLs.
+    fn get_storage_report(env: Env, room_id: u32) -> StorageReport {
+        let mut report = StorageReport {
+            entries: 0,
+            bytes: 0,
+        };
+        for key in Self::room_keys(room_id) {
+            if let Some(value) = env.storage().temporary().get::<_, Val>(&key) {
+                report.entries += 1;
+                report.bytes += key.to_xdr(&env).len() + value.to_xdr(&env).len();
+            }
+        }
+        report
+    }
+
+    /// Check a room's internal consistency and name every invariant it
+    /// breaks; an empty list means the room is coherent. For monitoring
+    /// and for verifying state after an upgrade.
+    ///
+    /// - `phase`: phase is outside Waiting..=Ended
+    /// - `active`: `game_active` disagrees with the phase
+    /// - `opponent`: no distinct Player B after Waiting
+    /// - `winner`: winner is not a player once ended, or set before
+    /// - `commit_a` / `commit_b`: commitment flag and stored entry disagree,
+    ///   or a hider's commitment is missing once play has begun
+    /// - `digs`: a dig is out of bounds, repeated, made outside play, by a
+    ///   stranger, or past the seeker's budget
+    /// - `actions`: more action points left than a turn grants
+    fn audit_room(env: Env, room_id: u32) -> Vec<Symbol> {
+        let room = Self::get_room(env.clone(), room_id);
+        Self::audit(&env, &room)
+    }
+
+    // ── Upgrade (admin only) ───────────────────────────────────────────
+
+    fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
+        Self::require_admin(&env);
+        env.deployer().update_current_contract_wasm(new_wasm_hash);
+    }
+
+    /// Bring state written by an earlier wasm up to `STATE_VERSION`; call
+    /// it right after `upgrade`. Rooms live in temporary storage, which
+    /// cannot be listed, so the ids of rooms to carry over are passed in
+    /// and may be split across calls. Rooms already in the current layout
+    /// are left alone.
+    fn migrate(env: Env, room_ids: Vec<u32>) {
+        Self::require_admin(&env);
+
+        for room_id in room_ids.iter() {
+            if Self::is_room_v1(&env, room_id) != Some(true) {
+                continue;
+            }
+            let key = DataKey::Room(room_id);
+            let old: RoomV1 = env.storage().temporary().get(&key).unwrap();
+            let room = Self::upgrade_room_v1(&env, old);
+            env.storage().temporary().set(&key, &room);
+            bump_temp(&env, &key);
+        }
+        env.storage().instance().set(&DataKey::StateVersion, &STATE_VERSION);
+        bump_instance(&env);
+    }
+
+    /// What `migrate` would do with `room_ids`, without writing anything,
+    /// so an upgrade can be rehearsed against live state first.
+    fn preview_migration(env: Env, room_ids: Vec<u32>) -> MigrationPreview {
+        let mut preview = MigrationPreview {
+            from_version: Self::get_version(env.clone()),
+            to_version: STATE_VERSION,
+            converted: Vec::new(&env),
+            scheme: CommitmentScheme::LegacySha256,
+            invalid: Vec::new(&env),
+            current: 0,
+            missing: 0,
+        };
+        for room_id in room_ids.iter() {
+            match Self::is_room_v1(&env, room_id) {
+                None => preview.missing += 1,
+                Some(false) => preview.current += 1,
+                Some(true) => {
+                    let key = DataKey::Room(room_id);
+                    let old: RoomV1 = env.storage().temporary().get(&key).unwrap();
+                    let room = Self::upgrade_room_v1(&env, old);
+                    if !Self::audit(&env, &room).is_empty() {
+                        preview.invalid.push_back(room_id);
+                    }
+                    preview.converted.push_back(room_id);
+                }
+            }
+        }
+        preview
+    }
+
+    /// Layout version of the stored state (see `migrate`).
+    fn get_version(env: Env) -> u32 {
+        instance_u32(&env, &DataKey::StateVersion, 1)
+    }
+}
+
+impl PiratesTreasure {
+    // ── Internal ───────────────────────────────────────────────────────
+
+    /// Whether room `room_id` is stored in the original layout; `None` if
+    /// there is no such room.
+    fn is_room_v1(env: &Env, room_id: u32) -> Option<bool> {
+        let key = DataKey::Room(room_id);
+        let fields = env.storage().temporary().get::<_, Map<Symbol, Val>>(&key)?;
+        // `config` is the first field the original layout lacks.
+        Some(!fields.contains_key(Symbol::new(env, "config")))
+    }
+
+    /// A room in the original layout with every later field at its
+    /// default: the default config on the legacy commitment scheme, no
+    /// nonces, and a fresh turn deadline if it is under way.
+    fn upgrade_room_v1(env: &Env, old: RoomV1) -> Room {
+        let mut room = Room {
+            room_id: old.room_id,
+            player_a: old.player_a,
+            player_b: old.player_b,
+            player_a_points: old.player_a_points,
+            player_b_points: old.player_b_points,
+            phase: old.phase,
+            turn_is_a: old.turn_is_a,
+            island_tile_counts: old.island_tile_counts,
+            has_commitment_a: old.has_commitment_a,
+            has_commitment_b: old.has_commitment_b,
+            game_active: old.game_active,
+            winner: old.winner,
+            digs: old.digs,
+            actions_left: 0,
+            config: Self::with_default_timeouts(
+                env,
+                RoomConfig {
+                    commitment_scheme: CommitmentScheme::LegacySha256,
+                    ..default_room_config()
+                },
+            ),
+            nonce: BytesN::from_array(env, &[0u8; 32]),
+            created_nonce: BytesN::from_array(env, &[0u8; 32]),
+            hubless: false,
+            frozen: false,
+            frozen_at: 0,
+            exhausted_at: None,
+            turn_deadline: 0,
+            series_id: None,
+            rematch_of: None,
+        };
+        if room.phase == 1 || room.phase == 2 {
+            Self::start_turn(env, &mut room);
+        }
+        room
+    }
+
+    /// `config` with every timeout left at 0 set to the contract's
+    /// current default, so a later `set_config` never changes the pacing
+    /// of a room already created.
+    fn with_default_timeouts(env: &Env, mut config: RoomConfig) -> RoomConfig {
+        if config.turn_timeout_ledgers == 0 {
+            config.turn_timeout_ledgers =
+                instance_u32(env, &DataKey::TurnTimeout, DEFAULT_TURN_TIMEOUT_LEDGERS);
+        }
+        if config.bury_timeout_ledgers == 0 {
+            config.bury_timeout_ledgers =
+                instance_u32(env, &DataKey::BuryTimeout, DEFAULT_BURY_TIMEOUT_LEDGERS);
+        }
+        if config.reveal_window_ledgers == 0 {
+            config.reveal_window_ledgers =
+                instance_u32(env, &DataKey::RevealWindow, DEFAULT_REVEAL_WINDOW_LEDGERS);
+        }
+        config
+    }
+
+    /// Store a fresh Waiting room after validating its config; the caller
+    /// has already checked authorization.
+    fn new_room(
+        env: Env,
+        room_id: u32,
+        player_a: Address,
+        player_a_points: i128,
+        config: RoomConfig,
+    ) -> Room {
+        Self::require_not_paused(&env);
+        let key = DataKey::Room(room_id);
+        if env.storage().temporary().has(&key) {
+            panic_with_error!(&env, Error::RoomExists);
+        }
+
+        // 3 islands with 10, 20, 30 tiles
+        let mut tile_counts = Vec::new(&env);
+        tile_counts.push_back(10u32);
+        tile_counts.push_back(20u32);
+        tile_counts.push_back(30u32);
+
+        if config.roles != Roles::Duel
+            && (config.dig_mode == DigMode::Simultaneous || config.dig_budget == 0)
+        {
+            panic_with_error!(&env, Error::InvalidConfig);
+        }
+        if config.roles == Roles::Duel && config.hider_odds_bps != 0 {
+            panic_with_error!(&env, Error::InvalidConfig);
+        }
+        if config.min_score_bps > 10_000 {
+            panic_with_error!(&env, Error::InvalidConfig);
+        }
+        if config.action_points != 0 {
+            // Every island must be affordable, and turns must alternate.
+            let max_cost = tile_counts.iter().map(dig_cost).max().unwrap_or(1);
+            if config.action_points < max_cost
+                || config.dig_mode == DigMode::Simultaneous
+                || config.roles != Roles::Duel
+            {
+                panic_with_error!(&env, Error::InvalidConfig);
+            }
+        }
+        // Derived salts are one per player, so they cannot tell several
+        // treasures apart.
+        if config.treasures > MAX_TREASURES || (config.treasures > 1 && config.derived_salts) {
+            panic_with_error!(&env, Error::InvalidConfig);
+        }
+        // A missed guess costs a turn, and simultaneous rooms have none.
+        if config.guess_mode && config.dig_mode == DigMode::Simultaneous {
+            panic_with_error!(&env, Error::InvalidConfig);
+        }
+        if config.hubless && config.hub.is_some() {
+            panic_with_error!(&env, Error::InvalidConfig);
+        }
+        if config.commitment_scheme == CommitmentScheme::LegacySha256 {
+            panic_with_error!(&env, Error::InvalidConfig);
+        }
+        if config.turn_timeout_ledgers > MAX_TIMEOUT_LEDGERS
+            || config.bury_timeout_ledgers > MAX_TIMEOUT_LEDGERS
+            || config.reveal_window_ledgers > MAX_TIMEOUT_LEDGERS
+        {
+            panic_with_error!(&env, Error::InvalidConfig);
+        }
+        let config = Self::with_default_timeouts(&env, config);
+        if config
+            .hub
+            .as_ref()
+            .is_some_and(|hub| !Self::get_approved_hubs(env.clone()).contains(hub))
+        {
+            panic_with_error!(&env, Error::HubNotApproved);
+        }
+        if let Some(token) = &config.stake_token {
+            Self::escrow(&env, token, &player_a, player_a_points);
+        }
+
+        let hubless = config.hubless;
+        let room = Room {
+            room_id,
+            player_a: player_a.clone(),
+            player_b: player_a.clone(),  // placeholder — overwritten on join
+            player_a_points,
+            player_b_points: 0,
+            phase: 0,
+            turn_is_a: true,
+            island_tile_counts: tile_counts,
+            has_commitment_a: false,
+            has_commitment_b: false,
+            game_active: false,
+            winner: player_a.clone(),    // placeholder
+            digs: Vec::new(&env),
+            actions_left: config.action_points,
+            config,
+            nonce: BytesN::from_array(&env, &[0u8; 32]),
+            created_nonce: Self::next_room_nonce(&env, room_id),
+            hubless,
+            frozen: false,
+            frozen_at: 0,
+            exhausted_at: None,
+            turn_deadline: 0,
+            series_id: None,
+            rematch_of: None,
+        };
+
+        env.storage().temporary().set(&key, &room);
+        bump_temp(&env, &key);
+        if room.config.invite_hash.is_none() {
+            Self::set_room_open(&env, room_id, true);
+        }
+        Self::set_player_room(&env, &player_a, room_id, true);
+        publish_detail(
+            &env,
+            &room,
+            RoomCreated {
+                room_id,
+                player_a: room.player_a.clone(),
+                player_a_points,
+            },
+        );
+        emit_room_event(&env, &room, symbol_short!("created"), &player_a);
+        room
+    }
+
+    /// Seat Player B once the caller has checked authorization. Private
+    /// rooms need the invite code; public rooms take none.
+    fn join(
+        env: Env,
+        room_id: u32,
+        player_b: Address,
+        player_b_points: i128,
+        invite_code: Option<Bytes>,
+    ) -> Room {
+        let key = DataKey::Room(room_id);
+        let mut room: Room = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::RoomNotFound));
+
+        Self::require_phase(&env, &room, 0);
+        // Room is "full" once someone other than player_a is stored.
+        // We detect "no opponent yet" by checking player_b == player_a (the placeholder).
+        if room.player_b != room.player_a {
+            panic_with_error!(&env, Error::RoomFull);
+        }
+        if player_b == room.player_a {
+            panic_with_error!(&env, Error::SelfPlay);
+        }
+        let invited = match (&room.config.invite_hash, invite_code) {
+            (None, None) => true,
+            (Some(hash), Some(code)) => env.crypto().sha256(&code).to_bytes() == *hash,
+            _ => false,
+        };
+        if !invited {
+            panic_with_error!(&env, Error::BadInviteCode);
+        }
+        let rep = Self::get_reputation(env.clone(), player_b.clone());
+        if rep.completed < room.config.min_completed
+            || rep.score_bps < room.config.min_score_bps
+        {
+            panic_with_error!(&env, Error::ReputationTooLow);
+        }
+
+        if let Some(token) = &room.config.stake_token {
+            Self::escrow(&env, token, &player_b, player_b_points);
+        }
+        room.player_b = player_b.clone();
+        room.player_b_points = player_b_points;
+
+        env.storage().temporary().set(&key, &room);
+        bump_temp(&env, &key);
+        Self::set_room_open(&env, room_id, false);
+        Self::set_player_room(&env, &player_b, room_id, true);
+        publish_detail(
+            &env,
+            &room,
+            PlayerJoined {
+                room_id,
+                player_b: player_b.clone(),
+                player_b_points,
+            },
+        );
+        emit_room_event(&env, &room, symbol_short!("joined"), &player_b);
+        room
+    }
+
+    /// Record `player`'s burial once the caller has checked authorization
+    /// (and, with `signed`, the burial signature).
+    fn bury(env: &Env, room_id: u32, player: Address, commitment: BytesN<32>, signed: bool) {
+        let key = DataKey::Room(room_id);
+        let mut room: Room = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .unwrap_or_else(|| panic_with_error!(env, Error::RoomNotFound));
+
+        Self::require_phase(env, &room, 1);
+        if room.config.signed_burials && !signed {
+            panic_with_error!(env, Error::WrongMode);
+        }
+
+        let is_a = player == room.player_a;
+        let is_b = player == room.player_b;
+        if !is_a && !is_b {
+            panic_with_error!(env, Error::NotAPlayer);
+        }
+
+        let hider_is_a = Self::hider_is_a(&room);
+        if hider_is_a == Some(!is_a) {
+            panic_with_error!(env, Error::WrongMode); // seekers hide nothing
+        }
+
+        if is_a && room.has_commitment_a {
+            panic_with_error!(env, Error::AlreadyBuried);
+        }
+        if is_b && room.has_commitment_b {
+            panic_with_error!(env, Error::AlreadyBuried);
+        }
+
+        Self::store_commitment(env, room_id, is_a, &commitment);
+
+        if is_a {
+            room.has_commitment_a = true;
+        } else {
+            room.has_commitment_b = true;
+        }
+
+        // Auto-advance to Playing once every hider's commitment is in.
+        if let Some(hider_is_a) = hider_is_a {
+            room.phase = 2;
+            room.turn_is_a = !hider_is_a; // The seeker holds the turn throughout.
+        } else if room.has_commitment_a && room.has_commitment_b {
+            room.phase = 2;
+            room.turn_is_a = true; // Player A digs first.
+        }
+        if room.phase == 2 {
+            Self::start_turn(env, &mut room);
+        }
+
+        env.storage().temporary().set(&key, &room);
+        bump_temp(env, &key);
+        publish_detail(
+            env,
+            &room,
+            TreasureBuried {
+                room_id,
+                player: player.clone(),
+                commitment,
+            },
+        );
+        emit_room_event(env, &room, symbol_short!("buried"), &player);
+    }
+
+    /// Check a reveal against the opponent's commitment and, on a match,
+    /// end the game in the caller's favour. `derived` says the salt came
+    /// through the derived-salt scheme; `proof` is the Merkle path of a
+    /// multi-treasure room, with the caller's own opened treasures.
+    #[allow(clippy::too_many_arguments)]
+    fn reveal(
+        env: &Env,
+        room_id: u32,
+        player: Address,
+        island_id: u32,
+        tile_id: u32,
+        salt: BytesN<32>,
+        derived: bool,
+        proof: Option<(Vec<BytesN<32>>, Vec<TreasureLeaf>)>,
+    ) {
+        let key = DataKey::Room(room_id);
+        let mut room: Room = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .unwrap_or_else(|| panic_with_error!(env, Error::RoomNotFound));
+
+        Self::require_phase(env, &room, 2);
+        if !room.game_active {
+            panic_with_error!(env, Error::GameEnded);
+        }
+        if room.config.derived_salts && !derived {
+            panic_with_error!(env, Error::WrongMode);
+        }
+        if (room.config.treasures > 1) != proof.is_some() {
+            panic_with_error!(env, Error::WrongMode);
+        }
+
+        let is_a = player == room.player_a;
+        let is_b = player == room.player_b;
+        if !is_a && !is_b {
+            panic_with_error!(env, Error::NotAPlayer);
+        }
+
+        // Turn check — reveal counts as a turn action, until the board is
+        // exhausted and the endgame window opens to both players.
+        if let Some(exhausted_at) = room.exhausted_at {
+            let window = room.config.reveal_window_ledgers;
+            if env.ledger().sequence() >= exhausted_at.saturating_add(window) {
+                panic_with_error!(env, Error::RevealWindowClosed);
+            }
+        } else if room.config.dig_mode != DigMode::Simultaneous
+            && ((room.turn_is_a && !is_a) || (!room.turn_is_a && !is_b))
+        {
+            panic_with_error!(env, Error::NotYourTurn);
+        }
+
+        // Retrieve the OPPONENT's commitment.
+        let opponent_is_a = !is_a;
+        let commit_key = DataKey::Commitment(room_id, opponent_is_a);
+        let stored_commitment: BytesN<32> = env
+            .storage()
+            .temporary()
+            .get(&commit_key)
+            .unwrap_or_else(|| panic_with_error!(env, Error::CommitmentMissing));
+
+        // Rebuild the hash under the room's commitment scheme.
+        let opponent = if is_a { &room.player_b } else { &room.player_a };
+        let mut computed =
+            Self::compute_commitment(env, &room, opponent, island_id, tile_id, &salt);
+        for sibling in proof.iter().flat_map(|(path, _)| path.iter()) {
+            computed = Self::merkle_parent(env, &computed, &sibling);
+        }
+
+        if computed != stored_commitment {
+            // In guess rooms a miss digs the guessed tile and passes the
+            // turn; once the board is exhausted there is nothing to dig.
+            if room.config.guess_mode && room.exhausted_at.is_none() {
+                Self::apply_dig(env, &mut room, &player, island_id, tile_id);
+                emit_room_event(env, &room, symbol_short!("missed"), &player);
+                return;
+            }
+            panic_with_error!(env, Error::CommitmentMismatch);
+        }
+
+        let reveal_key = DataKey::Reveals(room_id);
+        let mut reveals: Vec<Reveal> = env
+            .storage()
+            .temporary()
+            .get(&reveal_key)
+            .unwrap_or_else(|| Vec::new(env));
+        let mut found = 0;
+        for r in reveals.iter() {
+            if r.owner == *opponent {
+                if r.island_id == island_id && r.tile_id == tile_id {
+                    panic_with_error!(env, Error::AlreadyFound);
+                }
+                found += 1;
+            }
+        }
+        reveals.push_back(Reveal {
+            owner: opponent.clone(),
+            island_id,
+            tile_id,
+            salt,
+        });
+
+        // A multi-treasure hunt goes on until a majority is found.
+        if found < room.config.treasures / 2 {
+            env.storage().temporary().set(&reveal_key, &reveals);
+            bump_temp(env, &reveal_key);
+            emit_room_event(env, &room, symbol_short!("found"), &player);
+            return;
+        }
+
+        // ── Winner decided ─────────────────────────────────────────────
+        // A duel is only won over treasures the winner really buried.
+        if let Some((_, leaves)) = proof.as_ref().filter(|_| Self::hider_is_a(&room).is_none()) {
+            Self::require_opening(env, &room, is_a, leaves);
+        }
+        let player1_won = is_a; // true if Player A wins
+
+        // Notify Game Hub BEFORE mutating local state.
+        Self::report_result(env, &room, player1_won);
+
+        env.storage().temporary().set(&reveal_key, &reveals);
+        bump_temp(env, &reveal_key);
+
+        Self::record_game(env, &room.player_a, true);
+        Self::record_game(env, &room.player_b, true);
+
+        Self::settle_stakes(env, &room, Some(&player));
+        room.winner = player.clone();
+        Self::untrack_room(env, &room);
+        room.game_active = false;
+        room.phase = 3;
+
+        env.storage().temporary().set(&key, &room);
+        bump_temp(env, &key);
+        bump_instance(env);
+        publish_detail(
+            env,
+            &room,
+            TreasureRevealed {
+                room_id,
+                winner: player.clone(),
+                island_id,
+                tile_id,
+            },
+        );
+        emit_room_event(env, &room, symbol_short!("revealed"), &player);
+    }
+
+    /// Hand out the next free sequential room id, skipping ids already
+    /// taken through `create_room`.
+    fn next_room_id(env: &Env) -> u32 {
+        let mut room_id = instance_u32(env, &DataKey::RoomCounter, 0);
+        loop {
+            room_id += 1;
+            if !env.storage().temporary().has(&DataKey::Room(room_id)) {
+                break;
+            }
+        }
+        env.storage().instance().set(&DataKey::RoomCounter, &room_id);
+        bump_instance(env);
+        room_id
+    }
+
+    /// Add a room to the lobby index, or take it off. Listing also drops
+    /// rooms that expired while waiting, then the oldest rooms while the
+    /// lobby is full, so the index stays one bounded entry.
+    fn set_room_open(env: &Env, room_id: u32, open: bool) {
+        let key = DataKey::OpenRooms;
+        let listed: Vec<u32> = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .unwrap_or_else(|| Vec::new(env));
+        let mut ids = Vec::new(env);
+        for id in listed.iter() {
+            if id == room_id {
+                continue;
+            }
+            if open && !env.storage().temporary().has(&DataKey::Room(id)) {
+                continue;
+            }
+            ids.push_back(id);
+        }
+        if open {
+            while ids.len() >= MAX_OPEN_ROOMS {
+                ids.pop_front();
+            }
+            ids.push_back(room_id);
+        } else if ids.len() == listed.len() {
+            return;
+        }
+        env.storage().temporary().set(&key, &ids);
+        bump_temp(env, &key);
+    }
+
+    /// Add a room to `player`'s index, or take it off. Adding also drops
+    /// rooms that expired without ending.
+    fn set_player_room(env: &Env, player: &Address, room_id: u32, active: bool) {
+        let key = DataKey::PlayerRooms(player.clone());
+        let listed: Vec<u32> = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or_else(|| Vec::new(env));
+        let mut ids = Vec::new(env);
+        for id in listed.iter() {
+            if id == room_id {
+                continue;
+            }
+            if active && !env.storage().temporary().has(&DataKey::Room(id)) {
+                continue;
+            }
+            ids.push_back(id);
+        }
+        if active {
+            ids.push_back(room_id);
+        } else if ids.len() == listed.len() {
+            return;
+        }
+        env.storage().persistent().set(&key, &ids);
+        bump_persistent(env, &key);
+    }
+
+    /// Take an ended or cancelled room off both players' indexes.
+    fn untrack_room(env: &Env, room: &Room) {
+        Self::set_player_room(env, &room.player_a, room.room_id, false);
+        Self::set_player_room(env, &room.player_b, room.room_id, false);
+    }
+
+    /// Every temporary-storage key a room can own, bets aside: those are
+    /// keyed by bettor.
+    pub(crate) fn room_keys(room_id: u32) -> [DataKey; 16] {
+        [
+            DataKey::Room(room_id),
+            DataKey::Commitment(room_id, true),
+            DataKey::Commitment(room_id, false),
+            DataKey::Opened(room_id, true),
+            DataKey::Opened(room_id, false),
+            DataKey::BuriedAt(room_id, true),
+            DataKey::BuriedAt(room_id, false),
+            DataKey::RoundDig(room_id, true),
+            DataKey::RoundDig(room_id, false),
+            DataKey::Events(room_id),
+            DataKey::Observers(room_id),
+            DataKey::Tips(room_id),
+            DataKey::Reports(room_id),
+            DataKey::Reveals(room_id),
+            DataKey::BetBook(room_id),
+            DataKey::PendingHubReport(room_id),
+        ]
+    }
+
+    /// Report a decided game to the hub. A series game counts toward its
+    /// series instead, and the hub hears once, when the series is won.
+    fn report_result(env: &Env, room: &Room, player1_won: bool) {
+        let score_a = if player1_won { 10_000 } else { 0 };
+        Self::update_ratings(env, room, score_a);
+        Self::update_stats(env, room, score_a);
+        if let Some(leaderboard) = Self::get_leaderboard(env.clone()) {
+            let (winner, loser, points) = if player1_won {
+                (&room.player_a, &room.player_b, room.player_b_points)
+            } else {
+                (&room.player_b, &room.player_a, room.player_a_points)
+            };
+            LeaderboardClient::new(env, &leaderboard).record_result(winner, loser, &points);
+        }
+
+        let Some(series_id) = room.series_id else {
+            Self::notify_hub(env, room, HubReport::Ended(room.room_id, player1_won));
+            return;
+        };
+
+        let key = DataKey::Series(series_id);
+        let mut series = Self::get_series(env.clone(), series_id);
+        if player1_won {
+            series.wins_a += 1;
+        } else {
+            series.wins_b += 1;
+        }
+        if series.wins_a.max(series.wins_b) >= series.wins_needed {
+            let session_id = series.session_id.unwrap_or(room.room_id);
+            Self::notify_hub(env, room, HubReport::Ended(session_id, player1_won));
+            let winner = if player1_won { &series.player_a } else { &series.player_b };
+            series.winner = Some(winner.clone());
+            emit_room_event(env, room, symbol_short!("serieswon"), winner);
+        }
+        env.storage().temporary().set(&key, &series);
+        bump_temp(env, &key);
+    }
+
+    /// Client for the Game Hub `room` reports to: its own choice, or the
+    /// registered hub. `None` for hubless rooms, and for rooms without a
+    /// hub of their own while the contract runs standalone. Every hub call
+    /// goes through here.
+    fn hub<'a>(env: &'a Env, room: &Room) -> Option<GameHubClient<'a>> {
+        if room.hubless {
+            return None;
+        }
+        let hub = room.config.hub.clone().or_else(|| Self::get_hub(env.clone()))?;
+        Some(GameHubClient::new(env, &hub))
+    }
+
+    /// Send a finished game's `report` to the room's hub, if it has one.
+    /// A failing hub must not stop the game from finishing, so the call is
+    /// caught and the report kept for `retry_hub_report`.
+    fn notify_hub(env: &Env, room: &Room, report: HubReport) {
+        let Some(hub) = Self::hub(env, room) else {
+            return;
+        };
+        if !Self::send_hub_report(env, &hub, &report) {
+            let key = DataKey::PendingHubReport(room.room_id);
+            let pending = PendingHubReport {
+                hub: hub.address.clone(),
+                report,
+            };
+            env.storage().temporary().set(&key, &pending);
+            bump_temp(env, &key);
+            emit_room_event(env, room, symbol_short!("hubfailed"), &hub.address);
+        }
+    }
+
+    /// Make the hub call for `report` that the hub's kind supports; false
+    /// if it failed.
+    fn send_hub_report(env: &Env, hub: &GameHubClient, report: &HubReport) -> bool {
+        let kind = Self::get_hub_kind(env.clone(), hub.address.clone());
+        let game = env.current_contract_address();
+        let res = match (report, kind) {
+            (HubReport::Ended(session_id, player1_won), HubKind::Session) => {
+                hub.try_end_session(&game, session_id, player1_won)
+            }
+            (HubReport::Drawn(session_id), HubKind::Session) => {
+                hub.try_draw_session(&game, session_id)
+            }
+            (HubReport::Aborted(session_id), HubKind::Session) => {
+                hub.try_abort_session(&game, session_id)
+            }
+            (HubReport::Ended(session_id, player1_won), _) => {
+                hub.try_end_game(session_id, player1_won)
+            }
+            (HubReport::Drawn(session_id) | HubReport::Aborted(session_id), HubKind::Legacy) => {
+                hub.try_end_game(session_id, &false)
+            }
+            (HubReport::Drawn(session_id), HubKind::Extended) => hub.try_draw_game(session_id),
+            (HubReport::Aborted(session_id), HubKind::Extended) => hub.try_abort_game(session_id),
+        };
+        matches!(res, Ok(Ok(())))
+    }
+
+    /// Refuse a start only one player signs when the contract's hub would
+    /// escrow points for it, as it could not pull the other player's.
+    fn require_unescrowed_hub(env: &Env) {
+        let Some(hub) = Self::get_hub(env.clone()) else {
+            return;
+        };
+        if Self::get_hub_kind(env.clone(), hub.clone()) != HubKind::Session {
+            return;
+        }
+        let game = env.current_contract_address();
+        if GameHubClient::new(env, &hub).get_game_token(&game).is_some() {
+            panic_with_error!(env, Error::HubEscrows);
+        }
+    }
+
+    /// Move a room from Waiting to Burying once both players have
+    /// authorized their points.
+    fn start_room_authorized(
+        env: &Env,
+        room_id: u32,
+        player_a: &Address,
+        player_b: &Address,
+        player_a_points: i128,
+        player_b_points: i128,
+    ) -> Room {
+        Self::require_not_paused(env);
+        let key = DataKey::Room(room_id);
+        let mut room: Room = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .unwrap_or_else(|| panic_with_error!(env, Error::RoomNotFound));
+
+        Self::require_phase(env, &room, 0);
+        if room.player_b == room.player_a {
+            panic_with_error!(env, Error::NoOpponent);
+        }
+        if *player_a != room.player_a {
+            panic_with_error!(env, Error::NotPlayerA);
+        }
+        if *player_b != room.player_b {
+            panic_with_error!(env, Error::NotPlayerB);
+        }
+        if room.config.stake_token.is_some()
+            && (player_a_points != room.player_a_points || player_b_points != room.player_b_points)
+        {
+            panic_with_error!(env, Error::StakeMismatch);
+        }
+
+        // Register with Game Hub BEFORE mutating local state. A series
+        // is one hub session, opened by its first game. A room with no hub
+        // to report to stays hubless even if one is set later, as the hub
+        // would not know its session.
+        let mut series = room.series_id.map(|id| Self::get_series(env.clone(), id));
+        match Self::hub(env, &room) {
+            Some(hub) if series.as_ref().is_none_or(|s| s.session_id.is_none()) => {
+                hub.start_game(
+                    &env.current_contract_address(),
+                    &room_id,
+                    player_a,
+                    player_b,
+                    &player_a_points,
+                    &player_b_points,
+                );
+            }
+            Some(_) => {}
+            None => room.hubless = true,
+        }
+        if let Some(series) = series.as_mut().filter(|s| s.session_id.is_none()) {
+            series.session_id = Some(room_id);
+            let series_key = DataKey::Series(series.series_id);
+            env.storage().temporary().set(&series_key, &*series);
+            bump_temp(env, &series_key);
+        }
+
+        Self::record_game(env, player_a, false);
+        Self::record_game(env, player_b, false);
+
+        room.player_a_points = player_a_points;
+        room.player_b_points = player_b_points;
+        Self::set_room_open(env, room_id, false);
+        room.phase = 1; // → Burying
+        room.game_active = true;
+        room.turn_deadline =
+            env.ledger().sequence().saturating_add(room.config.bury_timeout_ledgers);
+        room.nonce = Self::next_room_nonce(env, room_id);
+
+        env.storage().temporary().set(&key, &room);
+        bump_temp(env, &key);
+        bump_instance(env);
+        emit_room_event(env, &room, symbol_short!("started"), player_a);
+        room
+    }
+
+    /// Store a player's commitment (separately from the room, which avoids
+    /// nested Option issues) along with the ledger it was buried at.
+    fn store_commitment(env: &Env, room_id: u32, is_a: bool, commitment: &BytesN<32>) {
+        let commit_key = DataKey::Commitment(room_id, is_a);
+        env.storage().temporary().set(&commit_key, commitment);
+        bump_temp(env, &commit_key);
+
+        let ledger_key = DataKey::BuriedAt(room_id, is_a);
+        env.storage().temporary().set(&ledger_key, &env.ledger().sequence());
+        bump_temp(env, &ledger_key);
+    }
+
+    /// SHA-256( xdr(contract) ‖ room_id ‖ counter ), where the counter is a
+    /// contract-wide sequence bumped on every draw. Derived from contract
+    /// state rather than the ledger so simulation and submission agree.
+    fn next_room_nonce(env: &Env, room_id: u32) -> BytesN<32> {
+        let counter: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::NonceCounter)
+            .unwrap_or(0);
+        env.storage().instance().set(&DataKey::NonceCounter, &(counter + 1));
+
+        let mut buf = env.current_contract_address().to_xdr(env);
+        buf.extend_from_array(&room_id.to_be_bytes());
+        buf.extend_from_array(&counter.to_be_bytes());
+        env.crypto().sha256(&buf).to_bytes()
+    }
+
+    /// Move both players' Elo ratings after a game Player A scored
+    /// `score_a` in (basis points: 10 000 a win, 5 000 a draw).
+    fn update_ratings(env: &Env, room: &Room, score_a: i64) {
+        let rating_a = Self::get_rating(env.clone(), room.player_a.clone()) as i64;
+        let rating_b = Self::get_rating(env.clone(), room.player_b.clone()) as i64;
+        let delta = ELO_K * (score_a - elo_expected_bps(rating_a - rating_b)) / 10_000;
+        for (player, rating) in [
+            (&room.player_a, rating_a + delta),
+            (&room.player_b, rating_b - delta),
+        ] {
+            let key = DataKey::Rating(player.clone());
+            env.storage().persistent().set(&key, &(rating.max(0) as u32));
+            bump_persistent(env, &key);
+        }
+    }
+
+    /// Add a game Player A scored `score_a` in (as for `update_ratings`)
+    /// to both players' stats.
+    fn update_stats(env: &Env, room: &Room, score_a: i64) {
+        for (player, score, opponent_points) in [
+            (&room.player_a, score_a, room.player_b_points),
+            (&room.player_b, 10_000 - score_a, room.player_a_points),
+        ] {
+            let key = DataKey::Stats(player.clone());
+            let mut stats = Self::get_player_stats(env.clone(), player.clone());
+            stats.games += 1;
+            match score {
+                10_000 => {
+                    stats.wins += 1;
+                    stats.total_points_won += opponent_points;
+                }
+                0 => stats.losses += 1,
+                _ => stats.draws += 1,
+            }
+            env.storage().persistent().set(&key, &stats);
+            bump_persistent(env, &key);
+        }
+    }
+
+    /// Count a started (or, with `completed`, a finished) game towards
+    /// `player`'s reputation.
+    fn record_game(env: &Env, player: &Address, completed: bool) {
+        let key = DataKey::Reputation(player.clone());
+        let mut rep = Self::get_reputation(env.clone(), player.clone());
+        if completed {
+            rep.completed += 1;
+            // Rooms started before reputation was tracked end here too.
+            rep.started = rep.started.max(rep.completed);
+        } else {
+            rep.started += 1;
+        }
+        rep.score_bps = (rep.completed as u64 * 10_000 / rep.started as u64) as u32;
+        env.storage().persistent().set(&key, &rep);
+        bump_persistent(env, &key);
+    }
+
+    fn bls_key(env: &Env, player: &Address) -> BytesN<96> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::BlsKey(player.clone()))
+            .unwrap_or_else(|| panic_with_error!(env, Error::BlsKeyMissing))
+    }
+
+    /// SHA-256( xdr(digs) ‖ commitment_a ‖ commitment_b ).
+    fn transcript_hash(env: &Env, room: &Room) -> BytesN<32> {
+        let mut buf = Bytes::new(env);
+        buf.append(&room.digs.clone().to_xdr(env));
+        Self::append_commitments(env, room.room_id, &mut buf);
+        env.crypto().sha256(&buf).to_bytes()
+    }
+
+    /// Append whichever commitments are stored for the room, A then B.
+    /// Absent commitments are implied by the room's `has_commitment_*` flags.
+    fn append_commitments(env: &Env, room_id: u32, buf: &mut Bytes) {
+        for is_a in [true, false] {
+            let commitment: Option<BytesN<32>> = env
+                .storage()
+                .temporary()
+                .get(&DataKey::Commitment(room_id, is_a));
+            if let Some(c) = commitment {
+                buf.extend_from_slice(&c.to_array());
+            }
+        }
+    }
+
+    /// Slice `items` to the page `cursor` describes, returning the start of
+    /// the next page if there is one.
+    fn page<T>(items: Vec<T>, cursor: &Cursor) -> (Vec<T>, Option<u32>)
+    where
+        T: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+    {
+        let limit = match cursor.limit {
+            0 => MAX_PAGE_SIZE,
+            n => n.min(MAX_PAGE_SIZE),
+        };
+        let len = items.len();
+        let start = cursor.start.min(len);
+        let end = start.saturating_add(limit).min(len);
+        let next = if end < len { Some(end) } else { None };
+        (items.slice(start..end), next)
+    }
+
+    /// Panic with `Paused` while the contract is paused; see `pause`.
+    fn require_not_paused(env: &Env) {
+        if Self::is_paused(env.clone()) {
+            panic_with_error!(env, Error::Paused);
+        }
+    }
+
+    fn fee_balances(env: &Env) -> Map<Address, i128> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Fees)
+            .unwrap_or_else(|| Map::new(env))
+    }
+
+    /// Require the admin's authorization and record the ledger of this
+    /// admin action for the dead-man switch.
+    fn require_admin(env: &Env) -> Address {
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        env.storage()
+            .instance()
+            .set(&DataKey::AdminLastActive, &env.ledger().sequence());
+        admin
+    }
+
+    /// Load a Playing room in one of `modes` and check it is `player`'s
+    /// turn.
+    fn room_on_turn(env: &Env, room_id: u32, player: &Address, modes: &[DigMode]) -> Room {
+        let room = Self::get_room(env.clone(), room_id);
+        Self::require_phase(env, &room, 2);
+
+        if !modes.contains(&room.config.dig_mode) {
+            panic_with_error!(env, Error::WrongMode);
+        }
+
+        let is_a = *player == room.player_a;
+        let is_b = *player == room.player_b;
+        if !is_a && !is_b {
+            panic_with_error!(env, Error::NotAPlayer);
+        }
+
+        // Turn check.
+        if (room.turn_is_a && !is_a) || (!room.turn_is_a && !is_b) {
+            panic_with_error!(env, Error::NotYourTurn);
+        }
+        room
+    }
+
+    /// Validate and record `player`'s dig, then pass the turn.
+    fn apply_dig(env: &Env, room: &mut Room, player: &Address, island_id: u32, tile_id: u32) {
+        // Validate island/tile.
+        if island_id >= room.island_tile_counts.len() {
+            panic_with_error!(env, Error::InvalidIsland);
+        }
+        let max_tiles = room.island_tile_counts.get(island_id).unwrap();
+        if tile_id >= max_tiles {
+            panic_with_error!(env, Error::InvalidTile);
+        }
+
+        // Check for duplicate digs.
+        for d in room.digs.iter() {
+            if d.island_id == island_id && d.tile_id == tile_id {
+                panic_with_error!(env, Error::AlreadyDug);
+            }
+        }
+
+        let seeking = Self::hider_is_a(room).is_some();
+        if seeking && room.digs.len() >= room.config.dig_budget {
+            panic_with_error!(env, Error::BudgetExhausted);
+        }
+
+        let spending = room.config.action_points != 0;
+        if spending {
+            let cost = dig_cost(room.island_tile_counts.get(island_id).unwrap());
+            if cost > room.actions_left {
+                panic_with_error!(env, Error::NotEnoughActions);
+            }
+            room.actions_left -= cost;
+        }
+
+        room.digs.push_back(DigRecord {
+            digger: player.clone(),
+            island_id,
+            tile_id,
+        });
+        Self::note_exhaustion(env, room);
+
+        // Alternate turns; a seeker keeps digging until found or out of
+        // budget, and with action points the turn lasts until they run out.
+        if !seeking && (!spending || room.actions_left == 0) {
+            room.turn_is_a = !room.turn_is_a;
+            room.actions_left = room.config.action_points;
+        }
+        Self::start_turn(env, room);
+
+        let key = DataKey::Room(room.room_id);
+        env.storage().temporary().set(&key, &*room);
+        bump_temp(env, &key);
+        publish_detail(
+            env,
+            room,
+            TileDug {
+                room_id: room.room_id,
+                digger: player.clone(),
+                island_id,
+                tile_id,
+            },
+        );
+        emit_room_event(env, room, symbol_short!("dug"), player);
+    }
+
+    /// Move a player's stake into the contract.
+    fn escrow(env: &Env, token: &Address, from: &Address, amount: i128) {
+        if amount <= 0 {
+            panic_with_error!(env, Error::InvalidAmount);
+        }
+        token::Client::new(env, token).transfer(from, env.current_contract_address(), &amount);
+    }
+
+    /// Pay an escrowed room's pot, less the protocol fee, to `winner`, or
+    /// refund both stakes when there is none. A surviving hider's pot is
+    /// weighted by the room's `hider_odds_bps`. No-op for rooms without a
+    /// stake token.
+    fn settle_stakes(env: &Env, room: &Room, winner: Option<&Address>) {
+        let Some(token) = &room.config.stake_token else {
+            return;
+        };
+        let client = token::Client::new(env, token);
+        let contract = env.current_contract_address();
+        match winner {
+            Some(winner) => {
+                let mut pot = room.player_a_points + room.player_b_points;
+                if Self::hider_is_a(room) == Some(*winner == room.player_a) {
+                    let (hider_points, seeker, seeker_points) = if *winner == room.player_a {
+                        (room.player_a_points, &room.player_b, room.player_b_points)
+                    } else {
+                        (room.player_b_points, &room.player_a, room.player_a_points)
+                    };
+                    let odds = match room.config.hider_odds_bps {
+                        0 => 10_000,
+                        odds => odds as i128,
+                    };
+                    let won = (hider_points * odds / 10_000).min(seeker_points);
+                    if seeker_points > won {
+                        client.transfer(&contract, seeker, &(seeker_points - won));
+                    }
+                    pot -= seeker_points - won;
+                }
+                let fee = pot * Self::get_fee_bps(env.clone()) as i128 / 10_000;
+                if fee > 0 {
+                    let mut fees = Self::fee_balances(env);
+                    fees.set(token.clone(), fees.get(token.clone()).unwrap_or(0) + fee);
+                    env.storage().instance().set(&DataKey::Fees, &fees);
+                }
+                client.transfer(&contract, winner, &(pot - fee));
+            }
+            None => {
+                client.transfer(&contract, &room.player_a, &room.player_a_points);
+                client.transfer(&contract, &room.player_b, &room.player_b_points);
+            }
+        }
+    }
+
+    /// Restart the turn clock after a move.
+    fn start_turn(env: &Env, room: &mut Room) {
+        room.turn_deadline =
+            env.ledger().sequence().saturating_add(room.config.turn_timeout_ledgers);
+    }
+
+    /// Open the endgame reveal window once every tile has been dug.
+    fn note_exhaustion(env: &Env, room: &mut Room) {
+        let tiles: u32 = room.island_tile_counts.iter().sum();
+        if room.exhausted_at.is_none() && room.digs.len() >= tiles {
+            room.exhausted_at = Some(env.ledger().sequence());
+        }
+    }
+
+    /// Panic with the `WrongPhaseExpected*` error for `phase` unless the
+    /// room is in it. Every player action passes through here, so this
+    /// also turns them away from frozen rooms.
+    fn require_phase(env: &Env, room: &Room, phase: u32) {
+        if room.frozen {
+            panic_with_error!(env, Error::RoomFrozen);
+        }
+        if room.phase == phase {
+            return;
+        }
+        let error = match phase {
+            0 => Error::WrongPhaseExpectedWaiting,
+            1 => Error::WrongPhaseExpectedBurying,
+            2 => Error::WrongPhaseExpectedPlaying,
+            _ => Error::WrongPhaseExpectedEnded,
+        };
+        panic_with_error!(env, error);
+    }
+
+    /// `require_phase` for a room that has ended either way: won (3), or
+    /// drawn or aborted (4).
+    fn require_ended(env: &Env, room: &Room) {
+        if room.phase == 4 && !room.frozen {
+            return;
+        }
+        Self::require_phase(env, room, 3);
+    }
+
+    /// The invariants `room` breaks, as listed on `audit_room`.
+    fn audit(env: &Env, room: &Room) -> Vec<Symbol> {
+        let mut violations = Vec::new(env);
+
+        if room.phase > 4 {
+            violations.push_back(symbol_short!("phase"));
+        }
+        if room.game_active != (room.phase == 1 || room.phase == 2) {
+            violations.push_back(symbol_short!("active"));
+        }
+        if room.phase > 0 && room.player_b == room.player_a {
+            violations.push_back(symbol_short!("opponent"));
+        }
+        let winner_ok = if room.phase == 3 {
+            room.winner == room.player_a || room.winner == room.player_b
+        } else {
+            room.winner == room.player_a // placeholder until decided
+        };
+        if !winner_ok {
+            violations.push_back(symbol_short!("winner"));
+        }
+
+        let hider_is_a = Self::hider_is_a(room);
+        let playing = room.phase >= 2;
+        for (is_a, flag, name) in [
+            (true, room.has_commitment_a, symbol_short!("commit_a")),
+            (false, room.has_commitment_b, symbol_short!("commit_b")),
+        ] {
+            let stored = env
+                .storage()
+                .temporary()
+                .has(&DataKey::Commitment(room.room_id, is_a));
+            let hides = hider_is_a.is_none_or(|h| h == is_a);
+            if flag != stored || (playing && hides && !flag) || (!hides && flag) {
+                violations.push_back(name);
+            }
+        }
+
+        let mut digs_ok = playing || room.digs.is_empty();
+        if hider_is_a.is_some() && room.digs.len() > room.config.dig_budget {
+            digs_ok = false;
+        }
+        for (i, d) in room.digs.iter().enumerate() {
+            let in_bounds = room
+                .island_tile_counts
+                .get(d.island_id)
+                .is_some_and(|tiles| d.tile_id < tiles);
+            let repeated = room
+                .digs
+                .iter()
+                .take(i)
+                .any(|e| e.island_id == d.island_id && e.tile_id == d.tile_id);
+            let by_player = d.digger == room.player_a || d.digger == room.player_b;
+            if !in_bounds || repeated || !by_player {
+                digs_ok = false;
+            }
+        }
+        if !digs_ok {
+            violations.push_back(symbol_short!("digs"));
+        }
+
+        if room.actions_left > room.config.action_points {
+            violations.push_back(symbol_short!("actions"));
+        }
+        violations
+    }
+
+    /// `Some(true)` if Player A is the hider of a hider/seeker room,
+    /// `Some(false)` if Player B is, `None` in a duel.
+    fn hider_is_a(room: &Room) -> Option<bool> {
+        match room.config.roles {
+            Roles::Duel => None,
+            Roles::AHides => Some(true),
+            Roles::BHides => Some(false),
+        }
+    }
+
+    /// Panic with `BadOpening` unless `leaves` open the burial of Player A
+    /// (`is_a`) or B: as many as the room buries, on distinct tiles of the
+    /// board, rebuilding the stored commitment as `open_treasures` lays
+    /// out.
+    fn require_opening(env: &Env, room: &Room, is_a: bool, leaves: &Vec<TreasureLeaf>) {
+        if leaves.len() != room.config.treasures.max(1) {
+            panic_with_error!(env, Error::BadOpening);
+        }
+        let owner = if is_a { &room.player_a } else { &room.player_b };
+        let mut level = Vec::new(env);
+        for (i, leaf) in leaves.iter().enumerate() {
+            let in_bounds = room
+                .island_tile_counts
+                .get(leaf.island_id)
+                .is_some_and(|tiles| leaf.tile_id < tiles);
+            let repeated = leaves
+                .iter()
+                .take(i)
+                .any(|l| l.island_id == leaf.island_id && l.tile_id == leaf.tile_id);
+            if !in_bounds || repeated {
+                panic_with_error!(env, Error::BadOpening);
+            }
+            level.push_back(Self::compute_commitment(
+                env,
+                room,
+                owner,
+                leaf.island_id,
+                leaf.tile_id,
+                &leaf.salt,
+            ));
+        }
+        while level.len() > 1 {
+            let mut next = Vec::new(env);
+            for i in (0..level.len()).step_by(2) {
+                let node = level.get_unchecked(i);
+                match level.get(i + 1) {
+                    Some(sibling) => next.push_back(Self::merkle_parent(env, &node, &sibling)),
+                    None => next.push_back(node),
+                }
+            }
+            level = next;
+        }
+        let stored: Option<BytesN<32>> =
+            env.storage().temporary().get(&DataKey::Commitment(room.room_id, is_a));
+        if level.first() != stored {
+            panic_with_error!(env, Error::BadOpening);
+        }
+    }
+
+    /// Parent of two Merkle nodes in a multi-treasure commitment:
+    /// SHA-256 of both, smaller first, so proofs need no left/right flags.
+    pub(crate) fn merkle_parent(env: &Env, a: &BytesN<32>, b: &BytesN<32>) -> BytesN<32> {
+        let (lo, hi) = if a <= b { (a, b) } else { (b, a) };
+        let mut buf = Bytes::from_array(env, &lo.to_array());
+        buf.append(&Bytes::from_array(env, &hi.to_array()));
+        env.crypto().sha256(&buf).to_bytes()
+    }
+
+    /// Compute `owner`'s commitment to (island_id, tile_id, salt) under the
+    /// room's scheme.
+    pub(crate) fn compute_commitment(
+        env: &Env,
+        room: &Room,
+        owner: &Address,
+        island_id: u32,
+        tile_id: u32,
+        salt: &BytesN<32>,
+    ) -> BytesN<32> {
+        match room.config.commitment_scheme {
+            CommitmentScheme::Sha256 => {
+                Self::sha256_commitment(env, room, island_id, tile_id, salt)
+            }
+            CommitmentScheme::Poseidon2 => {
+                Self::poseidon2_commitment(env, room, owner, island_id, tile_id, salt)
+            }
+            CommitmentScheme::LegacySha256 => {
+                Self::legacy_sha256_commitment(env, room, island_id, tile_id, salt)
+            }
+        }
+    }
+
+    /// SHA-256( xdr(config) ‖ xdr(island_tile_counts) ): the room's rules,
+    /// bound into every burial commitment so one made under a different
+    /// ruleset or board never verifies.
+    fn rules_hash(env: &Env, room: &Room) -> BytesN<32> {
+        let mut buf = room.config.clone().to_xdr(env);
+        buf.append(&room.island_tile_counts.clone().to_xdr(env));
+        env.crypto().sha256(&buf).to_bytes()
+    }
+
+    /// Compute SHA-256(room_id ‖ nonce ‖ rules_hash ‖ island_id ‖ tile_id ‖ salt).
+    fn sha256_commitment(
+        env: &Env,
+        room: &Room,
+        island_id: u32,
+        tile_id: u32,
+        salt: &BytesN<32>,
+    ) -> BytesN<32> {
+        let mut buf = Bytes::new(env);
+        buf.extend_from_array(&room.room_id.to_be_bytes());
+        buf.extend_from_slice(&room.nonce.to_array());
+        buf.extend_from_slice(&Self::rules_hash(env, room).to_array());
+        buf.extend_from_array(&island_id.to_be_bytes());
+        buf.extend_from_array(&tile_id.to_be_bytes());
+        buf.extend_from_slice(&salt.to_array());
+
+        let hash: Hash<32> = env.crypto().sha256(&buf);
+        BytesN::from_array(env, &hash.to_array())
+    }
+
+    /// Compute SHA-256(room_id ‖ island_id ‖ tile_id ‖ salt), the scheme
+    /// rooms buried under the original layout committed with.
+    fn legacy_sha256_commitment(
+        env: &Env,
+        room: &Room,
+        island_id: u32,
+        tile_id: u32,
+        salt: &BytesN<32>,
+    ) -> BytesN<32> {
+        let mut buf = Bytes::new(env);
+        buf.extend_from_array(&room.room_id.to_be_bytes());
+        buf.extend_from_array(&island_id.to_be_bytes());
+        buf.extend_from_array(&tile_id.to_be_bytes());
+        buf.extend_from_slice(&salt.to_array());
+
+        let hash: Hash<32> = env.crypto().sha256(&buf);
+        BytesN::from_array(env, &hash.to_array())
+    }
+
+    /// Compute SHA-256("dig" ‖ room_id ‖ nonce ‖ xdr(digger) ‖ island_id ‖
+    /// tile_id ‖ salt), the commitment to a simultaneous-round or committed
+    /// dig. The prefix keeps it from ever colliding with a burial
+    /// commitment; the digger keeps the opponent from copying it and
+    /// mirroring its reveal.
+    pub(crate) fn round_dig_commitment(
+        env: &Env,
+        room: &Room,
+        digger: &Address,
+        island_id: u32,
+        tile_id: u32,
+        salt: &BytesN<32>,
+    ) -> BytesN<32> {
+        let mut buf = Bytes::from_slice(env, b"dig");
+        buf.extend_from_array(&room.room_id.to_be_bytes());
+        buf.extend_from_slice(&room.nonce.to_array());
+        buf.append(&digger.to_xdr(env));
+        buf.extend_from_array(&island_id.to_be_bytes());
+        buf.extend_from_array(&tile_id.to_be_bytes());
+        buf.extend_from_slice(&salt.to_array());
+
+        let hash: Hash<32> = env.crypto().sha256(&buf);
+        BytesN::from_array(env, &hash.to_array())
+    }
+
+    /// Compute Poseidon2(room_id, island_id, tile_id, owner_hash, salt, nonce,
+    /// rules_hash), the commitment the `zk/treasure` circuit opens.
+    ///
+    /// `owner_hash` is keccak256 of the owner's address XDR; it, the salt,
+    /// the nonce and the rules hash have their top byte cleared so they fit
+    /// the field.
+    pub(crate) fn poseidon2_commitment(
+        env: &Env,
+        room: &Room,
+        owner: &Address,
+        island_id: u32,
+        tile_id: u32,
+        salt: &BytesN<32>,
+    ) -> BytesN<32> {
+        let mut owner_hash = env.crypto().keccak256(&owner.to_xdr(env)).to_array();
+        owner_hash[0] = 0;
+        let mut salt = salt.to_array();
+        salt[0] = 0;
+        let mut nonce = room.nonce.to_array();
+        nonce[0] = 0;
+        let mut rules = Self::rules_hash(env, room).to_array();
+        rules[0] = 0;
+
+        let inputs = Vec::from_array(
+            env,
+            [
+                U256::from_u32(env, room.room_id),
+                U256::from_u32(env, island_id),
+                U256::from_u32(env, tile_id),
+                U256::from_be_bytes(env, &Bytes::from_array(env, &owner_hash)),
+                U256::from_be_bytes(env, &Bytes::from_array(env, &salt)),
+                U256::from_be_bytes(env, &Bytes::from_array(env, &nonce)),
+                U256::from_be_bytes(env, &Bytes::from_array(env, &rules)),
+            ],
+        );
+        let digest = poseidon::hash(env, &inputs).to_be_bytes();
+        BytesN::try_from(digest).unwrap()
+    }
+}
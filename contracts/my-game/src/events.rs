@@ -0,0 +1,237 @@
+//! Room events.
+//!
+//! Every room state change is published as a `room_event` contract event
+//! and mirrored into a per-room outbox. The payload types here are public
+//! so off-chain Rust consumers can decode event XDR without hand-rolled
+//! parsers: topics are `("room_event", room_id: u32, kind: Symbol)` and the
+//! data decodes as [`RoomEventData`].
+//!
+//! Each registered observer of a room additionally gets a copy topped by
+//! its own address, `("observed", observer, room_id, kind)`, so a bot can
+//! subscribe to exactly the rooms it watches.
+//!
+//! The main actions also publish a typed event carrying their details
+//! ([`RoomCreated`], [`PlayerJoined`], [`TreasureBuried`], [`TileDug`],
+//! [`TreasureRevealed`]), topped by their own name and the room id, so a
+//! frontend can react to a move without re-reading the room.
+//!
+//! Rooms running with `EventVerbosity::Minimal` publish the same topics
+//! with an empty body instead ([`MinimalRoomEvent`] and
+//! [`MinimalObservedRoomEvent`]) and skip the typed detail events.
+
+use soroban_sdk::{contractevent, contracttype, Address, BytesN, Symbol};
+#[cfg(not(feature = "interface"))]
+use soroban_sdk::{Env, Event, Vec};
+
+#[cfg(not(feature = "interface"))]
+use crate::contract::{bump_temp, instance_u32, DEFAULT_RECENT_EVENTS};
+#[cfg(not(feature = "interface"))]
+use crate::{DataKey, EventVerbosity, Room};
+
+/// Published on every room state change. `kind` is one of `created`,
+/// `joined`, `started`, `buried`, `reburied`, `dug`, `revealed`, `tipped`,
+/// `reported`, `survived`, `hubless`, `frozen`, `unfrozen`, `rollback`,
+/// `draw`, `timeout`, `forfeited`, `cancelled`, `abandoned`, `aborted`,
+/// `found`, `missed`, `serieswon`, `bet`, `betpaid`, `hubfailed`,
+/// `hubsent`.
+#[contractevent(topics = ["room_event"])]
+pub struct RoomEventPublished {
+    #[topic]
+    pub room_id: u32,
+    #[topic]
+    pub kind: Symbol,
+    pub actor: Address,
+    pub ledger: u32,
+}
+
+/// Copy of a room event addressed to one of the room's observers.
+#[contractevent(topics = ["observed"])]
+pub struct ObservedRoomEvent {
+    #[topic]
+    pub observer: Address,
+    #[topic]
+    pub room_id: u32,
+    #[topic]
+    pub kind: Symbol,
+    pub actor: Address,
+    pub ledger: u32,
+}
+
+/// `RoomEventPublished` without its body, for minimal-verbosity rooms.
+#[contractevent(topics = ["room_event"])]
+pub struct MinimalRoomEvent {
+    #[topic]
+    pub room_id: u32,
+    #[topic]
+    pub kind: Symbol,
+}
+
+/// `ObservedRoomEvent` without its body, for minimal-verbosity rooms.
+#[contractevent(topics = ["observed"])]
+pub struct MinimalObservedRoomEvent {
+    #[topic]
+    pub observer: Address,
+    #[topic]
+    pub room_id: u32,
+    #[topic]
+    pub kind: Symbol,
+}
+
+/// A room was opened by `player_a`.
+#[contractevent]
+pub struct RoomCreated {
+    #[topic]
+    pub room_id: u32,
+    pub player_a: Address,
+    pub player_a_points: i128,
+}
+
+/// `player_b` took the open seat in a room.
+#[contractevent]
+pub struct PlayerJoined {
+    #[topic]
+    pub room_id: u32,
+    pub player_b: Address,
+    pub player_b_points: i128,
+}
+
+/// A player's burial commitment is in.
+#[contractevent]
+pub struct TreasureBuried {
+    #[topic]
+    pub room_id: u32,
+    pub player: Address,
+    pub commitment: BytesN<32>,
+}
+
+/// A tile was dug.
+#[contractevent]
+pub struct TileDug {
+    #[topic]
+    pub room_id: u32,
+    pub digger: Address,
+    pub island_id: u32,
+    pub tile_id: u32,
+}
+
+/// A winning reveal ended the game.
+#[contractevent]
+pub struct TreasureRevealed {
+    #[topic]
+    pub room_id: u32,
+    pub winner: Address,
+    pub island_id: u32,
+    pub tile_id: u32,
+}
+
+/// The data body of a published [`RoomEventPublished`] or
+/// [`ObservedRoomEvent`].
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoomEventData {
+    pub actor: Address,
+    pub ledger: u32,
+}
+
+/// An entry in a room's event outbox.
+///
+/// Mirrors the `room_event` contract events so clients that poll instead of
+/// subscribing can still catch up on what happened in a room.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoomEvent {
+    pub kind: Symbol,
+    pub actor: Address,
+    pub ledger: u32,
+}
+
+/// Publish a room event and append it to the room's outbox, dropping the
+/// oldest entry once the outbox is full.
+#[cfg(not(feature = "interface"))]
+pub(crate) fn emit_room_event(env: &Env, room: &Room, kind: Symbol, actor: &Address) {
+    let room_id = room.room_id;
+    let ledger = env.ledger().sequence();
+    let minimal = is_minimal(env, room);
+    if minimal {
+        MinimalRoomEvent {
+            room_id,
+            kind: kind.clone(),
+        }
+        .publish(env);
+    } else {
+        RoomEventPublished {
+            room_id,
+            kind: kind.clone(),
+            actor: actor.clone(),
+            ledger,
+        }
+        .publish(env);
+    }
+
+    let observers: Vec<Address> = env
+        .storage()
+        .temporary()
+        .get(&DataKey::Observers(room_id))
+        .unwrap_or_else(|| Vec::new(env));
+    for observer in observers.iter() {
+        if minimal {
+            MinimalObservedRoomEvent {
+                observer,
+                room_id,
+                kind: kind.clone(),
+            }
+            .publish(env);
+        } else {
+            ObservedRoomEvent {
+                observer,
+                room_id,
+                kind: kind.clone(),
+                actor: actor.clone(),
+                ledger,
+            }
+            .publish(env);
+        }
+    }
+
+    let key = DataKey::Events(room_id);
+    let mut events: Vec<RoomEvent> = env
+        .storage()
+        .temporary()
+        .get(&key)
+        .unwrap_or_else(|| Vec::new(env));
+    let max = instance_u32(env, &DataKey::MaxRecentEvents, DEFAULT_RECENT_EVENTS);
+    while events.len() >= max {
+        events.pop_front();
+    }
+    events.push_back(RoomEvent {
+        kind,
+        actor: actor.clone(),
+        ledger,
+    });
+    env.storage().temporary().set(&key, &events);
+    bump_temp(env, &key);
+}
+
+/// Publish a typed detail event for a room, unless the room runs with
+/// minimal events.
+#[cfg(not(feature = "interface"))]
+pub(crate) fn publish_detail(env: &Env, room: &Room, event: impl Event) {
+    if !is_minimal(env, room) {
+        event.publish(env);
+    }
+}
+
+/// Whether a room's events drop their body: the room's own verbosity,
+/// falling back to the contract-wide one.
+#[cfg(not(feature = "interface"))]
+fn is_minimal(env: &Env, room: &Room) -> bool {
+    let verbosity = match room.config.event_verbosity {
+        EventVerbosity::Default => env
+            .storage()
+            .instance()
+            .get(&DataKey::EventVerbosity)
+            .unwrap_or(EventVerbosity::Full),
+        v => v,
+    };
+    verbosity == EventVerbosity::Minimal
+}
@@ -1,16 +1,31 @@
 #![no_std]
 
 use soroban_sdk::{
-    contract, contracterror, contractimpl, contracttype, contractclient,
-    panic_with_error,
-    Address, BytesN, Env, Vec,
-    crypto::Hash,
+    contracterror, contracttype, contractclient,
+    Address, Bytes, BytesN, Env, Symbol, Vec,
+};
+
+#[cfg(not(feature = "interface"))]
+mod bls;
+pub mod events;
+#[cfg(not(feature = "interface"))]
+mod poseidon;
+#[cfg(all(any(test, feature = "testutils"), not(feature = "interface")))]
+pub mod testutils;
+
+pub use events::{
+    MinimalObservedRoomEvent, MinimalRoomEvent, ObservedRoomEvent, PlayerJoined, RoomCreated,
+    RoomEvent, RoomEventData, RoomEventPublished, TileDug, TreasureBuried, TreasureRevealed,
 };
 
 // ---------------------------------------------------------------------------
 // Game Hub client interface (calls into the hub contract)
 // ---------------------------------------------------------------------------
 
+/// `draw_game` and `abort_game` are only called on hubs set to
+/// `HubKind::Extended`; the official hub implements neither. The
+/// `*_session` calls and `get_game_token` are for `HubKind::Session` hubs,
+/// like `contracts/game-hub`.
 #[contractclient(name = "GameHubClient")]
 pub trait GameHub {
     fn start_game(
@@ -23,6 +38,21 @@ pub trait GameHub {
         player2_points: i128,
     );
     fn end_game(env: Env, session_id: u32, player1_won: bool);
+    fn draw_game(env: Env, session_id: u32);
+    fn abort_game(env: Env, session_id: u32);
+    fn end_session(env: Env, game_id: Address, session_id: u32, player1_won: bool);
+    fn draw_session(env: Env, game_id: Address, session_id: u32);
+    fn abort_session(env: Env, game_id: Address, session_id: u32);
+    fn get_game_token(env: Env, game: Address) -> Option<Address>;
+}
+
+// ---------------------------------------------------------------------------
+// Leaderboard client interface (see contracts/leaderboard)
+// ---------------------------------------------------------------------------
+
+#[contractclient(name = "LeaderboardClient")]
+pub trait Leaderboard {
+    fn record_result(env: Env, winner: Address, loser: Address, points: i128);
 }
 
 // ---------------------------------------------------------------------------
@@ -41,7 +71,8 @@ pub enum Error {
     RoomFull = 3,
     /// Cannot join your own room
     SelfPlay = 4,
-    /// Wrong game phase for this action
+    /// Wrong game phase for this action. No longer raised; see the
+    /// `WrongPhaseExpected*` variants.
     WrongPhase = 5,
     /// Not your turn
     NotYourTurn = 6,
@@ -63,6 +94,114 @@ pub enum Error {
     NoOpponent = 14,
     /// Unauthorized caller
     Unauthorized = 15,
+    /// BLS proof of possession or aggregate signature did not verify
+    BadSignature = 16,
+    /// Player has not registered a BLS public key
+    BlsKeyMissing = 17,
+    /// Amount must be positive
+    InvalidAmount = 18,
+    /// Reporter already filed a report for this room
+    AlreadyReported = 19,
+    /// Reporter has used up their reports for now, or the room is full
+    TooManyReports = 20,
+    /// Player has not buried a commitment yet
+    NotBuried = 21,
+    /// Action is not available in this room's dig mode
+    WrongMode = 22,
+    /// Both players must commit before either reveals their dig
+    RoundNotReady = 23,
+    /// Player already committed (or revealed) a dig this round
+    AlreadyCommitted = 24,
+    /// Room configuration options are inconsistent
+    InvalidConfig = 25,
+    /// Seeker has used up their dig budget
+    BudgetExhausted = 26,
+    /// Seeker still has digs left
+    BudgetRemaining = 27,
+    /// Action needs the room to be Waiting for players
+    WrongPhaseExpectedWaiting = 28,
+    /// Action needs the room to be in the Burying phase
+    WrongPhaseExpectedBurying = 29,
+    /// Action needs the room to be in the Playing phase
+    WrongPhaseExpectedPlaying = 30,
+    /// Action needs the game to have ended
+    WrongPhaseExpectedEnded = 31,
+    /// Address given as Player A is not this room's Player A
+    NotPlayerA = 32,
+    /// Address given as Player B is not this room's Player B
+    NotPlayerB = 33,
+    /// The opponent has no commitment to reveal against
+    CommitmentMissing = 34,
+    /// Admin has acted within the backup's inactivity threshold
+    AdminStillActive = 35,
+    /// Player has no committed dig to reveal
+    NoPendingDig = 36,
+    /// Not enough action points left this turn for that island
+    NotEnoughActions = 37,
+    /// Joiner's reputation is below the room's minimum
+    ReputationTooLow = 38,
+    /// Room already has the maximum number of observers
+    TooManyObservers = 39,
+    /// Room is frozen by the admin
+    RoomFrozen = 40,
+    /// No dig to roll back
+    NoDigToRollBack = 41,
+    /// Player has not registered an ed25519 burial key
+    BurialKeyMissing = 42,
+    /// Board still has undug tiles
+    BoardNotExhausted = 43,
+    /// Endgame reveal window has not closed yet
+    RevealWindowOpen = 44,
+    /// Endgame reveal window has closed
+    RevealWindowClosed = 45,
+    /// Start points differ from the stakes escrowed for the room
+    StakeMismatch = 46,
+    /// Opponent is not past their turn deadline
+    TurnNotExpired = 47,
+    /// Burial deadline has not passed yet
+    BurialWindowOpen = 48,
+    /// Treasure was already revealed
+    AlreadyFound = 49,
+    /// Invite code missing or wrong for a private room
+    BadInviteCode = 50,
+    /// Series not found
+    SeriesNotFound = 51,
+    /// Series with that ID already exists
+    SeriesExists = 52,
+    /// Series already has a winner
+    SeriesOver = 53,
+    /// Series' previous game has not ended
+    SeriesGameLive = 54,
+    /// Players cannot bet on their own room
+    BettorIsPlayer = 55,
+    /// Bettor already backs the other player
+    BetSideMismatch = 56,
+    /// No winning or refundable bet to claim
+    NothingToClaim = 57,
+    /// Player is already waiting in that queue
+    AlreadyQueued = 58,
+    /// Player is not waiting in that queue
+    NotQueued = 59,
+    /// Challenger already has a pending challenge to that opponent
+    ChallengeExists = 60,
+    /// No pending challenge between those players
+    ChallengeNotFound = 61,
+    /// Contract is paused: no room can be created or started
+    Paused = 62,
+    /// No admin transfer has been proposed
+    NoPendingAdmin = 63,
+    /// Hub is not on the admin's approved list
+    HubNotApproved = 64,
+    /// Room has no hub report waiting to be resent
+    NoPendingReport = 65,
+    /// Hub call failed again
+    HubUnavailable = 66,
+    /// Fee rate is above `MAX_FEE_BPS`
+    FeeTooHigh = 67,
+    /// The hub escrows points, which takes both players signing the start
+    HubEscrows = 68,
+    /// Opened treasures are not the burial's distinct tiles on the board
+    BadOpening = 69,
 }
 
 // ---------------------------------------------------------------------------
@@ -78,13 +217,185 @@ pub struct DigRecord {
     pub tile_id: u32,
 }
 
+/// How burial commitments are computed for a room.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum CommitmentScheme {
+    /// SHA-256(room_id ‖ nonce ‖ rules_hash ‖ island_id ‖ tile_id ‖ salt).
+    Sha256 = 0,
+    /// Poseidon2(room_id, island_id, tile_id, owner_hash, salt, nonce,
+    /// rules_hash) over BN254, cheap to re-prove inside a zero-knowledge
+    /// circuit.
+    Poseidon2 = 1,
+    /// SHA-256(room_id ‖ island_id ‖ tile_id ‖ salt), the original scheme.
+    /// Only set by `migrate`, so rooms buried before the upgrade can still
+    /// reveal; new rooms cannot choose it.
+    LegacySha256 = 2,
+}
+
+/// How players take turns digging.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum DigMode {
+    /// Players alternate single digs, Player A first.
+    Alternating = 0,
+    /// Each round both players commit a dig, then both reveal; the digs are
+    /// applied together so neither player gains from moving first.
+    Simultaneous = 1,
+    /// Players alternate, but each dig is committed with `commit_dig` and
+    /// then opened with `reveal_dig`, so the target cannot be reacted to
+    /// before it lands.
+    Committed = 2,
+}
+
+/// Who hides and who seeks.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum Roles {
+    /// Both players hide a treasure and both dig.
+    Duel = 0,
+    /// Player A only hides, burying `treasures` of them; Player B only
+    /// seeks, within a dig budget.
+    AHides = 1,
+    /// Player B only hides, burying `treasures` of them; Player A only
+    /// seeks, within a dig budget.
+    BHides = 2,
+}
+
+/// How much room events carry. Minimal events keep the topics, so
+/// indexers can still follow a room, but drop the payload to save fees.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum EventVerbosity {
+    /// Per room: follow the contract-wide setting. Contract-wide: `Full`.
+    Default = 0,
+    Full = 1,
+    Minimal = 2,
+}
+
+/// Options fixed when a room is created.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoomConfig {
+    pub commitment_scheme: CommitmentScheme,
+    pub dig_mode: DigMode,
+    pub roles: Roles,
+    /// Digs the seeker gets before the hider can claim the win. Must be
+    /// non-zero for hider/seeker rooms, ignored in duels.
+    pub dig_budget: u32,
+    /// Odds the hider plays at, in basis points, weighting escrowed stakes
+    /// by role: a surviving hider wins its stake times these odds out of
+    /// the seeker's stake, and the seeker gets the rest back, while a
+    /// seeker who finds the treasure takes the whole pot. 0 means even
+    /// odds. Hider/seeker rooms only.
+    pub hider_odds_bps: u32,
+    /// Ledgers the player on turn has to move. 0 takes the contract's
+    /// default (see `Config`) when the room is created. At most
+    /// `MAX_TIMEOUT_LEDGERS`, like the two below.
+    pub turn_timeout_ledgers: u32,
+    /// Ledgers both players have to bury once the room starts; 0 as above.
+    pub bury_timeout_ledgers: u32,
+    /// Ledgers either player may still reveal once the board is
+    /// exhausted; 0 as above.
+    pub reveal_window_ledgers: u32,
+    /// Action points per turn, spent by digs at one point per ten tiles of
+    /// the island dug. 0 means one dig per turn. Duels without simultaneous
+    /// digging only; must cover the costliest island.
+    pub action_points: u32,
+    /// Completed games a joiner needs (see `Reputation`); 0 admits anyone.
+    pub min_completed: u32,
+    /// Reputation score a joiner needs, in basis points; 0 admits anyone.
+    pub min_score_bps: u32,
+    pub event_verbosity: EventVerbosity,
+    /// Burials must come through `bury_treasure_signed`, signed by the
+    /// player's registered burial key, and cannot be reburied.
+    pub signed_burials: bool,
+    /// Salts must follow the derived-salt scheme (see `get_salt_message`),
+    /// checked on-chain through `reveal_treasure_derived`.
+    pub derived_salts: bool,
+    /// Token (SAC) each player's points are escrowed in. Stakes move into
+    /// the contract on create and join, the pot goes to the winner, and a
+    /// draw refunds both. `None` keeps points as hub-side numbers only.
+    pub stake_token: Option<Address>,
+    /// Treasures each player buries, 0 or 1 for the classic single
+    /// treasure. Above 1, a burial commits to the Merkle root of one
+    /// commitment per treasure, reveals go through
+    /// `reveal_treasure_proof`, and finding a majority of the opponent's
+    /// treasures wins. Nothing shows a root holds real treasures until it
+    /// is opened, so a win or a draw also takes opening one's own (see
+    /// `open_treasures`). At most `MAX_TREASURES`.
+    pub treasures: u32,
+    /// A reveal that misses is recorded as a dig of the guessed tile and
+    /// costs the turn, instead of reverting. Not for simultaneous rooms.
+    pub guess_mode: bool,
+    /// SHA-256 of the invite code of a private room. Private rooms are
+    /// joined through `join_private_room` with the code, and are never
+    /// listed in the lobby.
+    pub invite_hash: Option<BytesN<32>>,
+    /// Game Hub the room reports to, one the admin has approved. `None`
+    /// reports to the contract's hub (see `set_hub`).
+    pub hub: Option<Address>,
+    /// The room never calls a Game Hub, for casual play: its result stays
+    /// local. Cannot be combined with `hub`.
+    pub hubless: bool,
+}
+
+/// One of a player's own treasures, opened once the game no longer
+/// depends on it to prove their burial held real treasures.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TreasureLeaf {
+    pub island_id: u32,
+    pub tile_id: u32,
+    pub salt: BytesN<32>,
+}
+
+/// One room in a `create_rooms_batch` call, seating `player_a`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoomRequest {
+    pub room_id: u32,
+    pub player_a: Address,
+    pub player_a_points: i128,
+    pub config: RoomConfig,
+}
+
+/// A committed dig awaiting its reveal, in simultaneous or committed-dig
+/// rooms.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingDig {
+    /// SHA-256("dig" ‖ room_id ‖ nonce ‖ xdr(digger) ‖ island_id ‖ tile_id ‖
+    /// salt).
+    pub commitment: BytesN<32>,
+    pub revealed: bool,
+    pub island_id: u32,
+    pub tile_id: u32,
+}
+
+/// Progress of the current simultaneous-dig round.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoundState {
+    pub committed_a: bool,
+    pub committed_b: bool,
+    pub revealed_a: bool,
+    pub revealed_b: bool,
+}
+
 /// Full room state.
 ///
 /// `phase` values:
 ///   0 = Waiting (created, waiting for Player B or start)
 ///   1 = Burying  (both players submit commitments)
-///   2 = Playing  (turn-based digging)
+///   2 = Playing  (turn-based or simultaneous-round digging)
 ///   3 = Ended
+///   4 = Draw     (board exhausted, reveal window closed unclaimed; or
+///                 aborted, nobody having buried in time)
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Room {
@@ -104,6 +415,402 @@ pub struct Room {
     pub game_active: bool,
     pub winner: Address,             // zero-address until decided
     pub digs: Vec<DigRecord>,
+    /// Action points left in the current turn (see `RoomConfig`).
+    pub actions_left: u32,
+    pub config: RoomConfig,
+    /// Contract-generated nonce mixed into every commitment pre-image, so
+    /// the same choice never hashes the same in two rooms. Zero until start.
+    pub nonce: BytesN<32>,
+    /// Contract-generated nonce drawn when the room is created. It binds
+    /// `start_room_aggregated` signatures to this room, so they cannot
+    /// start a later room that reuses the id.
+    pub created_nonce: BytesN<32>,
+    /// Set by the admin during a hub outage: the game settles locally and
+    /// never calls the hub's `end_game`.
+    pub hubless: bool,
+    /// Set by the admin while an incident is investigated; no player
+    /// action is accepted until it is cleared.
+    pub frozen: bool,
+    /// Ledger the room was last frozen at. Thawing pushes `turn_deadline`
+    /// and `exhausted_at` back by the time spent frozen.
+    pub frozen_at: u32,
+    /// Ledger the last tile was dug at. From then on either player may
+    /// reveal, for the config's `reveal_window_ledgers`; after that the
+    /// game is a draw.
+    pub exhausted_at: Option<u32>,
+    /// Ledger by which the player on turn must move, after which the
+    /// opponent may `claim_timeout`. While Burying, the deadline for both
+    /// burials instead (see `claim_abandoned`). Zero before the start.
+    pub turn_deadline: u32,
+    /// The series this room is a game of, if any.
+    pub series_id: Option<u32>,
+    /// The room this one is a `rematch` of, if any.
+    pub rematch_of: Option<u32>,
+}
+
+/// `Room` as it was first released, before the config, nonce and
+/// admin flags were added. Returned by `get_room_v1` so consumers built
+/// against that layout keep decoding while they migrate.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoomV1 {
+    pub room_id: u32,
+    pub player_a: Address,
+    pub player_b: Address,
+    pub player_a_points: i128,
+    pub player_b_points: i128,
+    pub phase: u32,
+    pub turn_is_a: bool,
+    pub island_tile_counts: Vec<u32>,
+    pub has_commitment_a: bool,
+    pub has_commitment_b: bool,
+    pub game_active: bool,
+    pub winner: Address,
+    pub digs: Vec<DigRecord>,
+}
+
+impl From<Room> for RoomV1 {
+    fn from(room: Room) -> Self {
+        RoomV1 {
+            room_id: room.room_id,
+            player_a: room.player_a,
+            player_b: room.player_b,
+            player_a_points: room.player_a_points,
+            player_b_points: room.player_b_points,
+            phase: room.phase,
+            turn_is_a: room.turn_is_a,
+            island_tile_counts: room.island_tile_counts,
+            has_commitment_a: room.has_commitment_a,
+            has_commitment_b: room.has_commitment_b,
+            game_active: room.game_active,
+            winner: room.winner,
+            digs: room.digs,
+        }
+    }
+}
+
+/// Which Game Hub interface a hub implements; see `set_hub_kind`.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum HubKind {
+    /// `start_game` and `end_game` only, like the official hub. Draws and
+    /// aborts close the session with `end_game(session_id, false)`, as the
+    /// interface has no way to report no winner. The default.
+    Legacy = 0,
+    /// Also `draw_game` and `abort_game`.
+    Extended = 1,
+    /// Settles by game as well as session, through `end_session`,
+    /// `draw_session` and `abort_session`, so another game's session with
+    /// the same id never gets in the way. May escrow points; see
+    /// `Error::HubEscrows`.
+    Session = 2,
+}
+
+/// A game outcome owed to a Game Hub, by session.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum HubReport {
+    /// `end_game(session_id, player1_won)`
+    Ended(u32, bool),
+    /// `draw_game(session_id)`
+    Drawn(u32),
+    /// `abort_game(session_id)`
+    Aborted(u32),
+}
+
+/// A hub report that failed when its game finished, kept for
+/// `retry_hub_report`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingHubReport {
+    pub hub: Address,
+    pub report: HubReport,
+}
+
+/// A post-game tip from one player to the other.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Tip {
+    pub from: Address,
+    pub to: Address,
+    pub token: Address,
+    pub amount: i128,
+}
+
+/// Spectator stakes on a room, per side, in the room's stake token.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BetBook {
+    pub total_a: i128,
+    pub total_b: i128,
+}
+
+/// One spectator's stake on a room.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Bet {
+    pub on_player_a: bool,
+    pub amount: i128,
+}
+
+/// A player waiting in the matchmaking queue.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct QueueEntry {
+    pub player: Address,
+    pub points: i128,
+}
+
+/// Ledger sequence at which each player buried, or `None` if not yet.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BurialLedgers {
+    pub player_a: Option<u32>,
+    pub player_b: Option<u32>,
+}
+
+/// A verified pre-image of a player's commitment.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Reveal {
+    /// Whose treasure this reveals.
+    pub owner: Address,
+    pub island_id: u32,
+    pub tile_id: u32,
+    pub salt: BytesN<32>,
+}
+
+/// Both commitments and all reveals of an ended room, for third-party
+/// verification.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PublishedCommitments {
+    pub commitment_a: Option<BytesN<32>>,
+    pub commitment_b: Option<BytesN<32>>,
+    pub reveals: Vec<Reveal>,
+}
+
+/// A report filed against a finished game.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GameReport {
+    pub reporter: Address,
+    /// Short reason code, e.g. `collusion` or `bot`.
+    pub reason: Symbol,
+    pub ledger: u32,
+}
+
+/// Reports one address has filed in its current window.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReportWindow {
+    /// Ledger the window opened at.
+    pub start: u32,
+    pub count: u32,
+}
+
+/// The contract's effective configuration, read in one call by
+/// `get_config`. `set_config` writes the admin-tunable settings; the fixed
+/// ones are reported for reference and must be passed back unchanged.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Config {
+    /// `None` runs the contract standalone: rooms without a hub of their
+    /// own settle locally.
+    pub game_hub: Option<Address>,
+    /// Events kept in each room's outbox, 1..=`RECENT_EVENTS_CAP`.
+    pub max_recent_events: u32,
+    /// Reports one address may file per `REPORT_WINDOW_LEDGERS`,
+    /// 1..=`REPORTS_CAP`.
+    pub max_reports_per_reporter: u32,
+    /// Applies to rooms whose own verbosity is `Default`.
+    pub event_verbosity: EventVerbosity,
+    /// Ledgers the player on turn has to move, for rooms created without
+    /// their own; 1..=`MAX_TIMEOUT_LEDGERS`.
+    pub turn_timeout_ledgers: u32,
+    /// Ledgers both players have to bury once a room starts, as above.
+    pub bury_timeout_ledgers: u32,
+    /// Ledgers either player may still reveal once the board is
+    /// exhausted, as above.
+    pub reveal_window_ledgers: u32,
+    /// New rooms are blocked; see `pause`.
+    pub paused: bool,
+    /// Fixed: layout version of the stored state; see `migrate`.
+    pub version: u32,
+    /// Basis points taken from every won pot, 0..=`MAX_FEE_BPS`.
+    pub fee_bps: u32,
+    /// Who withdraws fees; `None` leaves it to the admin.
+    pub treasury: Option<Address>,
+}
+
+/// Where a page starts in a list endpoint and how many items it holds.
+/// A `limit` of 0, or above the contract's page cap, returns a full page.
+/// Lists with a fixed cap, such as observers and recent events, are
+/// returned whole instead.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Cursor {
+    pub start: u32,
+    pub limit: u32,
+}
+
+/// A page of a room's dig log. `next_cursor` is the `start` of the
+/// following page, or `None` on the last one.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DigPage {
+    pub items: Vec<DigRecord>,
+    pub next_cursor: Option<u32>,
+}
+
+/// A page of a room's tips; see `DigPage`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TipPage {
+    pub items: Vec<Tip>,
+    pub next_cursor: Option<u32>,
+}
+
+/// A page of a room's reports; see `DigPage`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReportPage {
+    pub items: Vec<GameReport>,
+    pub next_cursor: Option<u32>,
+}
+
+/// A page of the lobby's open rooms; see `DigPage`. Rooms that expired
+/// while listed are skipped, so a page may hold fewer than `limit`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoomPage {
+    pub items: Vec<Room>,
+    pub next_cursor: Option<u32>,
+}
+
+/// A page of a player's room ids; see `RoomPage`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoomIdPage {
+    pub items: Vec<u32>,
+    pub next_cursor: Option<u32>,
+}
+
+/// A room together with its per-player burial status, the current
+/// simultaneous-dig round and the effective config, returned by
+/// `get_full_view`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FullView {
+    pub room: Room,
+    pub burial: BurialLedgers,
+    pub round: RoundState,
+    pub config: Config,
+}
+
+/// Ledger entries a room currently occupies, returned by
+/// `get_storage_report`. `bytes` is the XDR size of keys and values, a
+/// close estimate of what rent is charged on.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StorageReport {
+    pub entries: u32,
+    pub bytes: u32,
+}
+
+/// What `migrate` would do with a list of rooms, from
+/// `preview_migration`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MigrationPreview {
+    /// Layout version stored now.
+    pub from_version: u32,
+    /// Layout version `migrate` records.
+    pub to_version: u32,
+    /// Rooms in the original layout, which `migrate` would rewrite.
+    pub converted: Vec<u32>,
+    /// Commitment scheme every converted room is put on.
+    pub scheme: CommitmentScheme,
+    /// Converted rooms `audit_room` would flag afterwards.
+    pub invalid: Vec<u32>,
+    /// Rooms already in the current layout, left alone.
+    pub current: u32,
+    /// Ids with no stored room, skipped.
+    pub missing: u32,
+}
+
+/// A backup admin and how long the admin must be inactive before the
+/// backup can claim the role.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminBackup {
+    pub backup: Address,
+    pub inactivity_ledgers: u32,
+}
+
+/// A best-of-N match between two players, one room per game. The hub
+/// sees the whole series as one session: `start_game` on the first
+/// game and `end_game` once a player reaches `wins_needed`. Drawn games
+/// count for neither player.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Series {
+    pub series_id: u32,
+    pub player_a: Address,
+    pub player_b: Address,
+    pub wins_needed: u32,
+    pub wins_a: u32,
+    pub wins_b: u32,
+    /// Room ids of the games so far, oldest first.
+    pub rooms: Vec<u32>,
+    /// Hub session of the series: the id of its first started room.
+    pub session_id: Option<u32>,
+    pub winner: Option<Address>,
+}
+
+/// How reliably an address finishes the games it starts. Every start
+/// counts until the game ends with a winner, so abandoned rooms (and any
+/// still in play) weigh the score down.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Reputation {
+    pub started: u32,
+    pub completed: u32,
+    /// `completed / started` in basis points; 0 before the first game.
+    pub score_bps: u32,
+}
+
+/// A player's results across every game that ended with a winner or a
+/// draw. `total_points_won` sums the opponent's points of each win.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PlayerStats {
+    pub games: u32,
+    pub wins: u32,
+    pub losses: u32,
+    pub draws: u32,
+    pub total_points_won: i128,
+}
+
+/// Canonical record of a finished room, exported XDR-encoded by
+/// `export_attestation` for co-signing and consumption by other chains.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Attestation {
+    /// The PiratesTreasure contract that produced the result.
+    pub contract: Address,
+    pub room_id: u32,
+    pub player_a: Address,
+    pub player_b: Address,
+    pub player_a_points: i128,
+    pub player_b_points: i128,
+    pub winner: Address,
+    /// 3 if `winner` won; 4 for a draw or abort, where `winner` is only a
+    /// placeholder.
+    pub phase: u32,
+    /// SHA-256 over the XDR of the dig log followed by both commitments.
+    pub transcript_hash: BytesN<32>,
 }
 
 // ---------------------------------------------------------------------------
@@ -111,456 +818,376 @@ pub struct Room {
 // ---------------------------------------------------------------------------
 
 #[contracttype]
-#[derive(Clone)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum DataKey {
     /// Room(room_id) → Room
     Room(u32),
     /// Commitment(room_id, is_player_a) → BytesN<32>
     Commitment(u32, bool),
+    /// Opened(room_id, is_player_a) → bool (the burial was opened)
+    Opened(u32, bool),
     /// Admin address
     Admin,
-    /// Game Hub contract address
+    /// Address proposed as the next admin, until it accepts
+    PendingAdmin,
+    /// Layout version of the stored state; absent means 1
+    StateVersion,
+    /// Game Hub contract address (absent when running standalone)
     GameHubAddress,
+    /// ApprovedHubs → Vec<Address> (further hubs rooms may choose)
+    ApprovedHubs,
+    /// HubKind(hub) → HubKind; absent means `Legacy`
+    HubKind(Address),
+    /// Events(room_id) → Vec<RoomEvent> (ring buffer, newest last)
+    Events(u32),
+    /// BlsKey(player) → BytesN<96> (persistent, G1 public key)
+    BlsKey(Address),
+    /// Tips(room_id) → Vec<Tip>
+    Tips(u32),
+    /// Reports(room_id) → Vec<GameReport>
+    Reports(u32),
+    /// BuriedAt(room_id, is_player_a) → u32 (ledger sequence)
+    BuriedAt(u32, bool),
+    /// Reveals(room_id) → Vec<Reveal>
+    Reveals(u32),
+    /// Number of room nonces drawn so far (one per creation, one per start)
+    NonceCounter,
+    /// RoundDig(room_id, is_player_a) → PendingDig (simultaneous and
+    /// committed-dig modes)
+    RoundDig(u32, bool),
+    /// Outbox length per room; defaults to `DEFAULT_RECENT_EVENTS`
+    MaxRecentEvents,
+    /// Report limit per reporter; defaults to `DEFAULT_REPORTS_PER_REPORTER`
+    MaxReportsPerReporter,
+    /// Turn timeout for new rooms; defaults to `DEFAULT_TURN_TIMEOUT_LEDGERS`
+    TurnTimeout,
+    /// Bury timeout for new rooms; defaults to `DEFAULT_BURY_TIMEOUT_LEDGERS`
+    BuryTimeout,
+    /// Reveal window for new rooms; defaults to
+    /// `DEFAULT_REVEAL_WINDOW_LEDGERS`
+    RevealWindow,
+    /// ReportWindow(reporter) → ReportWindow (temporary)
+    ReportWindow(Address),
+    /// Backup admin for the dead-man switch → AdminBackup
+    AdminBackup,
+    /// Ledger sequence of the admin's last authorized action
+    AdminLastActive,
+    /// Reputation(player) → Reputation (persistent)
+    Reputation(Address),
+    /// Observers(room_id) → Vec<Address>
+    Observers(u32),
+    /// Contract-wide EventVerbosity; defaults to `Full`
+    EventVerbosity,
+    /// BurialKey(player) → BytesN<32> (persistent, ed25519 public key)
+    BurialKey(Address),
+    /// OpenRooms → Vec<u32> (ids of rooms waiting for an opponent, at
+    /// most `MAX_OPEN_ROOMS`)
+    OpenRooms,
+    /// PlayerRooms(player) → Vec<u32> (persistent, ids of rooms the
+    /// player sits in that have not ended)
+    PlayerRooms(Address),
+    /// Last room id handed out by `create_room_auto`
+    RoomCounter,
+    /// Series(series_id) → Series
+    Series(u32),
+    /// BetBook(room_id) → BetBook
+    BetBook(u32),
+    /// PendingHubReport(room_id) → PendingHubReport
+    PendingHubReport(u32),
+    /// Bet(room_id, bettor) → Bet (removed once claimed)
+    Bet(u32, Address),
+    /// Rating(player) → u32 (persistent, Elo rating)
+    Rating(Address),
+    /// Stats(player) → PlayerStats (persistent)
+    Stats(Address),
+    /// Leaderboard contract that decided games are reported to
+    LeaderboardAddress,
+    /// Queue(bucket) → QueueEntry (the player waiting for a match at
+    /// stakes in that bucket)
+    Queue(u32),
+    /// Challenge(challenger, opponent) → i128 (the challenger's points)
+    Challenge(Address, Address),
+    /// Set while the admin has paused room creation and starts
+    Paused,
+    /// Fee taken from every won pot, in basis points; absent means none
+    FeeBps,
+    /// Address that withdraws fees in place of the admin
+    Treasury,
+    /// Fees → Map<Address, i128> (fees collected per stake token, not yet
+    /// withdrawn)
+    Fees,
 }
 
 // ---------------------------------------------------------------------------
-// TTL helpers (30-day temporary storage)
+// Contract interface
 // ---------------------------------------------------------------------------
 
-const DAY_IN_LEDGERS: u32 = 17_280;
-const TTL_BUMP: u32 = 30 * DAY_IN_LEDGERS;        // 518 400
-const TTL_THRESHOLD: u32 = TTL_BUMP - DAY_IN_LEDGERS; // 501 120
-
-fn bump_temp(env: &Env, key: &DataKey) {
-    env.storage()
-        .temporary()
-        .extend_ttl(key, TTL_THRESHOLD, TTL_BUMP);
-}
-
-fn bump_instance(env: &Env) {
-    env.storage()
-        .instance()
-        .extend_ttl(TTL_THRESHOLD, TTL_BUMP);
-}
-
-// ---------------------------------------------------------------------------
-// Contract
-// ---------------------------------------------------------------------------
-
-#[contract]
-pub struct PiratesTreasure;
-
-#[contractimpl]
-impl PiratesTreasure {
-    // ── Constructor ────────────────────────────────────────────────────
-    pub fn __constructor(env: Env, admin: Address, game_hub: Address) {
-        env.storage().instance().set(&DataKey::Admin, &admin);
-        env.storage().instance().set(&DataKey::GameHubAddress, &game_hub);
-        bump_instance(&env);
-    }
-
+/// The public entrypoints of `PiratesTreasure`.
+///
+/// With the `interface` feature the contract itself is compiled out and this
+/// trait generates `PiratesTreasureClient`, so other contracts can depend on
+/// this crate for its types and client alone.
+///
+/// Failures surface as `Error` codes. A calling contract that wants to
+/// handle one instead of trapping uses the client's generated `try_*`
+/// methods (`try_create_room`, `try_join_room`, `try_dig`, ...), which
+/// return the `Error` and roll back only the failed call.
+#[cfg_attr(feature = "interface", contractclient(name = "PiratesTreasureClient"))]
+pub trait PiratesTreasureInterface {
     // ── Admin helpers ──────────────────────────────────────────────────
 
-    pub fn get_admin(env: Env) -> Address {
-        env.storage().instance().get(&DataKey::Admin).unwrap()
-    }
-
-    pub fn set_admin(env: Env, new_admin: Address) {
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        admin.require_auth();
-        env.storage().instance().set(&DataKey::Admin, &new_admin);
-        bump_instance(&env);
-    }
-
-    pub fn get_hub(env: Env) -> Address {
-        env.storage().instance().get(&DataKey::GameHubAddress).unwrap()
-    }
-
-    pub fn set_hub(env: Env, new_hub: Address) {
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        admin.require_auth();
-        env.storage().instance().set(&DataKey::GameHubAddress, &new_hub);
-        bump_instance(&env);
-    }
+    fn get_admin(env: Env) -> Address;
+    fn propose_admin(env: Env, new_admin: Address);
+    fn accept_admin(env: Env);
+    fn get_pending_admin(env: Env) -> Option<Address>;
+    fn get_hub(env: Env) -> Option<Address>;
+    fn set_hub(env: Env, new_hub: Address);
+    fn approve_hub(env: Env, hub: Address);
+    fn revoke_hub(env: Env, hub: Address);
+    fn get_approved_hubs(env: Env) -> Vec<Address>;
+    fn set_hub_kind(env: Env, hub: Address, kind: HubKind);
+    fn get_hub_kind(env: Env, hub: Address) -> HubKind;
+    fn get_leaderboard(env: Env) -> Option<Address>;
+    fn set_leaderboard(env: Env, leaderboard: Address);
+    fn get_config(env: Env) -> Config;
+    fn set_config(env: Env, config: Config);
+    fn set_admin_backup(env: Env, backup: Address, inactivity_ledgers: u32);
+    fn get_admin_backup(env: Env) -> Option<AdminBackup>;
+    fn claim_admin(env: Env, backup: Address);
+    fn set_room_hubless(env: Env, room_id: u32);
+    fn set_room_frozen(env: Env, room_id: u32, frozen: bool);
+    fn pause(env: Env);
+    fn unpause(env: Env);
+    fn is_paused(env: Env) -> bool;
+    fn set_fee_bps(env: Env, fee_bps: u32);
+    fn get_fee_bps(env: Env) -> u32;
+    fn set_treasury(env: Env, treasury: Option<Address>);
+    fn get_treasury(env: Env) -> Option<Address>;
+    fn get_fees(env: Env, token: Address) -> i128;
+    fn withdraw_fees(env: Env, to: Address);
 
     // ── Room lifecycle ─────────────────────────────────────────────────
 
-    /// Create a new room. Caller becomes Player A.
-    pub fn create_room(
+    fn create_room(env: Env, room_id: u32, player_a: Address, player_a_points: i128) -> Room;
+    fn create_room_auto(env: Env, player_a: Address, player_a_points: i128) -> u32;
+    fn create_room_with_config(
         env: Env,
         room_id: u32,
         player_a: Address,
         player_a_points: i128,
-    ) -> Room {
-        player_a.require_auth();
-
-        let key = DataKey::Room(room_id);
-        if env.storage().temporary().has(&key) {
-            panic_with_error!(&env, Error::RoomExists);
-        }
-
-        // 3 islands with 10, 20, 30 tiles
-        let mut tile_counts = Vec::new(&env);
-        tile_counts.push_back(10u32);
-        tile_counts.push_back(20u32);
-        tile_counts.push_back(30u32);
-
-        let room = Room {
-            room_id,
-            player_a: player_a.clone(),
-            player_b: player_a.clone(),  // placeholder — overwritten on join
-            player_a_points,
-            player_b_points: 0,
-            phase: 0,
-            turn_is_a: true,
-            island_tile_counts: tile_counts,
-            has_commitment_a: false,
-            has_commitment_b: false,
-            game_active: false,
-            winner: player_a.clone(),    // placeholder
-            digs: Vec::new(&env),
-        };
-
-        env.storage().temporary().set(&key, &room);
-        bump_temp(&env, &key);
-        room
-    }
-
-    /// Player B joins an existing room.
-    pub fn join_room(
+        config: RoomConfig,
+    ) -> Room;
+    fn create_rooms_batch(env: Env, rooms: Vec<RoomRequest>) -> Vec<u32>;
+    fn join_room(env: Env, room_id: u32, player_b: Address, player_b_points: i128) -> Room;
+    fn join_private_room(
         env: Env,
         room_id: u32,
         player_b: Address,
         player_b_points: i128,
-    ) -> Room {
-        player_b.require_auth();
-
-        let key = DataKey::Room(room_id);
-        let mut room: Room = env
-            .storage()
-            .temporary()
-            .get(&key)
-            .unwrap_or_else(|| panic_with_error!(&env, Error::RoomNotFound));
-
-        if room.phase != 0 {
-            panic_with_error!(&env, Error::WrongPhase);
-        }
-        // Room is "full" once someone other than player_a is stored.
-        // We detect "no opponent yet" by checking player_b == player_a (the placeholder).
-        if room.player_b != room.player_a {
-            panic_with_error!(&env, Error::RoomFull);
-        }
-        if player_b == room.player_a {
-            panic_with_error!(&env, Error::SelfPlay);
-        }
-
-        room.player_b = player_b;
-        room.player_b_points = player_b_points;
-
-        env.storage().temporary().set(&key, &room);
-        bump_temp(&env, &key);
-        room
-    }
-
-    /// Both players co-sign to start. Calls Game Hub `start_game`.
-    pub fn start_room(
+        invite_code: Bytes,
+    ) -> Room;
+    fn cancel_room(env: Env, room_id: u32, player_a: Address);
+    fn rematch(env: Env, room_id: u32) -> Room;
+    fn start_room(
         env: Env,
         room_id: u32,
         player_a: Address,
         player_b: Address,
         player_a_points: i128,
         player_b_points: i128,
-    ) -> Room {
-        // Both players must authorize the points they're putting up.
-        player_a.require_auth();
-        player_b.require_auth();
-
-        let key = DataKey::Room(room_id);
-        let mut room: Room = env
-            .storage()
-            .temporary()
-            .get(&key)
-            .unwrap_or_else(|| panic_with_error!(&env, Error::RoomNotFound));
-
-        if room.phase != 0 {
-            panic_with_error!(&env, Error::WrongPhase);
-        }
-        if room.player_b == room.player_a {
-            panic_with_error!(&env, Error::NoOpponent);
-        }
-
-        // Register with Game Hub BEFORE mutating local state.
-        let hub_addr: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .unwrap();
-        let hub = GameHubClient::new(&env, &hub_addr);
-        hub.start_game(
-            &env.current_contract_address(),
-            &room_id,
-            &player_a,
-            &player_b,
-            &player_a_points,
-            &player_b_points,
-        );
-
-        room.player_a_points = player_a_points;
-        room.player_b_points = player_b_points;
-        room.phase = 1; // → Burying
-        room.game_active = true;
-
-        env.storage().temporary().set(&key, &room);
-        bump_temp(&env, &key);
-        bump_instance(&env);
-        room
-    }
+    ) -> Room;
+    fn register_bls_key(
+        env: Env,
+        player: Address,
+        public_key: BytesN<96>,
+        proof_of_possession: BytesN<192>,
+    );
+    fn register_burial_key(env: Env, player: Address, public_key: BytesN<32>);
+    fn get_start_message(
+        env: Env,
+        room_id: u32,
+        player_a_points: i128,
+        player_b_points: i128,
+    ) -> BytesN<32>;
+    fn start_room_aggregated(
+        env: Env,
+        room_id: u32,
+        player_a_points: i128,
+        player_b_points: i128,
+        signature: BytesN<192>,
+    ) -> Room;
 
     // ── Bury phase ─────────────────────────────────────────────────────
 
-    /// Submit a commitment = SHA-256(room_id ‖ island_id ‖ tile_id ‖ salt).
-    /// The pre-image is kept secret; only the hash is stored on-chain.
-    pub fn bury_treasure(
+    fn bury_treasure(env: Env, room_id: u32, player: Address, commitment: BytesN<32>);
+    fn bury_treasure_signed(
         env: Env,
         room_id: u32,
         player: Address,
         commitment: BytesN<32>,
-    ) {
-        player.require_auth();
-
-        let key = DataKey::Room(room_id);
-        let mut room: Room = env
-            .storage()
-            .temporary()
-            .get(&key)
-            .unwrap_or_else(|| panic_with_error!(&env, Error::RoomNotFound));
-
-        if room.phase != 1 {
-            panic_with_error!(&env, Error::WrongPhase);
-        }
-
-        let is_a = player == room.player_a;
-        let is_b = player == room.player_b;
-        if !is_a && !is_b {
-            panic_with_error!(&env, Error::NotAPlayer);
-        }
-
-        if is_a && room.has_commitment_a {
-            panic_with_error!(&env, Error::AlreadyBuried);
-        }
-        if is_b && room.has_commitment_b {
-            panic_with_error!(&env, Error::AlreadyBuried);
-        }
-
-        // Store commitment separately (avoids nested Option issues).
-        let commit_key = DataKey::Commitment(room_id, is_a);
-        env.storage().temporary().set(&commit_key, &commitment);
-        bump_temp(&env, &commit_key);
-
-        if is_a {
-            room.has_commitment_a = true;
-        } else {
-            room.has_commitment_b = true;
-        }
-
-        // Auto-advance to Playing once both commitments are in.
-        if room.has_commitment_a && room.has_commitment_b {
-            room.phase = 2;
-            room.turn_is_a = true; // Player A digs first.
-        }
-
-        env.storage().temporary().set(&key, &room);
-        bump_temp(&env, &key);
-    }
+        signature: BytesN<64>,
+    );
+    fn rebury_treasure(env: Env, room_id: u32, player: Address, commitment: BytesN<32>);
 
     // ── Dig phase ──────────────────────────────────────────────────────
 
-    /// Dig a tile. Must be the caller's turn.
-    pub fn dig(
+    fn dig(env: Env, room_id: u32, player: Address, island_id: u32, tile_id: u32);
+    fn end_turn(env: Env, room_id: u32, player: Address);
+    fn rollback_dig(env: Env, room_id: u32);
+    fn commit_dig(env: Env, room_id: u32, player: Address, commitment: BytesN<32>);
+    fn reveal_dig(
         env: Env,
         room_id: u32,
         player: Address,
         island_id: u32,
         tile_id: u32,
-    ) {
-        player.require_auth();
-
-        let key = DataKey::Room(room_id);
-        let mut room: Room = env
-            .storage()
-            .temporary()
-            .get(&key)
-            .unwrap_or_else(|| panic_with_error!(&env, Error::RoomNotFound));
-
-        if room.phase != 2 {
-            panic_with_error!(&env, Error::WrongPhase);
-        }
-
-        let is_a = player == room.player_a;
-        let is_b = player == room.player_b;
-        if !is_a && !is_b {
-            panic_with_error!(&env, Error::NotAPlayer);
-        }
-
-        // Turn check.
-        if (room.turn_is_a && !is_a) || (!room.turn_is_a && !is_b) {
-            panic_with_error!(&env, Error::NotYourTurn);
-        }
-
-        // Validate island/tile.
-        if island_id >= room.island_tile_counts.len() {
-            panic_with_error!(&env, Error::InvalidIsland);
-        }
-        let max_tiles = room.island_tile_counts.get(island_id).unwrap();
-        if tile_id >= max_tiles {
-            panic_with_error!(&env, Error::InvalidTile);
-        }
-
-        // Check for duplicate digs.
-        for d in room.digs.iter() {
-            if d.island_id == island_id && d.tile_id == tile_id {
-                panic_with_error!(&env, Error::AlreadyDug);
-            }
-        }
-
-        room.digs.push_back(DigRecord {
-            digger: player,
-            island_id,
-            tile_id,
-        });
-
-        // Alternate turns.
-        room.turn_is_a = !room.turn_is_a;
-
-        env.storage().temporary().set(&key, &room);
-        bump_temp(&env, &key);
-    }
+        salt: BytesN<32>,
+    );
+    fn commit_round_dig(env: Env, room_id: u32, player: Address, commitment: BytesN<32>);
+    fn reveal_round_dig(
+        env: Env,
+        room_id: u32,
+        player: Address,
+        island_id: u32,
+        tile_id: u32,
+        salt: BytesN<32>,
+    );
+    fn get_round_state(env: Env, room_id: u32) -> RoundState;
+    fn claim_hider_win(env: Env, room_id: u32, hider: Address, leaves: Vec<TreasureLeaf>);
+    fn open_treasures(env: Env, room_id: u32, player: Address, leaves: Vec<TreasureLeaf>);
+    fn finalize_draw(env: Env, room_id: u32, caller: Address);
+    fn claim_timeout(env: Env, room_id: u32, claimant: Address);
+    fn forfeit(env: Env, room_id: u32, player: Address);
+    fn claim_abandoned(env: Env, room_id: u32, player: Address);
+    fn abort_room(env: Env, room_id: u32, player: Address);
+    fn retry_hub_report(env: Env, room_id: u32);
+    fn get_pending_hub_report(env: Env, room_id: u32) -> Option<PendingHubReport>;
 
     // ── Reveal phase ───────────────────────────────────────────────────
 
-    /// Reveal the OPPONENT's treasure to claim victory.
-    ///
-    /// The caller provides (island_id, tile_id, salt). The contract
-    /// rehashes and checks against the **opponent's** stored commitment.
-    /// If the hash matches, the caller wins.
-    pub fn reveal_treasure(
+    fn reveal_treasure(
         env: Env,
         room_id: u32,
         player: Address,
         island_id: u32,
         tile_id: u32,
         salt: BytesN<32>,
-    ) {
-        player.require_auth();
-
-        let key = DataKey::Room(room_id);
-        let mut room: Room = env
-            .storage()
-            .temporary()
-            .get(&key)
-            .unwrap_or_else(|| panic_with_error!(&env, Error::RoomNotFound));
-
-        if room.phase != 2 {
-            panic_with_error!(&env, Error::WrongPhase);
-        }
-        if !room.game_active {
-            panic_with_error!(&env, Error::GameEnded);
-        }
+    );
+    #[allow(clippy::too_many_arguments)]
+    fn reveal_treasure_proof(
+        env: Env,
+        room_id: u32,
+        player: Address,
+        island_id: u32,
+        tile_id: u32,
+        salt: BytesN<32>,
+        proof: Vec<BytesN<32>>,
+        leaves: Vec<TreasureLeaf>,
+    );
+    fn reveal_treasure_derived(
+        env: Env,
+        room_id: u32,
+        player: Address,
+        island_id: u32,
+        tile_id: u32,
+        salt_signature: BytesN<64>,
+    );
 
-        let is_a = player == room.player_a;
-        let is_b = player == room.player_b;
-        if !is_a && !is_b {
-            panic_with_error!(&env, Error::NotAPlayer);
-        }
+    // ── Post-game ──────────────────────────────────────────────────────
 
-        // Turn check — reveal counts as a turn action.
-        if (room.turn_is_a && !is_a) || (!room.turn_is_a && !is_b) {
-            panic_with_error!(&env, Error::NotYourTurn);
-        }
+    fn tip(env: Env, room_id: u32, from: Address, token: Address, amount: i128);
+    fn report_game(env: Env, room_id: u32, reporter: Address, reason: Symbol);
+
+    // ── Matchmaking ────────────────────────────────────────────────────
 
-        // Retrieve the OPPONENT's commitment.
-        let opponent_is_a = !is_a;
-        let commit_key = DataKey::Commitment(room_id, opponent_is_a);
-        let stored_commitment: BytesN<32> = env
-            .storage()
-            .temporary()
-            .get(&commit_key)
-            .unwrap_or_else(|| panic_with_error!(&env, Error::CommitmentMismatch));
+    fn enter_queue(env: Env, player: Address, points: i128) -> Option<u32>;
+    fn leave_queue(env: Env, player: Address, points: i128);
+    fn get_queue(env: Env, points: i128) -> Option<QueueEntry>;
+    fn challenge(env: Env, challenger: Address, opponent: Address, points: i128);
+    fn accept_challenge(
+        env: Env,
+        challenger: Address,
+        opponent: Address,
+        opponent_points: i128,
+    ) -> Room;
+    fn decline_challenge(env: Env, challenger: Address, opponent: Address);
+    fn get_challenge(env: Env, challenger: Address, opponent: Address) -> Option<i128>;
 
-        // Rebuild the hash:  SHA-256( room_id ‖ island_id ‖ tile_id ‖ salt )
-        let computed = Self::compute_commitment(&env, room_id, island_id, tile_id, &salt);
+    // ── Series ─────────────────────────────────────────────────────────
 
-        if computed != stored_commitment {
-            panic_with_error!(&env, Error::CommitmentMismatch);
-        }
+    fn create_series(
+        env: Env,
+        series_id: u32,
+        player_a: Address,
+        player_b: Address,
+        wins_needed: u32,
+    ) -> Series;
+    fn create_series_room(
+        env: Env,
+        series_id: u32,
+        room_id: u32,
+        player_a_points: i128,
+        player_b_points: i128,
+        config: RoomConfig,
+    ) -> Room;
+    fn get_series(env: Env, series_id: u32) -> Series;
 
-        // ── Winner decided ─────────────────────────────────────────────
-        let player1_won = is_a; // true if Player A wins
-
-        // Notify Game Hub BEFORE mutating local state.
-        let hub_addr: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .unwrap();
-        let hub = GameHubClient::new(&env, &hub_addr);
-        hub.end_game(&room_id, &player1_won);
-
-        room.winner = player.clone();
-        room.game_active = false;
-        room.phase = 3;
-
-        env.storage().temporary().set(&key, &room);
-        bump_temp(&env, &key);
-        bump_instance(&env);
-    }
+    // ── Betting ────────────────────────────────────────────────────────
 
-    // ── Read-only helpers ──────────────────────────────────────────────
+    fn place_bet(env: Env, room_id: u32, bettor: Address, on_player_a: bool, amount: i128);
+    fn claim_bet(env: Env, room_id: u32, bettor: Address) -> i128;
+    fn get_bet_book(env: Env, room_id: u32) -> BetBook;
 
-    /// Read room state (returns the Room struct or panics).
-    pub fn get_room(env: Env, room_id: u32) -> Room {
-        let key = DataKey::Room(room_id);
-        env.storage()
-            .temporary()
-            .get(&key)
-            .unwrap_or_else(|| panic_with_error!(&env, Error::RoomNotFound))
-    }
+    // ── Observers ──────────────────────────────────────────────────────
 
-    /// Alias used by the frontend service layer.
-    pub fn get_game(env: Env, room_id: u32) -> Room {
-        Self::get_room(env, room_id)
-    }
+    fn watch_room(env: Env, room_id: u32, observer: Address);
+    fn unwatch_room(env: Env, room_id: u32, observer: Address);
+    fn get_observers(env: Env, room_id: u32) -> Vec<Address>;
 
-    // ── Internal ───────────────────────────────────────────────────────
+    // ── Read-only helpers ──────────────────────────────────────────────
 
-    /// Compute SHA-256(room_id ‖ island_id ‖ tile_id ‖ salt).
-    fn compute_commitment(
-        env: &Env,
+    fn get_room(env: Env, room_id: u32) -> Room;
+    fn get_game(env: Env, room_id: u32) -> Room;
+    fn get_room_v1(env: Env, room_id: u32) -> RoomV1;
+    fn get_tips(env: Env, room_id: u32, cursor: Cursor) -> TipPage;
+    fn get_burial_ledgers(env: Env, room_id: u32) -> BurialLedgers;
+    fn get_commitments(env: Env, room_id: u32) -> PublishedCommitments;
+    fn get_reports(env: Env, room_id: u32, cursor: Cursor) -> ReportPage;
+    fn get_digs(env: Env, room_id: u32, cursor: Cursor) -> DigPage;
+    fn get_recent_events(env: Env, room_id: u32) -> Vec<RoomEvent>;
+    fn get_open_rooms(env: Env, cursor: Cursor) -> RoomPage;
+    fn get_rooms_by_player(env: Env, player: Address, cursor: Cursor) -> RoomIdPage;
+    fn verify_commitment(
+        env: Env,
         room_id: u32,
+        owner: Address,
         island_id: u32,
         tile_id: u32,
-        salt: &BytesN<32>,
-    ) -> BytesN<32> {
-        use soroban_sdk::Bytes;
-
-        let mut buf = Bytes::new(env);
-        buf.extend_from_array(&room_id.to_be_bytes());
-        buf.extend_from_array(&island_id.to_be_bytes());
-        buf.extend_from_array(&tile_id.to_be_bytes());
-        buf.extend_from_slice(&salt.to_array());
-
-        let hash: Hash<32> = env.crypto().sha256(&buf);
-        BytesN::from_array(env, &hash.to_array())
-    }
+        salt: BytesN<32>,
+    ) -> bool;
+    fn export_attestation(env: Env, room_id: u32) -> Bytes;
+    fn get_state_hash(env: Env, room_id: u32) -> BytesN<32>;
+    fn get_rules_hash(env: Env, room_id: u32) -> BytesN<32>;
+    fn get_salt_message(env: Env, room_id: u32) -> Bytes;
+    fn get_full_view(env: Env, room_id: u32) -> FullView;
+    fn get_reputation(env: Env, player: Address) -> Reputation;
+    fn get_rating(env: Env, player: Address) -> u32;
+    fn get_player_stats(env: Env, player: Address) -> PlayerStats;
+    fn get_storage_report(env: Env, room_id: u32) -> StorageReport;
+    fn audit_room(env: Env, room_id: u32) -> Vec<Symbol>;
 
     // ── Upgrade (admin only) ───────────────────────────────────────────
 
-    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        admin.require_auth();
-        env.deployer().update_current_contract_wasm(new_wasm_hash);
-    }
+    fn upgrade(env: Env, new_wasm_hash: BytesN<32>);
+    fn migrate(env: Env, room_ids: Vec<u32>);
+    fn preview_migration(env: Env, room_ids: Vec<u32>) -> MigrationPreview;
+    fn get_version(env: Env) -> u32;
 }
 
+#[cfg(not(feature = "interface"))]
+mod contract;
+#[cfg(not(feature = "interface"))]
+pub use contract::{PiratesTreasure, PiratesTreasureClient};
+
 #[cfg(test)]
 mod test;
@@ -1,9 +1,12 @@
 #![cfg(test)]
 
 use super::*;
+use crate::contract::{DEFAULT_RECENT_EVENTS, DEFAULT_REPORTS_PER_REPORTER};
 use soroban_sdk::{
-    testutils::Address as _,
-    Address, BytesN, Env,
+    crypto::bls12_381::{Fr, G1Affine},
+    testutils::{Address as _, Ledger},
+    token::{StellarAssetClient, TokenClient},
+    contract, contractimpl, symbol_short, Address, BytesN, Env, U256,
 };
 
 // ---------------------------------------------------------------------------
@@ -15,8 +18,8 @@ fn setup_env() -> (Env, Address, Address, Address, Address) {
     env.mock_all_auths();
 
     let admin = Address::generate(&env);
-    let hub_id = env.register(mock_game_hub::WASM, ());
-    let game_id = env.register(PiratesTreasure, (&admin, &hub_id));
+    let hub_id = env.register(MockGameHub, ());
+    let game_id = env.register(PiratesTreasure, (&admin, Some(hub_id.clone())));
 
     let player_a = Address::generate(&env);
     let player_b = Address::generate(&env);
@@ -31,15 +34,20 @@ fn make_salt(env: &Env, seed: u8) -> BytesN<32> {
 }
 
 fn make_commitment(
-    env: &Env,
+    client: &PiratesTreasureClient,
     room_id: u32,
     island_id: u32,
     tile_id: u32,
     salt: &BytesN<32>,
 ) -> BytesN<32> {
     use soroban_sdk::Bytes;
+    let env = &client.env;
+    let nonce = client.get_room(&room_id).nonce;
+    let rules = client.get_rules_hash(&room_id);
     let mut buf = Bytes::new(env);
     buf.extend_from_array(&room_id.to_be_bytes());
+    buf.extend_from_slice(&nonce.to_array());
+    buf.extend_from_slice(&rules.to_array());
     buf.extend_from_array(&island_id.to_be_bytes());
     buf.extend_from_array(&tile_id.to_be_bytes());
     buf.extend_from_slice(&salt.to_array());
@@ -47,11 +55,92 @@ fn make_commitment(
     BytesN::from_array(env, &hash.to_array())
 }
 
-// Import the mock-game-hub WASM for test registration.
-mod mock_game_hub {
-    soroban_sdk::contractimport!(
-        file = "../../target/wasm32v1-none/release/mock_game_hub.wasm"
-    );
+fn make_leaf(env: &Env, island_id: u32, tile_id: u32, seed: u8) -> TreasureLeaf {
+    TreasureLeaf { island_id, tile_id, salt: make_salt(env, seed) }
+}
+
+// ---------------------------------------------------------------------------
+// Mock game hub: records every call so tests can check what was reported
+// ---------------------------------------------------------------------------
+
+/// One call a game made into [`MockGameHub`].
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum HubCall {
+    Started(u32),
+    Ended(u32, bool),
+    Drawn(u32),
+    Aborted(u32),
+    EndedSession(Address, u32, bool),
+    DrawnSession(Address, u32),
+    AbortedSession(Address, u32),
+}
+
+#[contract]
+pub struct MockGameHub;
+
+#[contractimpl]
+impl MockGameHub {
+    pub fn start_game(
+        env: Env,
+        _game_id: Address,
+        session_id: u32,
+        _player1: Address,
+        _player2: Address,
+        _player1_points: i128,
+        _player2_points: i128,
+    ) {
+        Self::record(&env, HubCall::Started(session_id));
+    }
+
+    pub fn end_game(env: Env, session_id: u32, player1_won: bool) {
+        Self::record(&env, HubCall::Ended(session_id, player1_won));
+    }
+
+    pub fn draw_game(env: Env, session_id: u32) {
+        Self::record(&env, HubCall::Drawn(session_id));
+    }
+
+    pub fn abort_game(env: Env, session_id: u32) {
+        Self::record(&env, HubCall::Aborted(session_id));
+    }
+
+    pub fn end_session(env: Env, game_id: Address, session_id: u32, player1_won: bool) {
+        Self::record(&env, HubCall::EndedSession(game_id, session_id, player1_won));
+    }
+
+    pub fn draw_session(env: Env, game_id: Address, session_id: u32) {
+        Self::record(&env, HubCall::DrawnSession(game_id, session_id));
+    }
+
+    pub fn abort_session(env: Env, game_id: Address, session_id: u32) {
+        Self::record(&env, HubCall::AbortedSession(game_id, session_id));
+    }
+
+    /// Escrow token for every game, as set by `set_game_token`.
+    pub fn get_game_token(env: Env, _game: Address) -> Option<Address> {
+        env.storage().instance().get(&symbol_short!("token"))
+    }
+
+    pub fn set_game_token(env: Env, token: Address) {
+        env.storage().instance().set(&symbol_short!("token"), &token);
+    }
+
+    /// Every call received so far, oldest first.
+    pub fn calls(env: Env) -> Vec<HubCall> {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("calls"))
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+}
+
+impl MockGameHub {
+    fn record(env: &Env, call: HubCall) {
+        let mut calls = Self::calls(env.clone());
+        calls.push_back(call);
+        env.storage().instance().set(&symbol_short!("calls"), &calls);
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -138,7 +227,7 @@ fn test_bury_treasure() {
     client.start_room(&1u32, &player_a, &player_b, &100_i128, &100_i128);
 
     let salt_a = make_salt(&env, 1);
-    let commit_a = make_commitment(&env, 1, 0, 5, &salt_a);
+    let commit_a = make_commitment(&client, 1, 0, 5, &salt_a);
     client.bury_treasure(&1u32, &player_a, &commit_a);
     let room = client.get_room(&1u32);
     assert!(room.has_commitment_a);
@@ -146,7 +235,7 @@ fn test_bury_treasure() {
     assert_eq!(room.phase, 1); // still burying
 
     let salt_b = make_salt(&env, 2);
-    let commit_b = make_commitment(&env, 1, 2, 15, &salt_b);
+    let commit_b = make_commitment(&client, 1, 2, 15, &salt_b);
     client.bury_treasure(&1u32, &player_b, &commit_b);
     let room = client.get_room(&1u32);
     assert!(room.has_commitment_a);
@@ -165,7 +254,7 @@ fn test_double_bury() {
     client.start_room(&1u32, &player_a, &player_b, &100_i128, &100_i128);
 
     let salt = make_salt(&env, 1);
-    let commit = make_commitment(&env, 1, 0, 5, &salt);
+    let commit = make_commitment(&client, 1, 0, 5, &salt);
     client.bury_treasure(&1u32, &player_a, &commit);
     client.bury_treasure(&1u32, &player_a, &commit); // AlreadyBuried
 }
@@ -181,11 +270,11 @@ fn test_dig_alternating_turns() {
 
     // Bury for both
     let salt_a = make_salt(&env, 1);
-    let commit_a = make_commitment(&env, 1, 0, 5, &salt_a);
+    let commit_a = make_commitment(&client, 1, 0, 5, &salt_a);
     client.bury_treasure(&1u32, &player_a, &commit_a);
 
     let salt_b = make_salt(&env, 2);
-    let commit_b = make_commitment(&env, 1, 2, 15, &salt_b);
+    let commit_b = make_commitment(&client, 1, 2, 15, &salt_b);
     client.bury_treasure(&1u32, &player_b, &commit_b);
 
     // Phase 2 — Player A digs first
@@ -213,10 +302,10 @@ fn test_dig_wrong_turn() {
     client.start_room(&1u32, &player_a, &player_b, &100_i128, &100_i128);
 
     let salt_a = make_salt(&env, 1);
-    let commit_a = make_commitment(&env, 1, 0, 5, &salt_a);
+    let commit_a = make_commitment(&client, 1, 0, 5, &salt_a);
     client.bury_treasure(&1u32, &player_a, &commit_a);
     let salt_b = make_salt(&env, 2);
-    let commit_b = make_commitment(&env, 1, 2, 15, &salt_b);
+    let commit_b = make_commitment(&client, 1, 2, 15, &salt_b);
     client.bury_treasure(&1u32, &player_b, &commit_b);
 
     // Player B tries to dig first — not their turn
@@ -234,10 +323,10 @@ fn test_dig_duplicate_tile() {
     client.start_room(&1u32, &player_a, &player_b, &100_i128, &100_i128);
 
     let salt_a = make_salt(&env, 1);
-    let commit_a = make_commitment(&env, 1, 0, 5, &salt_a);
+    let commit_a = make_commitment(&client, 1, 0, 5, &salt_a);
     client.bury_treasure(&1u32, &player_a, &commit_a);
     let salt_b = make_salt(&env, 2);
-    let commit_b = make_commitment(&env, 1, 2, 15, &salt_b);
+    let commit_b = make_commitment(&client, 1, 2, 15, &salt_b);
     client.bury_treasure(&1u32, &player_b, &commit_b);
 
     client.dig(&1u32, &player_a, &0u32, &0u32);
@@ -255,10 +344,10 @@ fn test_dig_invalid_island() {
     client.start_room(&1u32, &player_a, &player_b, &100_i128, &100_i128);
 
     let salt_a = make_salt(&env, 1);
-    let commit_a = make_commitment(&env, 1, 0, 5, &salt_a);
+    let commit_a = make_commitment(&client, 1, 0, 5, &salt_a);
     client.bury_treasure(&1u32, &player_a, &commit_a);
     let salt_b = make_salt(&env, 2);
-    let commit_b = make_commitment(&env, 1, 2, 15, &salt_b);
+    let commit_b = make_commitment(&client, 1, 2, 15, &salt_b);
     client.bury_treasure(&1u32, &player_b, &commit_b);
 
     client.dig(&1u32, &player_a, &5u32, &0u32); // InvalidIsland
@@ -275,10 +364,10 @@ fn test_dig_invalid_tile() {
     client.start_room(&1u32, &player_a, &player_b, &100_i128, &100_i128);
 
     let salt_a = make_salt(&env, 1);
-    let commit_a = make_commitment(&env, 1, 0, 5, &salt_a);
+    let commit_a = make_commitment(&client, 1, 0, 5, &salt_a);
     client.bury_treasure(&1u32, &player_a, &commit_a);
     let salt_b = make_salt(&env, 2);
-    let commit_b = make_commitment(&env, 1, 2, 15, &salt_b);
+    let commit_b = make_commitment(&client, 1, 2, 15, &salt_b);
     client.bury_treasure(&1u32, &player_b, &commit_b);
 
     client.dig(&1u32, &player_a, &0u32, &99u32); // InvalidTile (island 0 has 10 tiles)
@@ -295,12 +384,12 @@ fn test_reveal_treasure_correct() {
 
     // A buries at island=0, tile=5
     let salt_a = make_salt(&env, 1);
-    let commit_a = make_commitment(&env, 1, 0, 5, &salt_a);
+    let commit_a = make_commitment(&client, 1, 0, 5, &salt_a);
     client.bury_treasure(&1u32, &player_a, &commit_a);
 
     // B buries at island=2, tile=15
     let salt_b = make_salt(&env, 2);
-    let commit_b = make_commitment(&env, 1, 2, 15, &salt_b);
+    let commit_b = make_commitment(&client, 1, 2, 15, &salt_b);
     client.bury_treasure(&1u32, &player_b, &commit_b);
 
     // Player A reveals Player B's treasure (correctly)
@@ -323,11 +412,11 @@ fn test_reveal_treasure_wrong() {
     client.start_room(&1u32, &player_a, &player_b, &100_i128, &100_i128);
 
     let salt_a = make_salt(&env, 1);
-    let commit_a = make_commitment(&env, 1, 0, 5, &salt_a);
+    let commit_a = make_commitment(&client, 1, 0, 5, &salt_a);
     client.bury_treasure(&1u32, &player_a, &commit_a);
 
     let salt_b = make_salt(&env, 2);
-    let commit_b = make_commitment(&env, 1, 2, 15, &salt_b);
+    let commit_b = make_commitment(&client, 1, 2, 15, &salt_b);
     client.bury_treasure(&1u32, &player_b, &commit_b);
 
     // Wrong island — commitment mismatch
@@ -344,11 +433,11 @@ fn test_player_b_wins() {
     client.start_room(&1u32, &player_a, &player_b, &100_i128, &100_i128);
 
     let salt_a = make_salt(&env, 1);
-    let commit_a = make_commitment(&env, 1, 0, 5, &salt_a);
+    let commit_a = make_commitment(&client, 1, 0, 5, &salt_a);
     client.bury_treasure(&1u32, &player_a, &commit_a);
 
     let salt_b = make_salt(&env, 2);
-    let commit_b = make_commitment(&env, 1, 2, 15, &salt_b);
+    let commit_b = make_commitment(&client, 1, 2, 15, &salt_b);
     client.bury_treasure(&1u32, &player_b, &commit_b);
 
     // A digs (wastes turn)
@@ -391,15 +480,3113 @@ fn test_admin_functions() {
     let (env, game_id, _player_a, _player_b, hub_id) = setup_env();
     let client = PiratesTreasureClient::new(&env, &game_id);
 
-    let _admin = client.get_admin();
-    let hub = client.get_hub();
-    assert_eq!(hub, hub_id);
+    let admin = client.get_admin();
+    assert_eq!(client.get_hub(), Some(hub_id));
 
+    let res = client.try_accept_admin();
+    assert_eq!(res, Err(Ok(Error::NoPendingAdmin.into())));
     let new_admin = Address::generate(&env);
-    client.set_admin(&new_admin);
+    client.propose_admin(&new_admin);
+    assert_eq!(client.get_admin(), admin);
+    assert_eq!(client.get_pending_admin(), Some(new_admin.clone()));
+    client.accept_admin();
     assert_eq!(client.get_admin(), new_admin);
+    assert_eq!(client.get_pending_admin(), None);
 
     let new_hub = Address::generate(&env);
     client.set_hub(&new_hub);
-    assert_eq!(client.get_hub(), new_hub);
+    assert_eq!(client.get_hub(), Some(new_hub));
+}
+
+#[test]
+fn test_recent_events_outbox() {
+    let (env, game_id, player_a, player_b, _hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+
+    assert_eq!(client.get_recent_events(&1u32).len(), 0);
+
+    client.create_room(&1u32, &player_a, &100_i128);
+    client.join_room(&1u32, &player_b, &100_i128);
+
+    let events = client.get_recent_events(&1u32);
+    assert_eq!(events.len(), 2);
+    assert_eq!(events.get(0).unwrap().kind, symbol_short!("created"));
+    assert_eq!(events.get(0).unwrap().actor, player_a);
+    assert_eq!(events.get(1).unwrap().kind, symbol_short!("joined"));
+    assert_eq!(events.get(1).unwrap().actor, player_b);
+}
+
+#[test]
+fn test_recent_events_ring_buffer_is_bounded() {
+    let (env, game_id, player_a, player_b, _hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+
+    client.create_room(&1u32, &player_a, &100_i128);
+    client.join_room(&1u32, &player_b, &100_i128);
+    client.start_room(&1u32, &player_a, &player_b, &100_i128, &100_i128);
+
+    let salt_a = make_salt(&env, 1);
+    let commit_a = make_commitment(&client, 1, 0, 5, &salt_a);
+    client.bury_treasure(&1u32, &player_a, &commit_a);
+    let salt_b = make_salt(&env, 2);
+    let commit_b = make_commitment(&client, 1, 2, 15, &salt_b);
+    client.bury_treasure(&1u32, &player_b, &commit_b);
+
+    // 5 events so far; 12 digs push the total to 17.
+    for tile in 0..6u32 {
+        client.dig(&1u32, &player_a, &1u32, &tile);
+        client.dig(&1u32, &player_b, &1u32, &(tile + 10));
+    }
+
+    let events = client.get_recent_events(&1u32);
+    assert_eq!(events.len(), DEFAULT_RECENT_EVENTS);
+    // The oldest "created" entry has been evicted.
+    assert_eq!(events.get(0).unwrap().kind, symbol_short!("joined"));
+    assert_eq!(events.last().unwrap().kind, symbol_short!("dug"));
+}
+
+#[test]
+fn test_export_attestation() {
+    use soroban_sdk::xdr::FromXdr;
+
+    let (env, game_id, player_a, player_b, _hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+
+    client.create_room(&1u32, &player_a, &100_i128);
+    client.join_room(&1u32, &player_b, &200_i128);
+    client.start_room(&1u32, &player_a, &player_b, &100_i128, &200_i128);
+
+    let salt_a = make_salt(&env, 1);
+    let commit_a = make_commitment(&client, 1, 0, 5, &salt_a);
+    client.bury_treasure(&1u32, &player_a, &commit_a);
+    let salt_b = make_salt(&env, 2);
+    let commit_b = make_commitment(&client, 1, 2, 15, &salt_b);
+    client.bury_treasure(&1u32, &player_b, &commit_b);
+
+    client.dig(&1u32, &player_a, &1u32, &0u32);
+    client.reveal_treasure(&1u32, &player_b, &0u32, &5u32, &salt_a);
+
+    let encoded = client.export_attestation(&1u32);
+    let attestation = Attestation::from_xdr(&env, &encoded).unwrap();
+    assert_eq!(attestation.contract, game_id);
+    assert_eq!(attestation.room_id, 1);
+    assert_eq!(attestation.winner, player_b);
+    assert_eq!(attestation.phase, 3);
+    assert_eq!(attestation.player_b_points, 200);
+
+    // Deterministic: exporting twice yields identical bytes.
+    assert_eq!(client.export_attestation(&1u32), encoded);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #31)")]
+fn test_export_attestation_before_end() {
+    let (env, game_id, player_a, player_b, _hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+
+    client.create_room(&1u32, &player_a, &100_i128);
+    client.join_room(&1u32, &player_b, &100_i128);
+    client.export_attestation(&1u32); // WrongPhaseExpectedEnded
+}
+
+#[test]
+fn test_state_hash_tracks_room_changes() {
+    let (env, game_id, player_a, player_b, _hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+
+    client.create_room(&1u32, &player_a, &100_i128);
+    client.join_room(&1u32, &player_b, &100_i128);
+    client.start_room(&1u32, &player_a, &player_b, &100_i128, &100_i128);
+
+    let h0 = client.get_state_hash(&1u32);
+    assert_eq!(client.get_state_hash(&1u32), h0);
+
+    let salt_a = make_salt(&env, 1);
+    let commit_a = make_commitment(&client, 1, 0, 5, &salt_a);
+    client.bury_treasure(&1u32, &player_a, &commit_a);
+    let h1 = client.get_state_hash(&1u32);
+    assert_ne!(h1, h0);
+
+    let salt_b = make_salt(&env, 2);
+    let commit_b = make_commitment(&client, 1, 2, 15, &salt_b);
+    client.bury_treasure(&1u32, &player_b, &commit_b);
+    client.dig(&1u32, &player_a, &0u32, &0u32);
+    assert_ne!(client.get_state_hash(&1u32), h1);
+}
+
+#[test]
+fn test_poseidon2_permutation_known_answer() {
+    let env = Env::default();
+    let state = soroban_sdk::Vec::from_array(
+        &env,
+        [
+            U256::from_u32(&env, 0),
+            U256::from_u32(&env, 1),
+            U256::from_u32(&env, 2),
+        ],
+    );
+    let out = poseidon::permute(&env, &state);
+    assert_eq!(
+        out.get(0).unwrap(),
+        U256::from_parts(
+            &env,
+            0x0bb61d24daca55ee,
+            0xbcb1929a82650f32,
+            0x8134334da98ea4f8,
+            0x47f760054f4a3033,
+        )
+    );
+}
+
+#[test]
+fn test_poseidon2_commitment_known_answer() {
+    // Same vector as test_commitment_known_answer in zk/treasure/src/main.nr.
+    let env = Env::default();
+    let inputs = soroban_sdk::Vec::from_array(
+        &env,
+        [
+            U256::from_u32(&env, 42),
+            U256::from_u32(&env, 1),
+            U256::from_u32(&env, 15),
+            U256::from_parts(&env, 0, 0, 0, 0x1234567890abcdef),
+            U256::from_parts(&env, 0, 0, 0, 0xdeadbeefcafe1234),
+            U256::from_u32(&env, 7),
+            U256::from_u32(&env, 0x99),
+        ],
+    );
+    assert_eq!(
+        poseidon::hash(&env, &inputs),
+        U256::from_parts(
+            &env,
+            0x1b09849d3688ecfe,
+            0x9e2d1981cee5288d,
+            0x8fa2c062df635161,
+            0x4035d829244723b9,
+        )
+    );
+}
+
+#[test]
+fn test_poseidon2_room_reveal() {
+    let (env, game_id, player_a, player_b, _hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+
+    let config = RoomConfig {
+        commitment_scheme: CommitmentScheme::Poseidon2,
+        dig_mode: DigMode::Alternating,
+        roles: Roles::Duel,
+        dig_budget: 0,
+        hider_odds_bps: 0,
+        turn_timeout_ledgers: 0,
+        bury_timeout_ledgers: 0,
+        reveal_window_ledgers: 0,
+        action_points: 0,
+        min_completed: 0,
+        min_score_bps: 0,
+        event_verbosity: EventVerbosity::Default,
+        signed_burials: false,
+        derived_salts: false,
+        stake_token: None,
+        treasures: 0,
+        guess_mode: false,
+        invite_hash: None,
+        hub: None,
+        hubless: false,
+    };
+    client.create_room_with_config(&1u32, &player_a, &100_i128, &config);
+    client.join_room(&1u32, &player_b, &100_i128);
+    client.start_room(&1u32, &player_a, &player_b, &100_i128, &100_i128);
+
+    let room = client.get_room(&1u32);
+    let salt_a = make_salt(&env, 1);
+    let commit_a = PiratesTreasure::poseidon2_commitment(&env, &room, &player_a, 0, 5, &salt_a);
+    client.bury_treasure(&1u32, &player_a, &commit_a);
+    let salt_b = make_salt(&env, 2);
+    let commit_b = PiratesTreasure::poseidon2_commitment(&env, &room, &player_b, 2, 15, &salt_b);
+    client.bury_treasure(&1u32, &player_b, &commit_b);
+
+    // The SHA-256 pre-image does not satisfy a Poseidon2 commitment.
+    assert_ne!(commit_b, make_commitment(&client, 1, 2, 15, &salt_b));
+    assert!(client.verify_commitment(&1u32, &player_b, &2u32, &15u32, &salt_b));
+    assert!(!client.verify_commitment(&1u32, &player_b, &2u32, &14u32, &salt_b));
+    // Commitments are bound to their owner.
+    assert!(!client.verify_commitment(&1u32, &player_a, &2u32, &15u32, &salt_b));
+
+    client.reveal_treasure(&1u32, &player_a, &2u32, &15u32, &salt_b);
+    assert_eq!(client.get_room(&1u32).winner, player_a);
+}
+
+fn bls_keypair(env: &Env, secret: u32) -> (Fr, BytesN<96>, BytesN<192>) {
+    let bls = env.crypto().bls12_381();
+    let sk = Fr::from_u256(U256::from_u32(env, secret));
+    let generator = G1Affine::from_bytes(soroban_sdk::bytesn!(
+        env,
+        0x17f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb08b3f481e3aaa0f1a09e30ed741d8ae4fcf5e095d5d00af600db18cb2c04b3edd03cc744a2888ae40caa232946c5e7e1
+    ));
+    let pk = bls.g1_mul(&generator, &sk).to_bytes();
+    let pop = bls.g2_mul(&bls::hash_pop(env, &pk), &sk).to_bytes();
+    (sk, pk, pop)
+}
+
+#[test]
+fn test_start_room_aggregated() {
+    let (env, game_id, player_a, player_b, _hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+    let bls = env.crypto().bls12_381();
+
+    let (sk_a, pk_a, pop_a) = bls_keypair(&env, 1234);
+    let (sk_b, pk_b, pop_b) = bls_keypair(&env, 5678);
+    client.register_bls_key(&player_a, &pk_a, &pop_a);
+    client.register_bls_key(&player_b, &pk_b, &pop_b);
+
+    client.create_room(&1u32, &player_a, &100_i128);
+    client.join_room(&1u32, &player_b, &200_i128);
+
+    let msg = client.get_start_message(&1u32, &100_i128, &200_i128);
+    let h = bls::hash_message(&env, &msg.into());
+    let sig = bls.g2_add(&bls.g2_mul(&h, &sk_a), &bls.g2_mul(&h, &sk_b));
+
+    let room = client.start_room_aggregated(&1u32, &100_i128, &200_i128, &sig.to_bytes());
+    assert_eq!(room.phase, 1);
+    assert_eq!(room.player_b_points, 200);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #16)")]
+fn test_start_room_aggregated_requires_both_signers() {
+    let (env, game_id, player_a, player_b, _hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+    let bls = env.crypto().bls12_381();
+
+    let (sk_a, pk_a, pop_a) = bls_keypair(&env, 1234);
+    let (_sk_b, pk_b, pop_b) = bls_keypair(&env, 5678);
+    client.register_bls_key(&player_a, &pk_a, &pop_a);
+    client.register_bls_key(&player_b, &pk_b, &pop_b);
+
+    client.create_room(&1u32, &player_a, &100_i128);
+    client.join_room(&1u32, &player_b, &100_i128);
+
+    // Only Player A signed.
+    let msg = client.get_start_message(&1u32, &100_i128, &100_i128);
+    let sig = bls.g2_mul(&bls::hash_message(&env, &msg.into()), &sk_a);
+    client.start_room_aggregated(&1u32, &100_i128, &100_i128, &sig.to_bytes()); // BadSignature
+}
+
+#[test]
+fn test_start_signature_is_bound_to_one_room() {
+    let (env, game_id, player_a, player_b, _hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+    let bls = env.crypto().bls12_381();
+
+    let (sk_a, pk_a, pop_a) = bls_keypair(&env, 1234);
+    let (sk_b, pk_b, pop_b) = bls_keypair(&env, 5678);
+    client.register_bls_key(&player_a, &pk_a, &pop_a);
+    client.register_bls_key(&player_b, &pk_b, &pop_b);
+
+    client.create_room(&1u32, &player_a, &100_i128);
+    client.join_room(&1u32, &player_b, &100_i128);
+    let msg = client.get_start_message(&1u32, &100_i128, &100_i128);
+    let h = bls::hash_message(&env, &msg.into());
+    let sig = bls.g2_add(&bls.g2_mul(&h, &sk_a), &bls.g2_mul(&h, &sk_b));
+
+    // The same pair re-creates the room under the same id; the old
+    // signature no longer starts it.
+    client.cancel_room(&1u32, &player_a);
+    client.create_room(&1u32, &player_a, &100_i128);
+    client.join_room(&1u32, &player_b, &100_i128);
+    let res = client.try_start_room_aggregated(&1u32, &100_i128, &100_i128, &sig.to_bytes());
+    assert_eq!(res, Err(Ok(Error::BadSignature.into())));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #16)")]
+fn test_register_bls_key_rejects_bad_pop() {
+    let (env, game_id, player_a, _player_b, _hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+
+    let (_sk_a, pk_a, _pop_a) = bls_keypair(&env, 1234);
+    let (_sk_b, _pk_b, pop_b) = bls_keypair(&env, 5678);
+    client.register_bls_key(&player_a, &pk_a, &pop_b); // BadSignature
+}
+
+#[test]
+fn test_tip_opponent_after_game() {
+    let (env, game_id, player_a, player_b, _hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+
+    let token_admin = Address::generate(&env);
+    let token_id = env.register_stellar_asset_contract_v2(token_admin).address();
+    StellarAssetClient::new(&env, &token_id).mint(&player_b, &1_000);
+
+    client.create_room(&1u32, &player_a, &100_i128);
+    client.join_room(&1u32, &player_b, &100_i128);
+    client.start_room(&1u32, &player_a, &player_b, &100_i128, &100_i128);
+
+    let salt_a = make_salt(&env, 1);
+    let commit_a = make_commitment(&client, 1, 0, 5, &salt_a);
+    client.bury_treasure(&1u32, &player_a, &commit_a);
+    let salt_b = make_salt(&env, 2);
+    let commit_b = make_commitment(&client, 1, 2, 15, &salt_b);
+    client.bury_treasure(&1u32, &player_b, &commit_b);
+    client.reveal_treasure(&1u32, &player_a, &2u32, &15u32, &salt_b);
+
+    // Good game: the loser tips the winner.
+    client.tip(&1u32, &player_b, &token_id, &250_i128);
+
+    let token = TokenClient::new(&env, &token_id);
+    assert_eq!(token.balance(&player_a), 250);
+    assert_eq!(token.balance(&player_b), 750);
+
+    let tips = client.get_tips(&1u32, &Cursor { start: 0, limit: 0 }).items;
+    assert_eq!(tips.len(), 1);
+    assert_eq!(tips.get(0).unwrap().to, player_a);
+    assert_eq!(client.get_recent_events(&1u32).last().unwrap().kind, symbol_short!("tipped"));
+}
+
+#[test]
+fn test_escrowed_stakes() {
+    let (env, game_id, player_a, player_b, _hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+
+    let token_admin = Address::generate(&env);
+    let token_id = env.register_stellar_asset_contract_v2(token_admin).address();
+    let sac = StellarAssetClient::new(&env, &token_id);
+    sac.mint(&player_a, &1_000);
+    sac.mint(&player_b, &1_000);
+    let token = TokenClient::new(&env, &token_id);
+
+    let config = RoomConfig {
+        commitment_scheme: CommitmentScheme::Sha256,
+        dig_mode: DigMode::Alternating,
+        roles: Roles::Duel,
+        dig_budget: 0,
+        hider_odds_bps: 0,
+        turn_timeout_ledgers: 0,
+        bury_timeout_ledgers: 0,
+        reveal_window_ledgers: 0,
+        action_points: 0,
+        min_completed: 0,
+        min_score_bps: 0,
+        event_verbosity: EventVerbosity::Default,
+        signed_burials: false,
+        derived_salts: false,
+        stake_token: Some(token_id.clone()),
+        treasures: 0,
+        guess_mode: false,
+        invite_hash: None,
+        hub: None,
+        hubless: false,
+    };
+    client.create_room_with_config(&1u32, &player_a, &100_i128, &config);
+    client.join_room(&1u32, &player_b, &300_i128);
+    assert_eq!(token.balance(&game_id), 400);
+
+    let res = client.try_start_room(&1u32, &player_a, &player_b, &100_i128, &100_i128);
+    assert_eq!(res, Err(Ok(Error::StakeMismatch.into())));
+    client.start_room(&1u32, &player_a, &player_b, &100_i128, &300_i128);
+
+    let salt_a = make_salt(&env, 1);
+    client.bury_treasure(&1u32, &player_a, &make_commitment(&client, 1, 0, 5, &salt_a));
+    let salt_b = make_salt(&env, 2);
+    client.bury_treasure(&1u32, &player_b, &make_commitment(&client, 1, 2, 15, &salt_b));
+    client.reveal_treasure(&1u32, &player_a, &2u32, &15u32, &salt_b);
+
+    // The winner takes the whole pot.
+    assert_eq!(token.balance(&game_id), 0);
+    assert_eq!(token.balance(&player_a), 1_300);
+    assert_eq!(token.balance(&player_b), 700);
+}
+
+#[test]
+fn test_protocol_fee() {
+    use crate::contract::MAX_FEE_BPS;
+
+    let (env, game_id, player_a, player_b, _hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+
+    let token_admin = Address::generate(&env);
+    let token_id = env.register_stellar_asset_contract_v2(token_admin).address();
+    let sac = StellarAssetClient::new(&env, &token_id);
+    sac.mint(&player_a, &1_000);
+    sac.mint(&player_b, &1_000);
+    let token = TokenClient::new(&env, &token_id);
+
+    let res = client.try_set_fee_bps(&(MAX_FEE_BPS + 1));
+    assert_eq!(res, Err(Ok(Error::FeeTooHigh.into())));
+    client.set_fee_bps(&250u32);
+    assert_eq!(client.get_fee_bps(), 250);
+
+    let config = RoomConfig {
+        commitment_scheme: CommitmentScheme::Sha256,
+        dig_mode: DigMode::Alternating,
+        roles: Roles::Duel,
+        dig_budget: 0,
+        hider_odds_bps: 0,
+        turn_timeout_ledgers: 0,
+        bury_timeout_ledgers: 0,
+        reveal_window_ledgers: 0,
+        action_points: 0,
+        min_completed: 0,
+        min_score_bps: 0,
+        event_verbosity: EventVerbosity::Default,
+        signed_burials: false,
+        derived_salts: false,
+        stake_token: Some(token_id.clone()),
+        treasures: 0,
+        guess_mode: false,
+        invite_hash: None,
+        hub: None,
+        hubless: false,
+    };
+    client.create_room_with_config(&1u32, &player_a, &100_i128, &config);
+    client.join_room(&1u32, &player_b, &300_i128);
+    client.start_room(&1u32, &player_a, &player_b, &100_i128, &300_i128);
+    client.forfeit(&1u32, &player_b);
+
+    // 2.5% of the 400 pot stays behind as the fee.
+    assert_eq!(token.balance(&player_a), 1_290);
+    assert_eq!(client.get_fees(&token_id), 10);
+    assert_eq!(token.balance(&game_id), 10);
+
+    let treasury = Address::generate(&env);
+    client.set_treasury(&Some(treasury.clone()));
+    assert_eq!(client.get_treasury(), Some(treasury.clone()));
+    client.withdraw_fees(&treasury);
+    assert_eq!(token.balance(&treasury), 10);
+    assert_eq!(client.get_fees(&token_id), 0);
+
+    // Both settings travel with the rest of the config.
+    let config = client.get_config();
+    assert_eq!(config.fee_bps, 250);
+    assert_eq!(config.treasury, Some(treasury));
+    let res = client.try_set_config(&Config {
+        fee_bps: MAX_FEE_BPS + 1,
+        ..config.clone()
+    });
+    assert_eq!(res, Err(Ok(Error::FeeTooHigh.into())));
+    client.set_config(&Config {
+        fee_bps: 0,
+        treasury: None,
+        ..config
+    });
+    assert_eq!(client.get_fee_bps(), 0);
+    assert_eq!(client.get_treasury(), None);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #31)")]
+fn test_tip_before_game_end() {
+    let (env, game_id, player_a, player_b, _hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+    let token_id = Address::generate(&env);
+
+    client.create_room(&1u32, &player_a, &100_i128);
+    client.join_room(&1u32, &player_b, &100_i128);
+    client.tip(&1u32, &player_b, &token_id, &10_i128); // WrongPhaseExpectedEnded
+}
+
+#[test]
+fn test_report_game() {
+    let (env, game_id, player_a, player_b, _hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+    let watcher = Address::generate(&env);
+
+    client.create_room(&1u32, &player_a, &100_i128);
+    client.join_room(&1u32, &player_b, &100_i128);
+    client.start_room(&1u32, &player_a, &player_b, &100_i128, &100_i128);
+
+    let salt_a = make_salt(&env, 1);
+    let commit_a = make_commitment(&client, 1, 0, 5, &salt_a);
+    client.bury_treasure(&1u32, &player_a, &commit_a);
+    let salt_b = make_salt(&env, 2);
+    let commit_b = make_commitment(&client, 1, 2, 15, &salt_b);
+    client.bury_treasure(&1u32, &player_b, &commit_b);
+    client.reveal_treasure(&1u32, &player_a, &2u32, &15u32, &salt_b);
+
+    client.report_game(&1u32, &watcher, &symbol_short!("collusion"));
+    client.report_game(&1u32, &player_b, &symbol_short!("bot"));
+
+    let reports = client.get_reports(&1u32, &Cursor { start: 0, limit: 0 }).items;
+    assert_eq!(reports.len(), 2);
+    assert_eq!(reports.get(0).unwrap().reporter, watcher);
+    assert_eq!(reports.get(0).unwrap().reason, symbol_short!("collusion"));
+
+    let dup = client.try_report_game(&1u32, &watcher, &symbol_short!("bot"));
+    assert_eq!(dup, Err(Ok(Error::AlreadyReported.into())));
+}
+
+#[test]
+fn test_reports_are_bounded_per_reporter() {
+    use crate::contract::REPORT_WINDOW_LEDGERS;
+
+    let (env, game_id, player_a, player_b, _hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+    client.set_config(&Config {
+        max_reports_per_reporter: 1,
+        ..client.get_config()
+    });
+    for room_id in 1..=3u32 {
+        client.create_room(&room_id, &player_a, &100_i128);
+        client.join_room(&room_id, &player_b, &100_i128);
+        client.start_room(&room_id, &player_a, &player_b, &100_i128, &100_i128);
+        client.forfeit(&room_id, &player_b);
+    }
+
+    let spammer = Address::generate(&env);
+    env.ledger().set_sequence_number(100);
+    client.report_game(&1u32, &spammer, &symbol_short!("bot"));
+    let res = client.try_report_game(&2u32, &spammer, &symbol_short!("bot"));
+    assert_eq!(res, Err(Ok(Error::TooManyReports.into())));
+
+    // Other reporters are unaffected, and the spammer's window reopens.
+    client.report_game(&2u32, &player_b, &symbol_short!("collusion"));
+    env.ledger().set_sequence_number(100 + REPORT_WINDOW_LEDGERS);
+    client.report_game(&3u32, &spammer, &symbol_short!("bot"));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #31)")]
+fn test_report_game_in_progress() {
+    let (env, game_id, player_a, player_b, _hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+
+    client.create_room(&1u32, &player_a, &100_i128);
+    client.report_game(&1u32, &player_b, &symbol_short!("bot")); // WrongPhaseExpectedEnded
+}
+
+#[test]
+fn test_burial_ledgers() {
+    let (env, game_id, player_a, player_b, _hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+
+    client.create_room(&1u32, &player_a, &100_i128);
+    client.join_room(&1u32, &player_b, &100_i128);
+    client.start_room(&1u32, &player_a, &player_b, &100_i128, &100_i128);
+
+    let burial = client.get_burial_ledgers(&1u32);
+    assert_eq!(burial.player_a, None);
+    assert_eq!(burial.player_b, None);
+
+    env.ledger().set_sequence_number(100);
+    let salt_a = make_salt(&env, 1);
+    let commit_a = make_commitment(&client, 1, 0, 5, &salt_a);
+    client.bury_treasure(&1u32, &player_a, &commit_a);
+
+    env.ledger().set_sequence_number(105);
+    let salt_b = make_salt(&env, 2);
+    let commit_b = make_commitment(&client, 1, 2, 15, &salt_b);
+    client.bury_treasure(&1u32, &player_b, &commit_b);
+
+    let burial = client.get_burial_ledgers(&1u32);
+    assert_eq!(burial.player_a, Some(100));
+    assert_eq!(burial.player_b, Some(105));
+}
+
+#[test]
+fn test_commitments_published_after_end() {
+    let (env, game_id, player_a, player_b, _hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+
+    client.create_room(&1u32, &player_a, &100_i128);
+    client.join_room(&1u32, &player_b, &100_i128);
+    client.start_room(&1u32, &player_a, &player_b, &100_i128, &100_i128);
+
+    let salt_a = make_salt(&env, 1);
+    let commit_a = make_commitment(&client, 1, 0, 5, &salt_a);
+    client.bury_treasure(&1u32, &player_a, &commit_a);
+    let salt_b = make_salt(&env, 2);
+    let commit_b = make_commitment(&client, 1, 2, 15, &salt_b);
+    client.bury_treasure(&1u32, &player_b, &commit_b);
+
+    // Still refused mid-game.
+    assert!(client.try_get_commitments(&1u32).is_err());
+
+    client.reveal_treasure(&1u32, &player_a, &2u32, &15u32, &salt_b);
+
+    let published = client.get_commitments(&1u32);
+    assert_eq!(published.commitment_a, Some(commit_a));
+    assert_eq!(published.commitment_b, Some(commit_b));
+    assert_eq!(published.reveals.len(), 1);
+    let reveal = published.reveals.get(0).unwrap();
+    assert_eq!(reveal.owner, player_b);
+    assert_eq!((reveal.island_id, reveal.tile_id), (2, 15));
+    assert_eq!(reveal.salt, salt_b);
+}
+
+#[test]
+fn test_room_nonce_separates_commitments() {
+    let (env, game_id, player_a, player_b, _hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+
+    let room = client.create_room(&1u32, &player_a, &100_i128);
+    assert_eq!(room.nonce, BytesN::from_array(&env, &[0u8; 32]));
+
+    client.join_room(&1u32, &player_b, &100_i128);
+    client.start_room(&1u32, &player_a, &player_b, &100_i128, &100_i128);
+    client.create_room(&2u32, &player_a, &100_i128);
+    client.join_room(&2u32, &player_b, &100_i128);
+    client.start_room(&2u32, &player_a, &player_b, &100_i128, &100_i128);
+
+    let nonce_1 = client.get_room(&1u32).nonce;
+    let nonce_2 = client.get_room(&2u32).nonce;
+    assert_ne!(nonce_1, BytesN::from_array(&env, &[0u8; 32]));
+    assert_ne!(nonce_1, nonce_2);
+}
+
+#[test]
+fn test_rebury_before_opponent_commits() {
+    let (env, game_id, player_a, player_b, _hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+
+    client.create_room(&1u32, &player_a, &100_i128);
+    client.join_room(&1u32, &player_b, &100_i128);
+    client.start_room(&1u32, &player_a, &player_b, &100_i128, &100_i128);
+
+    let salt_a = make_salt(&env, 1);
+    let typo = make_commitment(&client, 1, 0, 6, &salt_a);
+    client.bury_treasure(&1u32, &player_a, &typo);
+
+    let fixed = make_commitment(&client, 1, 0, 5, &salt_a);
+    client.rebury_treasure(&1u32, &player_a, &fixed);
+    assert!(client.verify_commitment(&1u32, &player_a, &0u32, &5u32, &salt_a));
+    assert!(!client.verify_commitment(&1u32, &player_a, &0u32, &6u32, &salt_a));
+    assert_eq!(client.get_recent_events(&1u32).last().unwrap().kind, symbol_short!("reburied"));
+
+    // Once the opponent buries, the room is Playing and commitments are locked.
+    let salt_b = make_salt(&env, 2);
+    let commit_b = make_commitment(&client, 1, 2, 15, &salt_b);
+    client.bury_treasure(&1u32, &player_b, &commit_b);
+    let locked = client.try_rebury_treasure(&1u32, &player_a, &typo);
+    assert_eq!(locked, Err(Ok(Error::WrongPhaseExpectedBurying.into())));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #21)")]
+fn test_rebury_without_bury() {
+    let (env, game_id, player_a, player_b, _hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+
+    client.create_room(&1u32, &player_a, &100_i128);
+    client.join_room(&1u32, &player_b, &100_i128);
+    client.start_room(&1u32, &player_a, &player_b, &100_i128, &100_i128);
+
+    let salt_a = make_salt(&env, 1);
+    let commit_a = make_commitment(&client, 1, 0, 5, &salt_a);
+    client.rebury_treasure(&1u32, &player_a, &commit_a); // NotBuried
+}
+
+#[test]
+fn test_simultaneous_round() {
+    let (env, game_id, player_a, player_b, _hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+
+    let config = RoomConfig {
+        commitment_scheme: CommitmentScheme::Sha256,
+        dig_mode: DigMode::Simultaneous,
+        roles: Roles::Duel,
+        dig_budget: 0,
+        hider_odds_bps: 0,
+        turn_timeout_ledgers: 0,
+        bury_timeout_ledgers: 0,
+        reveal_window_ledgers: 0,
+        action_points: 0,
+        min_completed: 0,
+        min_score_bps: 0,
+        event_verbosity: EventVerbosity::Default,
+        signed_burials: false,
+        derived_salts: false,
+        stake_token: None,
+        treasures: 0,
+        guess_mode: false,
+        invite_hash: None,
+        hub: None,
+        hubless: false,
+    };
+    client.create_room_with_config(&1u32, &player_a, &100_i128, &config);
+    client.join_room(&1u32, &player_b, &100_i128);
+    client.start_room(&1u32, &player_a, &player_b, &100_i128, &100_i128);
+
+    let salt_a = make_salt(&env, 1);
+    let salt_b = make_salt(&env, 2);
+    client.bury_treasure(&1u32, &player_a, &make_commitment(&client, 1, 0, 5, &salt_a));
+    client.bury_treasure(&1u32, &player_b, &make_commitment(&client, 1, 2, 15, &salt_b));
+
+    // Alternating digs are not available.
+    let res = client.try_dig(&1u32, &player_a, &0u32, &1u32);
+    assert_eq!(res, Err(Ok(Error::WrongMode.into())));
+
+    let room = client.get_room(&1u32);
+    let dig_salt_a = make_salt(&env, 3);
+    let dig_salt_b = make_salt(&env, 4);
+    let dig_a = PiratesTreasure::round_dig_commitment(&env, &room, &player_a, 1, 7, &dig_salt_a);
+    let dig_b = PiratesTreasure::round_dig_commitment(&env, &room, &player_b, 1, 7, &dig_salt_b);
+    // The same choice and salt commit differently for each player, so a
+    // copied commitment cannot be opened by the copier.
+    let copied = PiratesTreasure::round_dig_commitment(&env, &room, &player_b, 1, 7, &dig_salt_a);
+    assert_ne!(copied, dig_a);
+
+    // B may commit first; nobody reveals until both have committed.
+    client.commit_round_dig(&1u32, &player_b, &dig_b);
+    let early = client.try_reveal_round_dig(&1u32, &player_b, &1u32, &7u32, &dig_salt_b);
+    assert_eq!(early, Err(Ok(Error::RoundNotReady.into())));
+    client.commit_round_dig(&1u32, &player_a, &dig_a);
+
+    client.reveal_round_dig(&1u32, &player_b, &1u32, &7u32, &dig_salt_b);
+    let state = client.get_round_state(&1u32);
+    assert!(state.committed_a && state.revealed_b && !state.revealed_a);
+    assert!(client.get_room(&1u32).digs.is_empty());
+
+    // Both picked the same tile; both digs land once the round closes.
+    client.reveal_round_dig(&1u32, &player_a, &1u32, &7u32, &dig_salt_a);
+    let digs = client.get_room(&1u32).digs;
+    assert_eq!(digs.len(), 2);
+    assert_eq!(digs.get(0).unwrap().digger, player_a);
+    assert_eq!(digs.get(1).unwrap().digger, player_b);
+    assert!(!client.get_round_state(&1u32).committed_a);
+
+    // No turn order: B can reveal straight away.
+    client.reveal_treasure(&1u32, &player_b, &0u32, &5u32, &salt_a);
+    assert_eq!(client.get_room(&1u32).winner, player_b);
+}
+
+#[test]
+fn test_hider_seeker_budget() {
+    let (env, game_id, player_a, player_b, _hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+
+    let config = RoomConfig {
+        commitment_scheme: CommitmentScheme::Sha256,
+        dig_mode: DigMode::Alternating,
+        roles: Roles::AHides,
+        dig_budget: 2,
+        hider_odds_bps: 0,
+        turn_timeout_ledgers: 0,
+        bury_timeout_ledgers: 0,
+        reveal_window_ledgers: 0,
+        action_points: 0,
+        min_completed: 0,
+        min_score_bps: 0,
+        event_verbosity: EventVerbosity::Default,
+        signed_burials: false,
+        derived_salts: false,
+        stake_token: None,
+        treasures: 0,
+        guess_mode: false,
+        invite_hash: None,
+        hub: None,
+        hubless: false,
+    };
+    client.create_room_with_config(&1u32, &player_a, &300_i128, &config);
+    client.join_room(&1u32, &player_b, &100_i128);
+    client.start_room(&1u32, &player_a, &player_b, &300_i128, &100_i128);
+
+    // Only the hider buries, and that alone starts play.
+    let salt_b = make_salt(&env, 2);
+    let res = client.try_bury_treasure(&1u32, &player_b, &make_commitment(&client, 1, 2, 15, &salt_b));
+    assert_eq!(res, Err(Ok(Error::WrongMode.into())));
+    let salt_a = make_salt(&env, 1);
+    client.bury_treasure(&1u32, &player_a, &make_commitment(&client, 1, 0, 5, &salt_a));
+    assert_eq!(client.get_room(&1u32).phase, 2);
+
+    // The seeker keeps the turn; the hider never digs.
+    client.dig(&1u32, &player_b, &0u32, &1u32);
+    let res = client.try_dig(&1u32, &player_a, &0u32, &2u32);
+    assert_eq!(res, Err(Ok(Error::NotYourTurn.into())));
+    let opening = Vec::from_array(&env, [make_leaf(&env, 0, 5, 1)]);
+    let res = client.try_claim_hider_win(&1u32, &player_a, &opening);
+    assert_eq!(res, Err(Ok(Error::BudgetRemaining.into())));
+    client.dig(&1u32, &player_b, &0u32, &2u32);
+    let res = client.try_dig(&1u32, &player_b, &0u32, &3u32);
+    assert_eq!(res, Err(Ok(Error::BudgetExhausted.into())));
+
+    // The hider must show where the treasure was.
+    let wrong = Vec::from_array(&env, [make_leaf(&env, 0, 6, 1)]);
+    let res = client.try_claim_hider_win(&1u32, &player_a, &wrong);
+    assert_eq!(res, Err(Ok(Error::BadOpening.into())));
+    client.claim_hider_win(&1u32, &player_a, &opening);
+    let room = client.get_room(&1u32);
+    assert_eq!(room.phase, 3);
+    assert_eq!(room.winner, player_a);
+}
+
+#[test]
+fn test_hider_seeker_treasures_and_odds() {
+    let (env, game_id, player_a, player_b, _hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+
+    let token_admin = Address::generate(&env);
+    let token_id = env.register_stellar_asset_contract_v2(token_admin).address();
+    let sac = StellarAssetClient::new(&env, &token_id);
+    sac.mint(&player_a, &1_000);
+    sac.mint(&player_b, &1_000);
+    let token = TokenClient::new(&env, &token_id);
+
+    let mut config = RoomConfig {
+        commitment_scheme: CommitmentScheme::Sha256,
+        dig_mode: DigMode::Alternating,
+        roles: Roles::Duel,
+        dig_budget: 0,
+        hider_odds_bps: 5_000,
+        turn_timeout_ledgers: 0,
+        bury_timeout_ledgers: 0,
+        reveal_window_ledgers: 0,
+        action_points: 0,
+        min_completed: 0,
+        min_score_bps: 0,
+        event_verbosity: EventVerbosity::Default,
+        signed_burials: false,
+        derived_salts: false,
+        stake_token: Some(token_id.clone()),
+        treasures: 3,
+        guess_mode: false,
+        invite_hash: None,
+        hub: None,
+        hubless: false,
+    };
+    // Odds only weigh a hider against a seeker.
+    let res = client.try_create_room_with_config(&1u32, &player_a, &200_i128, &config);
+    assert_eq!(res, Err(Ok(Error::InvalidConfig.into())));
+    config.roles = Roles::AHides;
+    config.dig_budget = 3;
+    client.create_room_with_config(&1u32, &player_a, &200_i128, &config);
+    client.join_room(&1u32, &player_b, &300_i128);
+    client.start_room(&1u32, &player_a, &player_b, &200_i128, &300_i128);
+
+    // The hider buries three treasures under one Merkle root.
+    let leaves = [(0u32, 1u32), (1, 2), (2, 3)].map(|(island, tile)| {
+        make_commitment(&client, 1, island, tile, &make_salt(&env, 10 + tile as u8))
+    });
+    let pair = PiratesTreasure::merkle_parent(&env, &leaves[0], &leaves[1]);
+    let root = PiratesTreasure::merkle_parent(&env, &pair, &leaves[2]);
+    client.bury_treasure(&1u32, &player_a, &root);
+    assert_eq!(client.get_room(&1u32).phase, 2);
+
+    // One find is short of a majority, and the budget runs out.
+    let proof = Vec::from_array(&env, [leaves[1].clone(), leaves[2].clone()]);
+    let salt = make_salt(&env, 11);
+    client.reveal_treasure_proof(&1u32, &player_b, &0u32, &1u32, &salt, &proof, &Vec::new(&env));
+    assert_eq!(client.get_room(&1u32).phase, 2);
+    for tile in 4..7u32 {
+        client.dig(&1u32, &player_b, &0u32, &tile);
+    }
+    let opening = Vec::from_array(
+        &env,
+        [make_leaf(&env, 0, 1, 11), make_leaf(&env, 1, 2, 12), make_leaf(&env, 2, 3, 13)],
+    );
+    client.claim_hider_win(&1u32, &player_a, &opening);
+
+    // At half odds the hider wins 100 of the seeker's 300.
+    assert_eq!(token.balance(&game_id), 0);
+    assert_eq!(token.balance(&player_a), 1_100);
+    assert_eq!(token.balance(&player_b), 900);
+}
+
+#[test]
+fn test_set_config() {
+    use crate::contract::{
+        DEFAULT_BURY_TIMEOUT_LEDGERS, DEFAULT_REVEAL_WINDOW_LEDGERS, DEFAULT_TURN_TIMEOUT_LEDGERS,
+        MAX_TIMEOUT_LEDGERS,
+    };
+
+    let (env, game_id, player_a, player_b, hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+
+    let defaults = client.get_config();
+    assert_eq!(defaults.game_hub, Some(hub.clone()));
+    assert_eq!(defaults.max_recent_events, DEFAULT_RECENT_EVENTS);
+    assert_eq!(defaults.max_reports_per_reporter, DEFAULT_REPORTS_PER_REPORTER);
+    assert_eq!(defaults.event_verbosity, EventVerbosity::Full);
+    assert_eq!(defaults.turn_timeout_ledgers, DEFAULT_TURN_TIMEOUT_LEDGERS);
+    assert_eq!(defaults.bury_timeout_ledgers, DEFAULT_BURY_TIMEOUT_LEDGERS);
+    assert_eq!(defaults.reveal_window_ledgers, DEFAULT_REVEAL_WINDOW_LEDGERS);
+    assert!(!defaults.paused);
+    assert_eq!(defaults.version, client.get_version());
+
+    let bad = Config {
+        max_recent_events: 0,
+        ..defaults.clone()
+    };
+    assert_eq!(client.try_set_config(&bad), Err(Ok(Error::InvalidConfig.into())));
+    let bad = Config {
+        turn_timeout_ledgers: 0,
+        ..defaults.clone()
+    };
+    assert_eq!(client.try_set_config(&bad), Err(Ok(Error::InvalidConfig.into())));
+    let bad = Config {
+        reveal_window_ledgers: MAX_TIMEOUT_LEDGERS + 1,
+        ..defaults.clone()
+    };
+    assert_eq!(client.try_set_config(&bad), Err(Ok(Error::InvalidConfig.into())));
+    let fixed = Config {
+        version: defaults.version + 1,
+        ..defaults.clone()
+    };
+    assert_eq!(client.try_set_config(&fixed), Err(Ok(Error::InvalidConfig.into())));
+
+    client.set_config(&Config {
+        max_recent_events: 2,
+        turn_timeout_ledgers: 100,
+        ..defaults
+    });
+    assert_eq!(client.get_config().max_recent_events, 2);
+    assert_eq!(client.get_config().turn_timeout_ledgers, 100);
+
+    client.create_room(&1u32, &player_a, &100_i128);
+    client.join_room(&1u32, &player_b, &100_i128);
+    client.start_room(&1u32, &player_a, &player_b, &100_i128, &100_i128);
+    let events = client.get_recent_events(&1u32);
+    assert_eq!(events.len(), 2);
+    assert_eq!(events.get(0).unwrap().kind, symbol_short!("joined"));
+
+    // Rooms take the admin's timeouts unless they set their own.
+    let room = client.get_room(&1u32);
+    assert_eq!(room.config.turn_timeout_ledgers, 100);
+    assert_eq!(room.config.bury_timeout_ledgers, DEFAULT_BURY_TIMEOUT_LEDGERS);
+
+    let mut config = RoomConfig {
+        commitment_scheme: CommitmentScheme::Sha256,
+        dig_mode: DigMode::Alternating,
+        roles: Roles::Duel,
+        dig_budget: 0,
+        hider_odds_bps: 0,
+        turn_timeout_ledgers: 0,
+        bury_timeout_ledgers: 0,
+        reveal_window_ledgers: 50,
+        action_points: 0,
+        min_completed: 0,
+        min_score_bps: 0,
+        event_verbosity: EventVerbosity::Default,
+        signed_burials: false,
+        derived_salts: false,
+        stake_token: None,
+        treasures: 0,
+        guess_mode: false,
+        invite_hash: None,
+        hub: None,
+        hubless: false,
+    };
+    client.create_room_with_config(&2u32, &player_a, &100_i128, &config);
+    let room = client.get_room(&2u32);
+    assert_eq!(room.config.turn_timeout_ledgers, 100);
+    assert_eq!(room.config.reveal_window_ledgers, 50);
+
+    config.turn_timeout_ledgers = MAX_TIMEOUT_LEDGERS + 1;
+    let res = client.try_create_room_with_config(&3u32, &player_a, &100_i128, &config);
+    assert_eq!(res, Err(Ok(Error::InvalidConfig.into())));
+}
+
+#[test]
+fn test_room_event_decodes_typed() {
+    use soroban_sdk::{testutils::Events as _, xdr, TryFromVal, Val};
+
+    let (env, game_id, player_a, _player_b, _hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+
+    client.create_room(&7u32, &player_a, &100_i128);
+
+    let all = env.events().all().filter_by_contract(&game_id);
+    let event = all.events().last().unwrap();
+    let xdr::ContractEventBody::V0(body) = &event.body;
+    let room_id = u32::try_from_val(&env, &Val::try_from_val(&env, &body.topics[1]).unwrap());
+    assert_eq!(room_id, Ok(7));
+    let data = Val::try_from_val(&env, &body.data).unwrap();
+    let data = RoomEventData::try_from_val(&env, &data).unwrap();
+    assert_eq!(data.actor, player_a);
+}
+
+#[test]
+fn test_typed_action_events() {
+    use soroban_sdk::{testutils::Events as _, Event};
+
+    let (env, game_id, player_a, player_b, _hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+
+    client.create_room(&1u32, &player_a, &100_i128);
+    let created = RoomCreated {
+        room_id: 1,
+        player_a: player_a.clone(),
+        player_a_points: 100,
+    };
+    let all = env.events().all().filter_by_contract(&game_id);
+    assert!(all.events().contains(&created.to_xdr(&env, &game_id)));
+
+    client.join_room(&1u32, &player_b, &100_i128);
+    client.start_room(&1u32, &player_a, &player_b, &100_i128, &100_i128);
+    let salt_a = make_salt(&env, 1);
+    client.bury_treasure(&1u32, &player_a, &make_commitment(&client, 1, 0, 5, &salt_a));
+    let salt_b = make_salt(&env, 2);
+    client.bury_treasure(&1u32, &player_b, &make_commitment(&client, 1, 2, 15, &salt_b));
+    client.dig(&1u32, &player_a, &1u32, &7u32);
+    let dug = TileDug {
+        room_id: 1,
+        digger: player_a.clone(),
+        island_id: 1,
+        tile_id: 7,
+    };
+    let all = env.events().all().filter_by_contract(&game_id);
+    assert!(all.events().contains(&dug.to_xdr(&env, &game_id)));
+}
+
+#[test]
+fn test_observers() {
+    use soroban_sdk::{testutils::Events as _, xdr, TryFromVal, Val};
+
+    let (env, game_id, player_a, player_b, _hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+    let bot = Address::generate(&env);
+
+    let res = client.try_watch_room(&1u32, &bot);
+    assert_eq!(res, Err(Ok(Error::RoomNotFound.into())));
+    client.create_room(&1u32, &player_a, &100_i128);
+    client.watch_room(&1u32, &bot);
+    client.watch_room(&1u32, &bot);
+    assert_eq!(client.get_observers(&1u32), Vec::from_array(&env, [bot.clone()]));
+
+    // The join is copied to the bot under its own address.
+    client.join_room(&1u32, &player_b, &100_i128);
+    let all = env.events().all().filter_by_contract(&game_id);
+    let event = all.events().last().unwrap();
+    let xdr::ContractEventBody::V0(body) = &event.body;
+    let topic = Val::try_from_val(&env, &body.topics[1]).unwrap();
+    assert_eq!(Address::try_from_val(&env, &topic), Ok(bot.clone()));
+
+    for _ in 1..8 {
+        client.watch_room(&1u32, &Address::generate(&env));
+    }
+    let res = client.try_watch_room(&1u32, &Address::generate(&env));
+    assert_eq!(res, Err(Ok(Error::TooManyObservers.into())));
+
+    client.unwatch_room(&1u32, &bot);
+    assert_eq!(client.get_observers(&1u32).len(), 7);
+    assert!(!client.get_observers(&1u32).contains(&bot));
+}
+
+#[test]
+fn test_event_verbosity() {
+    use soroban_sdk::{testutils::Events as _, xdr, Map, TryFromVal, Val};
+
+    let (env, game_id, player_a, _player_b, _hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+    let last_body_is_empty = || {
+        let all = env.events().all().filter_by_contract(&game_id);
+        let event = all.events().last().unwrap().clone();
+        let xdr::ContractEventBody::V0(body) = event.body;
+        assert_eq!(body.topics.len(), 3);
+        let data = Val::try_from_val(&env, &body.data).unwrap();
+        Map::<Symbol, Val>::try_from_val(&env, &data).is_ok_and(|m| m.is_empty())
+    };
+
+    client.create_room(&1u32, &player_a, &100_i128);
+    assert!(!last_body_is_empty());
+
+    // Contract-wide minimal events apply to rooms left at Default...
+    client.set_config(&Config {
+        event_verbosity: EventVerbosity::Minimal,
+        ..client.get_config()
+    });
+    client.create_room(&2u32, &player_a, &100_i128);
+    assert!(last_body_is_empty());
+
+    // ...but a room can ask for full payloads.
+    let config = RoomConfig {
+        commitment_scheme: CommitmentScheme::Sha256,
+        dig_mode: DigMode::Alternating,
+        roles: Roles::Duel,
+        dig_budget: 0,
+        hider_odds_bps: 0,
+        turn_timeout_ledgers: 0,
+        bury_timeout_ledgers: 0,
+        reveal_window_ledgers: 0,
+        action_points: 0,
+        min_completed: 0,
+        min_score_bps: 0,
+        event_verbosity: EventVerbosity::Full,
+        signed_burials: false,
+        derived_salts: false,
+        stake_token: None,
+        treasures: 0,
+        guess_mode: false,
+        invite_hash: None,
+        hub: None,
+        hubless: false,
+    };
+    client.create_room_with_config(&3u32, &player_a, &100_i128, &config);
+    assert!(!last_body_is_empty());
+    assert_eq!(client.get_recent_events(&2u32).len(), 1);
+}
+
+#[test]
+fn test_signed_burials() {
+    use ed25519_dalek::{Signer, SigningKey};
+
+    let (env, game_id, player_a, player_b, _hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+    let config = RoomConfig {
+        commitment_scheme: CommitmentScheme::Sha256,
+        dig_mode: DigMode::Alternating,
+        roles: Roles::Duel,
+        dig_budget: 0,
+        hider_odds_bps: 0,
+        turn_timeout_ledgers: 0,
+        bury_timeout_ledgers: 0,
+        reveal_window_ledgers: 0,
+        action_points: 0,
+        min_completed: 0,
+        min_score_bps: 0,
+        event_verbosity: EventVerbosity::Default,
+        signed_burials: true,
+        derived_salts: false,
+        stake_token: None,
+        treasures: 0,
+        guess_mode: false,
+        invite_hash: None,
+        hub: None,
+        hubless: false,
+    };
+    client.create_room_with_config(&1u32, &player_a, &100_i128, &config);
+    client.join_room(&1u32, &player_b, &100_i128);
+    client.start_room(&1u32, &player_a, &player_b, &100_i128, &100_i128);
+
+    let key = SigningKey::from_bytes(&[7u8; 32]);
+    let commitment = make_commitment(&client, 1, 0, 5, &make_salt(&env, 1));
+    let sign = |room_id: u32, commitment: &BytesN<32>| {
+        let mut msg = [0u8; 36];
+        msg[..4].copy_from_slice(&room_id.to_be_bytes());
+        msg[4..].copy_from_slice(&commitment.to_array());
+        BytesN::from_array(&env, &key.sign(&msg).to_bytes())
+    };
+
+    let res = client.try_bury_treasure(&1u32, &player_a, &commitment);
+    assert_eq!(res, Err(Ok(Error::WrongMode.into())));
+    let signature = sign(1, &commitment);
+    let res = client.try_bury_treasure_signed(&1u32, &player_a, &commitment, &signature);
+    assert_eq!(res, Err(Ok(Error::BurialKeyMissing.into())));
+
+    let public_key = BytesN::from_array(&env, &key.verifying_key().to_bytes());
+    client.register_burial_key(&player_a, &public_key);
+    // A signature for another room does not carry over.
+    assert!(client
+        .try_bury_treasure_signed(&1u32, &player_a, &commitment, &sign(2, &commitment))
+        .is_err());
+    client.bury_treasure_signed(&1u32, &player_a, &commitment, &signature);
+    assert!(client.get_room(&1u32).has_commitment_a);
+    let res = client.try_rebury_treasure(&1u32, &player_a, &commitment);
+    assert_eq!(res, Err(Ok(Error::WrongMode.into())));
+}
+
+#[test]
+fn test_derived_salts() {
+    use ed25519_dalek::{Signer, SigningKey};
+
+    let (env, game_id, player_a, player_b, _hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+    let config = RoomConfig {
+        commitment_scheme: CommitmentScheme::Sha256,
+        dig_mode: DigMode::Alternating,
+        roles: Roles::Duel,
+        dig_budget: 0,
+        hider_odds_bps: 0,
+        turn_timeout_ledgers: 0,
+        bury_timeout_ledgers: 0,
+        reveal_window_ledgers: 0,
+        action_points: 0,
+        min_completed: 0,
+        min_score_bps: 0,
+        event_verbosity: EventVerbosity::Default,
+        signed_burials: false,
+        derived_salts: true,
+        stake_token: None,
+        treasures: 0,
+        guess_mode: false,
+        invite_hash: None,
+        hub: None,
+        hubless: false,
+    };
+    client.create_room_with_config(&1u32, &player_a, &100_i128, &config);
+    client.join_room(&1u32, &player_b, &100_i128);
+    client.start_room(&1u32, &player_a, &player_b, &100_i128, &100_i128);
+
+    // B derives their salt by signing the room's salt message.
+    let key_b = SigningKey::from_bytes(&[9u8; 32]);
+    let public_key = BytesN::from_array(&env, &key_b.verifying_key().to_bytes());
+    client.register_burial_key(&player_b, &public_key);
+    let msg = client.get_salt_message(&1u32);
+    let mut buf = [0u8; 47];
+    msg.copy_into_slice(&mut buf);
+    let signature = BytesN::from_array(&env, &key_b.sign(&buf).to_bytes());
+    let salt_b = env.crypto().sha256(&signature.clone().into()).to_bytes();
+
+    let salt_a = make_salt(&env, 1);
+    client.bury_treasure(&1u32, &player_a, &make_commitment(&client, 1, 0, 5, &salt_a));
+    client.bury_treasure(&1u32, &player_b, &make_commitment(&client, 1, 2, 15, &salt_b));
+
+    // A raw salt is refused even when it is the right one.
+    let res = client.try_reveal_treasure(&1u32, &player_a, &2u32, &15u32, &salt_b);
+    assert_eq!(res, Err(Ok(Error::WrongMode.into())));
+    let forged = BytesN::from_array(&env, &[1u8; 64]);
+    assert!(client
+        .try_reveal_treasure_derived(&1u32, &player_a, &2u32, &15u32, &forged)
+        .is_err());
+
+    client.reveal_treasure_derived(&1u32, &player_a, &2u32, &15u32, &signature);
+    assert_eq!(client.get_room(&1u32).winner, player_a);
+}
+
+#[test]
+fn test_start_room_auth_bound_to_points() {
+    use soroban_sdk::{testutils::AuthorizedFunction, IntoVal, Val};
+
+    let (env, game_id, player_a, player_b, _hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+
+    client.create_room(&1u32, &player_a, &100_i128);
+    client.join_room(&1u32, &player_b, &250_i128);
+    client.start_room(&1u32, &player_a, &player_b, &100_i128, &250_i128);
+
+    // Each signature covers only (room_id, own points).
+    let auths = env.auths();
+    assert_eq!(auths.len(), 2);
+    for ((signer, invocation), (player, points)) in
+        auths.iter().zip([(&player_a, 100_i128), (&player_b, 250_i128)])
+    {
+        assert_eq!(signer, player);
+        let AuthorizedFunction::Contract((contract, function, args)) = &invocation.function
+        else {
+            panic!("expected a contract invocation");
+        };
+        assert_eq!(contract, &game_id);
+        assert_eq!(function, &Symbol::new(&env, "start_room"));
+        let expected: Vec<Val> = (1u32, points).into_val(&env);
+        assert_eq!(args, &expected);
+    }
+}
+
+#[test]
+fn test_start_room_checks_player_addresses() {
+    let (env, game_id, player_a, player_b, _hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+    let stranger = Address::generate(&env);
+
+    client.create_room(&1u32, &player_a, &100_i128);
+    client.join_room(&1u32, &player_b, &100_i128);
+
+    let res = client.try_bury_treasure(&1u32, &player_a, &make_salt(&env, 9));
+    assert_eq!(res, Err(Ok(Error::WrongPhaseExpectedBurying.into())));
+    let res = client.try_start_room(&1u32, &stranger, &player_b, &100_i128, &100_i128);
+    assert_eq!(res, Err(Ok(Error::NotPlayerA.into())));
+    let res = client.try_start_room(&1u32, &player_a, &stranger, &100_i128, &100_i128);
+    assert_eq!(res, Err(Ok(Error::NotPlayerB.into())));
+
+    client.start_room(&1u32, &player_a, &player_b, &100_i128, &100_i128);
+    let res = client.try_dig(&1u32, &player_a, &0u32, &0u32);
+    assert_eq!(res, Err(Ok(Error::WrongPhaseExpectedPlaying.into())));
+}
+
+#[test]
+fn test_admin_dead_man_switch() {
+    let (env, game_id, _player_a, _player_b, hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+    let backup = Address::generate(&env);
+
+    env.ledger().set_sequence_number(100);
+    client.set_admin_backup(&backup, &1_000u32);
+    assert_eq!(client.get_admin_backup().unwrap().backup, backup);
+
+    // Any admin action resets the clock.
+    env.ledger().set_sequence_number(900);
+    client.set_hub(&hub);
+    env.ledger().set_sequence_number(1_500);
+    let early = client.try_claim_admin(&backup);
+    assert_eq!(early, Err(Ok(Error::AdminStillActive.into())));
+
+    let stranger = Address::generate(&env);
+    let res = client.try_claim_admin(&stranger);
+    assert_eq!(res, Err(Ok(Error::Unauthorized.into())));
+
+    env.ledger().set_sequence_number(1_900);
+    client.claim_admin(&backup);
+    assert_eq!(client.get_admin(), backup);
+    assert_eq!(client.get_admin_backup(), None);
+}
+
+#[test]
+fn test_hubless_room_settles_locally() {
+    let (env, game_id, player_a, player_b, _hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+
+    client.create_room(&1u32, &player_a, &100_i128);
+    let res = client.try_set_room_hubless(&1u32);
+    assert_eq!(res, Err(Ok(Error::WrongPhaseExpectedBurying.into())));
+    client.join_room(&1u32, &player_b, &100_i128);
+    client.start_room(&1u32, &player_a, &player_b, &100_i128, &100_i128);
+    let salt_a = make_salt(&env, 1);
+    client.bury_treasure(&1u32, &player_a, &make_commitment(&client, 1, 0, 5, &salt_a));
+    let salt_b = make_salt(&env, 2);
+    client.bury_treasure(&1u32, &player_b, &make_commitment(&client, 1, 2, 15, &salt_b));
+
+    // The hub goes dark: any call into it now fails.
+    client.set_hub(&Address::generate(&env));
+
+    client.set_room_hubless(&1u32);
+    assert!(client.get_room(&1u32).hubless);
+    client.reveal_treasure(&1u32, &player_a, &2u32, &15u32, &salt_b);
+    let room = client.get_room(&1u32);
+    assert_eq!(room.winner, player_a);
+    // Nothing was owed to the hub, so nothing waits for a retry.
+    assert_eq!(client.get_pending_hub_report(&1u32), None);
+    assert_eq!(client.try_set_room_hubless(&1u32), Err(Ok(Error::GameEnded.into())));
+}
+
+#[test]
+fn test_frozen_room_rejects_moves() {
+    use crate::contract::{DEFAULT_BURY_TIMEOUT_LEDGERS, DEFAULT_TURN_TIMEOUT_LEDGERS};
+
+    let (env, game_id, player_a, player_b, _hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+
+    client.create_room(&1u32, &player_a, &100_i128);
+    client.join_room(&1u32, &player_b, &100_i128);
+    client.start_room(&1u32, &player_a, &player_b, &100_i128, &100_i128);
+    let salt_a = make_salt(&env, 1);
+    client.bury_treasure(&1u32, &player_a, &make_commitment(&client, 1, 0, 5, &salt_a));
+    let salt_b = make_salt(&env, 2);
+    client.bury_treasure(&1u32, &player_b, &make_commitment(&client, 1, 2, 15, &salt_b));
+
+    let deadline = client.get_room(&1u32).turn_deadline;
+    client.set_room_frozen(&1u32, &true);
+    assert!(client.get_room(&1u32).frozen);
+    let res = client.try_dig(&1u32, &player_a, &0u32, &1u32);
+    assert_eq!(res, Err(Ok(Error::RoomFrozen.into())));
+    let res = client.try_reveal_treasure(&1u32, &player_a, &2u32, &15u32, &salt_b);
+    assert_eq!(res, Err(Ok(Error::RoomFrozen.into())));
+    let events = client.get_recent_events(&1u32);
+    assert_eq!(events.last().unwrap().kind, symbol_short!("frozen"));
+
+    // Time spent frozen does not count against the player on turn.
+    let frozen_for = DEFAULT_TURN_TIMEOUT_LEDGERS + 10;
+    env.ledger().set_sequence_number(env.ledger().sequence() + frozen_for);
+    client.set_room_frozen(&1u32, &false);
+    assert_eq!(client.get_room(&1u32).turn_deadline, deadline + frozen_for);
+    let res = client.try_claim_timeout(&1u32, &player_b);
+    assert_eq!(res, Err(Ok(Error::TurnNotExpired.into())));
+    client.dig(&1u32, &player_a, &0u32, &1u32);
+    client.reveal_treasure(&1u32, &player_b, &0u32, &5u32, &salt_a);
+    let res = client.try_set_room_frozen(&1u32, &true);
+    assert_eq!(res, Err(Ok(Error::GameEnded.into())));
+
+    // Nor can an aborted room be frozen.
+    client.create_room(&2u32, &player_a, &100_i128);
+    client.join_room(&2u32, &player_b, &100_i128);
+    client.start_room(&2u32, &player_a, &player_b, &100_i128, &100_i128);
+    env.ledger().set_sequence_number(env.ledger().sequence() + DEFAULT_BURY_TIMEOUT_LEDGERS + 1);
+    client.abort_room(&2u32, &player_a);
+    let res = client.try_set_room_frozen(&2u32, &true);
+    assert_eq!(res, Err(Ok(Error::GameEnded.into())));
+}
+
+#[test]
+fn test_rollback_dig() {
+    let (env, game_id, player_a, player_b, _hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+
+    client.create_room(&1u32, &player_a, &100_i128);
+    client.join_room(&1u32, &player_b, &100_i128);
+    client.start_room(&1u32, &player_a, &player_b, &100_i128, &100_i128);
+    let salt_a = make_salt(&env, 1);
+    client.bury_treasure(&1u32, &player_a, &make_commitment(&client, 1, 0, 5, &salt_a));
+    let salt_b = make_salt(&env, 2);
+    client.bury_treasure(&1u32, &player_b, &make_commitment(&client, 1, 2, 15, &salt_b));
+
+    let res = client.try_rollback_dig(&1u32);
+    assert_eq!(res, Err(Ok(Error::NoDigToRollBack.into())));
+
+    client.dig(&1u32, &player_a, &0u32, &1u32);
+    client.rollback_dig(&1u32);
+    // Both players had to sign off.
+    let signers: Vec<Address> =
+        Vec::from_iter(&env, env.auths().into_iter().map(|(addr, _)| addr));
+    assert_eq!(signers, Vec::from_array(&env, [player_a.clone(), player_b.clone()]));
+    let room = client.get_room(&1u32);
+    assert!(room.digs.is_empty());
+    assert!(room.turn_is_a);
+    let events = client.get_recent_events(&1u32);
+    assert_eq!(events.last().unwrap().kind, symbol_short!("rollback"));
+
+    // The tile is free to dig again.
+    client.dig(&1u32, &player_a, &0u32, &1u32);
+    assert_eq!(client.get_room(&1u32).digs.len(), 1);
+}
+
+#[test]
+fn test_rollback_dig_reopens_exhausted_board() {
+    let (env, game_id, player_a, player_b, _hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+
+    client.create_room(&1u32, &player_a, &100_i128);
+    client.join_room(&1u32, &player_b, &100_i128);
+    client.start_room(&1u32, &player_a, &player_b, &100_i128, &100_i128);
+    let salt_a = make_salt(&env, 1);
+    client.bury_treasure(&1u32, &player_a, &make_commitment(&client, 1, 0, 5, &salt_a));
+    let salt_b = make_salt(&env, 2);
+    client.bury_treasure(&1u32, &player_b, &make_commitment(&client, 1, 2, 15, &salt_b));
+
+    env.ledger().set_sequence_number(100);
+    let mut a_turn = true;
+    for (island, tiles) in [(0u32, 10u32), (1, 20), (2, 30)] {
+        for tile in 0..tiles {
+            let digger = if a_turn { &player_a } else { &player_b };
+            client.dig(&1u32, digger, &island, &tile);
+            a_turn = !a_turn;
+        }
+    }
+    assert_eq!(client.get_room(&1u32).exhausted_at, Some(100));
+
+    // Undoing the last dig frees a tile, so the endgame has not begun.
+    client.rollback_dig(&1u32);
+    let room = client.get_room(&1u32);
+    assert_eq!(room.exhausted_at, None);
+    assert!(!room.turn_is_a);
+    let stranger = Address::generate(&env);
+    let res = client.try_finalize_draw(&1u32, &stranger);
+    assert_eq!(res, Err(Ok(Error::BoardNotExhausted.into())));
+
+    env.ledger().set_sequence_number(110);
+    client.dig(&1u32, &player_b, &2u32, &29u32);
+    assert_eq!(client.get_room(&1u32).exhausted_at, Some(110));
+}
+
+#[test]
+fn test_audit_room() {
+    let (env, game_id, player_a, player_b, _hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+
+    client.create_room(&1u32, &player_a, &100_i128);
+    assert!(client.audit_room(&1u32).is_empty());
+    client.join_room(&1u32, &player_b, &100_i128);
+    client.start_room(&1u32, &player_a, &player_b, &100_i128, &100_i128);
+    let salt_a = make_salt(&env, 1);
+    client.bury_treasure(&1u32, &player_a, &make_commitment(&client, 1, 0, 5, &salt_a));
+    let salt_b = make_salt(&env, 2);
+    client.bury_treasure(&1u32, &player_b, &make_commitment(&client, 1, 2, 15, &salt_b));
+    client.dig(&1u32, &player_a, &0u32, &1u32);
+    assert!(client.audit_room(&1u32).is_empty());
+
+    // Corrupt the stored room behind the contract's back.
+    let mut room = client.get_room(&1u32);
+    room.winner = player_b.clone();
+    room.digs.push_back(room.digs.get(0).unwrap());
+    env.as_contract(&game_id, || {
+        env.storage().temporary().set(&DataKey::Room(1), &room);
+        env.storage().temporary().remove(&DataKey::Commitment(1, false));
+    });
+    assert_eq!(
+        client.audit_room(&1u32),
+        Vec::from_array(
+            &env,
+            [symbol_short!("winner"), symbol_short!("commit_b"), symbol_short!("digs")]
+        )
+    );
+
+    client.reveal_treasure(&1u32, &player_b, &0u32, &5u32, &salt_a);
+    let violations = client.audit_room(&1u32);
+    assert!(!violations.contains(symbol_short!("winner")));
+}
+
+#[test]
+fn test_migrate_v1_rooms() {
+    use crate::contract::STATE_VERSION;
+    use soroban_sdk::Bytes;
+
+    // The original layout's commitment: SHA-256(room_id ‖ island ‖ tile ‖ salt).
+    let legacy_commitment = |env: &Env, island: u32, tile: u32, salt: &BytesN<32>| {
+        let mut buf = Bytes::new(env);
+        buf.extend_from_array(&1u32.to_be_bytes());
+        buf.extend_from_array(&island.to_be_bytes());
+        buf.extend_from_array(&tile.to_be_bytes());
+        buf.extend_from_slice(&salt.to_array());
+        env.crypto().sha256(&buf).to_bytes()
+    };
+
+    let (env, game_id, player_a, player_b, _hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+    assert_eq!(client.get_version(), STATE_VERSION);
+
+    client.create_room(&1u32, &player_a, &100_i128);
+    client.join_room(&1u32, &player_b, &100_i128);
+    client.start_room(&1u32, &player_a, &player_b, &100_i128, &100_i128);
+    client.create_room(&2u32, &player_a, &50_i128);
+
+    // A buried under the original wasm, before the upgrade.
+    let salt_a = make_salt(&env, 1);
+    client.bury_treasure(&1u32, &player_a, &legacy_commitment(&env, 0, 5, &salt_a));
+
+    // Roll room 1 back to the layout an earlier wasm would have stored.
+    let old = client.get_room_v1(&1u32);
+    env.as_contract(&game_id, || {
+        env.storage().temporary().set(&DataKey::Room(1), &old);
+        env.storage().instance().remove(&DataKey::StateVersion);
+    });
+    assert_eq!(client.get_version(), 1);
+    assert!(client.try_get_room(&1u32).is_err());
+
+    // A dry run reports the work and writes nothing.
+    let ids = Vec::from_array(&env, [1u32, 2, 3]);
+    let preview = client.preview_migration(&ids);
+    assert_eq!(preview.from_version, 1);
+    assert_eq!(preview.to_version, STATE_VERSION);
+    assert_eq!(preview.converted, Vec::from_array(&env, [1u32]));
+    assert_eq!(preview.scheme, CommitmentScheme::LegacySha256);
+    assert!(preview.invalid.is_empty());
+    assert_eq!((preview.current, preview.missing), (1, 1));
+    assert!(client.try_get_room(&1u32).is_err());
+
+    // A broken room is flagged before it is converted.
+    let mut broken = old.clone();
+    broken.game_active = false;
+    env.as_contract(&game_id, || env.storage().temporary().set(&DataKey::Room(4), &broken));
+    let preview = client.preview_migration(&Vec::from_array(&env, [1u32, 4]));
+    assert_eq!(preview.invalid, Vec::from_array(&env, [4u32]));
+
+    client.migrate(&ids);
+    assert_eq!(client.get_version(), STATE_VERSION);
+    let room = client.get_room(&1u32);
+    assert_eq!(RoomV1::from(room.clone()), old);
+    assert!(room.turn_deadline > env.ledger().sequence());
+    assert_eq!(room.config.commitment_scheme, CommitmentScheme::LegacySha256);
+    assert_eq!(client.get_room(&2u32).player_a_points, 50);
+
+    // The game in flight finishes against its original commitments.
+    let salt_b = make_salt(&env, 2);
+    client.bury_treasure(&1u32, &player_b, &legacy_commitment(&env, 2, 15, &salt_b));
+    client.reveal_treasure(&1u32, &player_a, &2u32, &15u32, &salt_b);
+    assert_eq!(client.get_room(&1u32).winner, player_a);
+
+    // New rooms cannot opt into the legacy scheme.
+    let config = RoomConfig {
+        commitment_scheme: CommitmentScheme::LegacySha256,
+        ..client.get_room(&2u32).config
+    };
+    let res = client.try_create_room_with_config(&3u32, &player_a, &100_i128, &config);
+    assert_eq!(res, Err(Ok(Error::InvalidConfig.into())));
+}
+
+#[test]
+fn test_abort_room() {
+    use crate::contract::DEFAULT_BURY_TIMEOUT_LEDGERS;
+
+    let (env, game_id, player_a, player_b, hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+    client.set_hub_kind(&hub, &HubKind::Extended);
+
+    env.ledger().set_sequence_number(100);
+    client.create_room(&1u32, &player_a, &100_i128);
+    client.join_room(&1u32, &player_b, &100_i128);
+    client.start_room(&1u32, &player_a, &player_b, &100_i128, &100_i128);
+
+    env.ledger().set_sequence_number(100 + DEFAULT_BURY_TIMEOUT_LEDGERS);
+    let res = client.try_abort_room(&1u32, &player_b);
+    assert_eq!(res, Err(Ok(Error::BurialWindowOpen.into())));
+
+    env.ledger().set_sequence_number(101 + DEFAULT_BURY_TIMEOUT_LEDGERS);
+    client.abort_room(&1u32, &player_b);
+    let calls = MockGameHubClient::new(&env, &hub).calls();
+    assert_eq!(calls.last(), Some(HubCall::Aborted(1)));
+    let room = client.get_room(&1u32);
+    assert_eq!(room.phase, 4);
+    assert!(!room.game_active);
+    assert_eq!(client.get_player_stats(&player_a).games, 0);
+}
+
+#[test]
+fn test_legacy_hub_hears_end_game() {
+    use crate::contract::DEFAULT_BURY_TIMEOUT_LEDGERS;
+
+    let (env, game_id, player_a, player_b, hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+    let hub_client = MockGameHubClient::new(&env, &hub);
+    assert_eq!(client.get_hub_kind(&hub), HubKind::Legacy);
+
+    env.ledger().set_sequence_number(100);
+    for room_id in 1..=2u32 {
+        client.create_room(&room_id, &player_a, &100_i128);
+        client.join_room(&room_id, &player_b, &100_i128);
+        client.start_room(&room_id, &player_a, &player_b, &100_i128, &100_i128);
+    }
+    env.ledger().set_sequence_number(101 + DEFAULT_BURY_TIMEOUT_LEDGERS);
+
+    // A legacy hub only has `end_game`, so the abort closes the session
+    // through it.
+    client.abort_room(&1u32, &player_b);
+    assert_eq!(hub_client.calls().last(), Some(HubCall::Ended(1, false)));
+
+    client.set_hub_kind(&hub, &HubKind::Extended);
+    assert_eq!(client.get_hub_kind(&hub), HubKind::Extended);
+    client.abort_room(&2u32, &player_b);
+    assert_eq!(hub_client.calls().last(), Some(HubCall::Aborted(2)));
+}
+
+#[test]
+fn test_session_hub_is_told_the_game() {
+    let (env, game_id, player_a, player_b, hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+    let hub_client = MockGameHubClient::new(&env, &hub);
+    client.set_hub_kind(&hub, &HubKind::Session);
+
+    client.create_room(&1u32, &player_a, &100_i128);
+    client.join_room(&1u32, &player_b, &100_i128);
+    client.start_room(&1u32, &player_a, &player_b, &100_i128, &100_i128);
+    client.forfeit(&1u32, &player_b);
+    let ended = HubCall::EndedSession(game_id.clone(), 1, true);
+    assert_eq!(hub_client.calls().last(), Some(ended));
+
+    // Matching and challenges start games only one player signs, so they
+    // are refused once the hub escrows.
+    let player_c = Address::generate(&env);
+    assert_eq!(client.enter_queue(&player_a, &100_i128), None);
+    client.challenge(&player_a, &player_c, &100_i128);
+    hub_client.set_game_token(&Address::generate(&env));
+    let res = client.try_enter_queue(&player_b, &100_i128);
+    assert_eq!(res, Err(Ok(Error::HubEscrows.into())));
+    let res = client.try_challenge(&player_b, &player_c, &100_i128);
+    assert_eq!(res, Err(Ok(Error::HubEscrows.into())));
+    let res = client.try_accept_challenge(&player_a, &player_c, &100_i128);
+    assert_eq!(res, Err(Ok(Error::HubEscrows.into())));
+
+    // Other kinds of hub cannot say, and are not asked.
+    client.set_hub_kind(&hub, &HubKind::Extended);
+    assert!(client.enter_queue(&player_b, &100_i128).is_some());
+}
+
+#[test]
+fn test_failed_hub_report_is_retried() {
+    let (env, game_id, player_a, player_b, _hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+
+    client.create_room(&1u32, &player_a, &100_i128);
+    client.join_room(&1u32, &player_b, &100_i128);
+    client.start_room(&1u32, &player_a, &player_b, &100_i128, &100_i128);
+
+    // The hub goes away mid-game; the game still finishes.
+    let gone = Address::generate(&env);
+    client.set_hub(&gone);
+    client.forfeit(&1u32, &player_b);
+    assert_eq!(client.get_room(&1u32).winner, player_a);
+    let pending = PendingHubReport {
+        hub: gone.clone(),
+        report: HubReport::Ended(1, true),
+    };
+    assert_eq!(client.get_pending_hub_report(&1u32), Some(pending));
+    let res = client.try_retry_hub_report(&1u32);
+    assert_eq!(res, Err(Ok(Error::HubUnavailable.into())));
+
+    env.register_at(&gone, MockGameHub, ());
+    client.retry_hub_report(&1u32);
+    let calls = MockGameHubClient::new(&env, &gone).calls();
+    assert_eq!(calls, Vec::from_array(&env, [HubCall::Ended(1, true)]));
+    assert_eq!(client.get_pending_hub_report(&1u32), None);
+    let res = client.try_retry_hub_report(&1u32);
+    assert_eq!(res, Err(Ok(Error::NoPendingReport.into())));
+}
+
+#[test]
+fn test_exhausted_board_ends_in_draw() {
+    use crate::contract::DEFAULT_REVEAL_WINDOW_LEDGERS;
+    use soroban_sdk::xdr::FromXdr;
+
+    let (env, game_id, player_a, player_b, hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+
+    client.create_room(&1u32, &player_a, &100_i128);
+    client.join_room(&1u32, &player_b, &100_i128);
+    client.start_room(&1u32, &player_a, &player_b, &100_i128, &100_i128);
+    let salt_a = make_salt(&env, 1);
+    client.bury_treasure(&1u32, &player_a, &make_commitment(&client, 1, 0, 5, &salt_a));
+    let salt_b = make_salt(&env, 2);
+    client.bury_treasure(&1u32, &player_b, &make_commitment(&client, 1, 2, 15, &salt_b));
+
+    let stranger = Address::generate(&env);
+    let res = client.try_finalize_draw(&1u32, &stranger);
+    assert_eq!(res, Err(Ok(Error::BoardNotExhausted.into())));
+
+    // Dig out every tile without anyone revealing.
+    env.ledger().set_sequence_number(100);
+    let mut a_turn = true;
+    for (island, tiles) in [(0u32, 10u32), (1, 20), (2, 30)] {
+        for tile in 0..tiles {
+            let digger = if a_turn { &player_a } else { &player_b };
+            client.dig(&1u32, digger, &island, &tile);
+            a_turn = !a_turn;
+        }
+    }
+    assert_eq!(client.get_room(&1u32).exhausted_at, Some(100));
+
+    // Either player may now reveal regardless of turn.
+    assert!(client.get_room(&1u32).turn_is_a);
+    let res = client.try_reveal_treasure(&1u32, &player_b, &0u32, &5u32, &salt_b);
+    assert_eq!(res, Err(Ok(Error::CommitmentMismatch.into())));
+    let res = client.try_finalize_draw(&1u32, &stranger);
+    assert_eq!(res, Err(Ok(Error::RevealWindowOpen.into())));
+
+    env.ledger().set_sequence_number(100 + DEFAULT_REVEAL_WINDOW_LEDGERS);
+    let res = client.try_reveal_treasure(&1u32, &player_b, &0u32, &5u32, &salt_a);
+    assert_eq!(res, Err(Ok(Error::RevealWindowClosed.into())));
+    client.finalize_draw(&1u32, &stranger);
+    let room = client.get_room(&1u32);
+    assert_eq!(room.phase, 4);
+    assert!(!room.game_active);
+    assert_eq!(client.get_reputation(&player_a).completed, 1);
+    assert_eq!(client.get_player_stats(&player_b).draws, 1);
+    // The hub is legacy by default, so the draw closes its session with
+    // `end_game`; a hub that reported every outcome would hang otherwise.
+    let calls = MockGameHubClient::new(&env, &hub).calls();
+    assert_eq!(calls.last(), Some(HubCall::Ended(1, false)));
+
+    // A drawn room has ended as much as a won one.
+    let published = client.get_commitments(&1u32);
+    assert_eq!(published.commitment_b, Some(make_commitment(&client, 1, 2, 15, &salt_b)));
+    let encoded = client.export_attestation(&1u32);
+    assert_eq!(Attestation::from_xdr(&env, &encoded).unwrap().phase, 4);
+    let token_id = env.register_stellar_asset_contract_v2(stranger.clone()).address();
+    StellarAssetClient::new(&env, &token_id).mint(&player_a, &100);
+    client.tip(&1u32, &player_a, &token_id, &40_i128);
+    assert_eq!(TokenClient::new(&env, &token_id).balance(&player_b), 40);
+    client.report_game(&1u32, &stranger, &symbol_short!("bot"));
+    let all = Cursor { start: 0, limit: 0 };
+    assert_eq!(client.get_reports(&1u32, &all).items.len(), 1);
+}
+
+#[test]
+fn test_unopened_treasures_forfeit() {
+    use crate::contract::DEFAULT_REVEAL_WINDOW_LEDGERS;
+
+    let (env, game_id, player_a, player_b, _hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+
+    let config = RoomConfig {
+        commitment_scheme: CommitmentScheme::Sha256,
+        dig_mode: DigMode::Alternating,
+        roles: Roles::Duel,
+        dig_budget: 0,
+        hider_odds_bps: 0,
+        turn_timeout_ledgers: 0,
+        bury_timeout_ledgers: 0,
+        reveal_window_ledgers: 0,
+        action_points: 0,
+        min_completed: 0,
+        min_score_bps: 0,
+        event_verbosity: EventVerbosity::Default,
+        signed_burials: false,
+        derived_salts: false,
+        stake_token: None,
+        treasures: 2,
+        guess_mode: false,
+        invite_hash: None,
+        hub: None,
+        hubless: false,
+    };
+    client.create_room_with_config(&1u32, &player_a, &100_i128, &config);
+    client.join_room(&1u32, &player_b, &100_i128);
+    client.start_room(&1u32, &player_a, &player_b, &100_i128, &100_i128);
+
+    // Player B's root holds only one real leaf, so it can never be opened.
+    let a_leaves = [make_leaf(&env, 0, 1, 1), make_leaf(&env, 1, 2, 2)];
+    let commit = |leaf: &TreasureLeaf| {
+        make_commitment(&client, 1, leaf.island_id, leaf.tile_id, &leaf.salt)
+    };
+    let a_root = PiratesTreasure::merkle_parent(&env, &commit(&a_leaves[0]), &commit(&a_leaves[1]));
+    client.bury_treasure(&1u32, &player_a, &a_root);
+    let b_leaf = make_leaf(&env, 2, 3, 3);
+    let b_root = PiratesTreasure::merkle_parent(&env, &commit(&b_leaf), &make_salt(&env, 9));
+    client.bury_treasure(&1u32, &player_b, &b_root);
+
+    let a_opening = Vec::from_array(&env, a_leaves);
+    let res = client.try_open_treasures(&1u32, &player_a, &a_opening);
+    assert_eq!(res, Err(Ok(Error::BoardNotExhausted.into())));
+
+    env.ledger().set_sequence_number(100);
+    let mut a_turn = true;
+    for (island, tiles) in [(0u32, 10u32), (1, 20), (2, 30)] {
+        for tile in 0..tiles {
+            let digger = if a_turn { &player_a } else { &player_b };
+            client.dig(&1u32, digger, &island, &tile);
+            a_turn = !a_turn;
+        }
+    }
+    let res = client.try_open_treasures(&1u32, &player_a, &a_opening);
+    assert_eq!(res, Err(Ok(Error::RevealWindowOpen.into())));
+
+    env.ledger().set_sequence_number(100 + DEFAULT_REVEAL_WINDOW_LEDGERS);
+    client.open_treasures(&1u32, &player_a, &a_opening);
+    let b_opening = Vec::from_array(&env, [b_leaf.clone(), make_leaf(&env, 2, 4, 9)]);
+    let res = client.try_open_treasures(&1u32, &player_b, &b_opening);
+    assert_eq!(res, Err(Ok(Error::BadOpening.into())));
+
+    // B gets a second window to open before forfeiting.
+    let stranger = Address::generate(&env);
+    let res = client.try_finalize_draw(&1u32, &stranger);
+    assert_eq!(res, Err(Ok(Error::RevealWindowOpen.into())));
+    env.ledger().set_sequence_number(100 + 2 * DEFAULT_REVEAL_WINDOW_LEDGERS);
+    client.finalize_draw(&1u32, &stranger);
+    let room = client.get_room(&1u32);
+    assert_eq!(room.phase, 3);
+    assert_eq!(room.winner, player_a);
+    assert_eq!(client.get_recent_events(&1u32).last().unwrap().kind, symbol_short!("unopened"));
+}
+
+#[test]
+fn test_claim_timeout() {
+    use crate::contract::DEFAULT_TURN_TIMEOUT_LEDGERS;
+
+    let (env, game_id, player_a, player_b, _hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+
+    client.create_room(&1u32, &player_a, &100_i128);
+    client.join_room(&1u32, &player_b, &100_i128);
+    client.start_room(&1u32, &player_a, &player_b, &100_i128, &100_i128);
+    let salt_a = make_salt(&env, 1);
+    client.bury_treasure(&1u32, &player_a, &make_commitment(&client, 1, 0, 5, &salt_a));
+    let salt_b = make_salt(&env, 2);
+    client.bury_treasure(&1u32, &player_b, &make_commitment(&client, 1, 2, 15, &salt_b));
+
+    env.ledger().set_sequence_number(100);
+    client.dig(&1u32, &player_a, &0u32, &1u32);
+    assert_eq!(client.get_room(&1u32).turn_deadline, 100 + DEFAULT_TURN_TIMEOUT_LEDGERS);
+
+    // Player B is on turn: only Player A may claim, and only once B is late.
+    env.ledger().set_sequence_number(100 + DEFAULT_TURN_TIMEOUT_LEDGERS);
+    let res = client.try_claim_timeout(&1u32, &player_a);
+    assert_eq!(res, Err(Ok(Error::TurnNotExpired.into())));
+    env.ledger().set_sequence_number(101 + DEFAULT_TURN_TIMEOUT_LEDGERS);
+    let res = client.try_claim_timeout(&1u32, &player_b);
+    assert_eq!(res, Err(Ok(Error::TurnNotExpired.into())));
+
+    client.claim_timeout(&1u32, &player_a);
+    let room = client.get_room(&1u32);
+    assert_eq!(room.phase, 3);
+    assert_eq!(room.winner, player_a);
+    assert_eq!(client.get_reputation(&player_b).completed, 0);
+    assert_eq!(client.get_recent_events(&1u32).last().unwrap().kind, symbol_short!("timeout"));
+}
+
+#[test]
+fn test_forfeit() {
+    let (env, game_id, player_a, player_b, _hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+
+    client.create_room(&1u32, &player_a, &100_i128);
+    client.join_room(&1u32, &player_b, &100_i128);
+    let res = client.try_forfeit(&1u32, &player_a);
+    assert_eq!(res, Err(Ok(Error::WrongPhaseExpectedPlaying.into())));
+
+    client.start_room(&1u32, &player_a, &player_b, &100_i128, &100_i128);
+    let stranger = Address::generate(&env);
+    let res = client.try_forfeit(&1u32, &stranger);
+    assert_eq!(res, Err(Ok(Error::NotAPlayer.into())));
+
+    // Conceding mid-burial hands the game to the opponent.
+    client.forfeit(&1u32, &player_a);
+    let room = client.get_room(&1u32);
+    assert_eq!(room.phase, 3);
+    assert!(!room.game_active);
+    assert_eq!(room.winner, player_b);
+    assert_eq!(client.get_recent_events(&1u32).last().unwrap().kind, symbol_short!("forfeited"));
+
+    let res = client.try_forfeit(&1u32, &player_b);
+    assert_eq!(res, Err(Ok(Error::WrongPhaseExpectedPlaying.into())));
+}
+
+#[test]
+fn test_cancel_room() {
+    let (env, game_id, player_a, player_b, _hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+
+    client.create_room(&1u32, &player_a, &100_i128);
+    client.join_room(&1u32, &player_b, &100_i128);
+    let res = client.try_cancel_room(&1u32, &player_b);
+    assert_eq!(res, Err(Ok(Error::NotPlayerA.into())));
+
+    client.cancel_room(&1u32, &player_a);
+    let res = client.try_get_room(&1u32);
+    assert_eq!(res, Err(Ok(Error::RoomNotFound.into())));
+    assert_eq!(client.get_storage_report(&1u32).entries, 0);
+
+    // The id is free again; a started room can no longer be cancelled.
+    client.create_room(&1u32, &player_a, &100_i128);
+    client.join_room(&1u32, &player_b, &100_i128);
+    client.start_room(&1u32, &player_a, &player_b, &100_i128, &100_i128);
+    let res = client.try_cancel_room(&1u32, &player_a);
+    assert_eq!(res, Err(Ok(Error::WrongPhaseExpectedWaiting.into())));
+}
+
+#[test]
+fn test_claim_abandoned() {
+    use crate::contract::DEFAULT_BURY_TIMEOUT_LEDGERS;
+
+    let (env, game_id, player_a, player_b, _hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+
+    env.ledger().set_sequence_number(100);
+    client.create_room(&1u32, &player_a, &100_i128);
+    client.join_room(&1u32, &player_b, &100_i128);
+    client.start_room(&1u32, &player_a, &player_b, &100_i128, &100_i128);
+    let salt_a = make_salt(&env, 1);
+    client.bury_treasure(&1u32, &player_a, &make_commitment(&client, 1, 0, 5, &salt_a));
+
+    env.ledger().set_sequence_number(100 + DEFAULT_BURY_TIMEOUT_LEDGERS);
+    let res = client.try_claim_abandoned(&1u32, &player_a);
+    assert_eq!(res, Err(Ok(Error::BurialWindowOpen.into())));
+
+    // Only the player who buried may claim.
+    env.ledger().set_sequence_number(101 + DEFAULT_BURY_TIMEOUT_LEDGERS);
+    let res = client.try_claim_abandoned(&1u32, &player_b);
+    assert_eq!(res, Err(Ok(Error::CommitmentMissing.into())));
+
+    client.claim_abandoned(&1u32, &player_a);
+    let room = client.get_room(&1u32);
+    assert_eq!(room.phase, 3);
+    assert_eq!(room.winner, player_a);
+    assert_eq!(client.get_recent_events(&1u32).last().unwrap().kind, symbol_short!("abandoned"));
+}
+
+#[test]
+fn test_multiple_treasures() {
+    let (env, game_id, player_a, player_b, _hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+
+    let config = RoomConfig {
+        commitment_scheme: CommitmentScheme::Sha256,
+        dig_mode: DigMode::Alternating,
+        roles: Roles::Duel,
+        dig_budget: 0,
+        hider_odds_bps: 0,
+        turn_timeout_ledgers: 0,
+        bury_timeout_ledgers: 0,
+        reveal_window_ledgers: 0,
+        action_points: 0,
+        min_completed: 0,
+        min_score_bps: 0,
+        event_verbosity: EventVerbosity::Default,
+        signed_burials: false,
+        derived_salts: false,
+        stake_token: None,
+        treasures: 3,
+        guess_mode: false,
+        invite_hash: None,
+        hub: None,
+        hubless: false,
+    };
+    client.create_room_with_config(&1u32, &player_a, &100_i128, &config);
+    client.join_room(&1u32, &player_b, &100_i128);
+    client.start_room(&1u32, &player_a, &player_b, &100_i128, &100_i128);
+
+    // Three treasures per player: root = H(H(t0, t1), t2).
+    let bury = |player: &Address, tiles: [(u32, u32); 3], seed: u8| {
+        let leaves = tiles.map(|(island, tile)| {
+            make_commitment(&client, 1, island, tile, &make_salt(&env, seed + tile as u8))
+        });
+        let pair = PiratesTreasure::merkle_parent(&env, &leaves[0], &leaves[1]);
+        let root = PiratesTreasure::merkle_parent(&env, &pair, &leaves[2]);
+        client.bury_treasure(&1u32, player, &root);
+        (leaves, pair)
+    };
+    let _ = bury(&player_a, [(0, 1), (1, 2), (2, 3)], 10);
+    let (b_leaves, b_pair) = bury(&player_b, [(0, 4), (1, 5), (2, 6)], 20);
+
+    // The single-treasure reveal does not apply here.
+    let res = client.try_reveal_treasure(&1u32, &player_a, &0u32, &4u32, &make_salt(&env, 24));
+    assert_eq!(res, Err(Ok(Error::WrongMode.into())));
+
+    let none = Vec::new(&env);
+    let proof = Vec::from_array(&env, [b_leaves[1].clone(), b_leaves[2].clone()]);
+    let salt = make_salt(&env, 24);
+    client.reveal_treasure_proof(&1u32, &player_a, &0u32, &4u32, &salt, &proof, &none);
+    assert_eq!(client.get_room(&1u32).phase, 2);
+    assert_eq!(client.get_recent_events(&1u32).last().unwrap().kind, symbol_short!("found"));
+    let res =
+        client.try_reveal_treasure_proof(&1u32, &player_a, &0u32, &4u32, &salt, &proof, &none);
+    assert_eq!(res, Err(Ok(Error::AlreadyFound.into())));
+
+    // A second find is a majority of three, but only wins along with an
+    // opening of the finder's own three treasures.
+    let proof = Vec::from_array(&env, [b_pair]);
+    let salt = make_salt(&env, 26);
+    let a_leaves =
+        [make_leaf(&env, 0, 1, 11), make_leaf(&env, 1, 2, 12), make_leaf(&env, 2, 3, 13)];
+    let short = Vec::from_array(&env, [a_leaves[0].clone(), a_leaves[1].clone()]);
+    let repeated = Vec::from_array(
+        &env,
+        [a_leaves[0].clone(), a_leaves[1].clone(), a_leaves[1].clone()],
+    );
+    let reordered = Vec::from_array(
+        &env,
+        [a_leaves[2].clone(), a_leaves[1].clone(), a_leaves[0].clone()],
+    );
+    for opening in [none, short, repeated, reordered] {
+        let res = client
+            .try_reveal_treasure_proof(&1u32, &player_a, &2u32, &6u32, &salt, &proof, &opening);
+        assert_eq!(res, Err(Ok(Error::BadOpening.into())));
+    }
+    let opening = Vec::from_array(&env, a_leaves);
+    client.reveal_treasure_proof(&1u32, &player_a, &2u32, &6u32, &salt, &proof, &opening);
+    let room = client.get_room(&1u32);
+    assert_eq!(room.phase, 3);
+    assert_eq!(room.winner, player_a);
+}
+
+#[test]
+fn test_guess_mode() {
+    let (env, game_id, player_a, player_b, _hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+
+    let config = RoomConfig {
+        commitment_scheme: CommitmentScheme::Sha256,
+        dig_mode: DigMode::Alternating,
+        roles: Roles::Duel,
+        dig_budget: 0,
+        hider_odds_bps: 0,
+        turn_timeout_ledgers: 0,
+        bury_timeout_ledgers: 0,
+        reveal_window_ledgers: 0,
+        action_points: 0,
+        min_completed: 0,
+        min_score_bps: 0,
+        event_verbosity: EventVerbosity::Default,
+        signed_burials: false,
+        derived_salts: false,
+        stake_token: None,
+        treasures: 0,
+        guess_mode: true,
+        invite_hash: None,
+        hub: None,
+        hubless: false,
+    };
+    client.create_room_with_config(&1u32, &player_a, &100_i128, &config);
+    client.join_room(&1u32, &player_b, &100_i128);
+    client.start_room(&1u32, &player_a, &player_b, &100_i128, &100_i128);
+    let salt_a = make_salt(&env, 1);
+    client.bury_treasure(&1u32, &player_a, &make_commitment(&client, 1, 0, 5, &salt_a));
+    let salt_b = make_salt(&env, 2);
+    client.bury_treasure(&1u32, &player_b, &make_commitment(&client, 1, 2, 15, &salt_b));
+
+    // A wrong guess digs the tile and hands the turn over.
+    client.reveal_treasure(&1u32, &player_a, &1u32, &3u32, &salt_b);
+    let room = client.get_room(&1u32);
+    assert_eq!(room.phase, 2);
+    assert!(!room.turn_is_a);
+    assert_eq!(room.digs.len(), 1);
+    assert_eq!(room.digs.get(0).unwrap().tile_id, 3);
+    assert_eq!(client.get_recent_events(&1u32).last().unwrap().kind, symbol_short!("missed"));
+
+    client.reveal_treasure(&1u32, &player_b, &0u32, &5u32, &salt_a);
+    assert_eq!(client.get_room(&1u32).winner, player_b);
+}
+
+#[test]
+fn test_open_rooms() {
+    let (env, game_id, player_a, player_b, _hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+    let all = Cursor { start: 0, limit: 0 };
+
+    for room_id in 1..=3u32 {
+        client.create_room(&room_id, &player_a, &100_i128);
+    }
+    let page = client.get_open_rooms(&Cursor { start: 0, limit: 2 });
+    assert_eq!(page.items.len(), 2);
+    assert_eq!(page.items.get(0).unwrap().room_id, 1);
+    assert_eq!(page.next_cursor, Some(2));
+
+    // Joined and cancelled rooms leave the lobby.
+    client.join_room(&2u32, &player_b, &100_i128);
+    client.cancel_room(&3u32, &player_a);
+    let page = client.get_open_rooms(&all);
+    assert_eq!(page.items.len(), 1);
+    assert_eq!(page.items.get(0).unwrap().room_id, 1);
+    assert_eq!(page.next_cursor, None);
+}
+
+#[test]
+fn test_open_rooms_are_capped() {
+    use crate::contract::MAX_OPEN_ROOMS;
+
+    let (env, game_id, player_a, player_b, _hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+
+    // A full lobby drops its oldest room for the newest.
+    for room_id in 1..=MAX_OPEN_ROOMS + 1 {
+        client.create_room(&room_id, &player_a, &100_i128);
+    }
+    let page = client.get_open_rooms(&Cursor { start: 0, limit: 1 });
+    assert_eq!(page.items.get(0).unwrap().room_id, 2);
+    let last = Cursor { start: MAX_OPEN_ROOMS - 1, limit: 0 };
+    let page = client.get_open_rooms(&last);
+    assert_eq!(page.items.get(0).unwrap().room_id, MAX_OPEN_ROOMS + 1);
+    assert_eq!(page.next_cursor, None);
+
+    // The dropped room is still there to join by id.
+    client.join_room(&1u32, &player_b, &100_i128);
+}
+
+#[test]
+fn test_rooms_by_player() {
+    let (env, game_id, player_a, player_b, _hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+
+    client.create_room(&1u32, &player_a, &100_i128);
+    client.create_room(&2u32, &player_a, &100_i128);
+    client.join_room(&2u32, &player_b, &100_i128);
+    let all = Cursor { start: 0, limit: 0 };
+    let page = client.get_rooms_by_player(&player_a, &all);
+    assert_eq!(page.items, Vec::from_array(&env, [1u32, 2]));
+    assert_eq!(page.next_cursor, None);
+    assert_eq!(client.get_rooms_by_player(&player_b, &all).items, Vec::from_array(&env, [2u32]));
+    let first = client.get_rooms_by_player(&player_a, &Cursor { start: 0, limit: 1 });
+    assert_eq!(first.items, Vec::from_array(&env, [1u32]));
+    assert_eq!(first.next_cursor, Some(1));
+
+    // Ended and cancelled rooms drop out.
+    client.start_room(&2u32, &player_a, &player_b, &100_i128, &100_i128);
+    client.forfeit(&2u32, &player_b);
+    client.cancel_room(&1u32, &player_a);
+    assert!(client.get_rooms_by_player(&player_a, &all).items.is_empty());
+    assert!(client.get_rooms_by_player(&player_b, &all).items.is_empty());
+}
+
+#[test]
+fn test_create_room_auto() {
+    let (env, game_id, player_a, player_b, _hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+
+    assert_eq!(client.create_room_auto(&player_a, &100_i128), 1);
+    // An id taken by hand is skipped.
+    client.create_room(&2u32, &player_b, &100_i128);
+    assert_eq!(client.create_room_auto(&player_b, &50_i128), 3);
+
+    let room = client.get_room(&3u32);
+    assert_eq!(room.player_a, player_b);
+    assert_eq!(room.player_a_points, 50);
+}
+
+#[test]
+fn test_matchmaking_queue() {
+    let (env, game_id, player_a, player_b, _hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+
+    assert_eq!(client.enter_queue(&player_a, &100_i128), None);
+    assert_eq!(client.get_queue(&120_i128).unwrap().player, player_a);
+    let res = client.try_enter_queue(&player_a, &110_i128);
+    assert_eq!(res, Err(Ok(Error::AlreadyQueued.into())));
+
+    // Stakes more than twice apart wait in different queues.
+    let player_c = Address::generate(&env);
+    assert_eq!(client.enter_queue(&player_c, &1_000_i128), None);
+
+    let room_id = client.enter_queue(&player_b, &120_i128).unwrap();
+    let room = client.get_room(&room_id);
+    assert_eq!((room.player_a, room.player_b), (player_a, player_b.clone()));
+    assert_eq!((room.player_a_points, room.player_b_points), (100, 120));
+    assert_eq!(room.phase, 1);
+    assert_eq!(client.get_queue(&100_i128), None);
+
+    client.leave_queue(&player_c, &1_000_i128);
+    let res = client.try_leave_queue(&player_c, &1_000_i128);
+    assert_eq!(res, Err(Ok(Error::NotQueued.into())));
+}
+
+#[test]
+fn test_challenge_flow() {
+    let (env, game_id, player_a, player_b, _hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+
+    client.challenge(&player_a, &player_b, &100_i128);
+    assert_eq!(client.get_challenge(&player_a, &player_b), Some(100));
+    let res = client.try_challenge(&player_a, &player_b, &200_i128);
+    assert_eq!(res, Err(Ok(Error::ChallengeExists.into())));
+
+    client.decline_challenge(&player_a, &player_b);
+    assert_eq!(client.get_challenge(&player_a, &player_b), None);
+    let res = client.try_accept_challenge(&player_a, &player_b, &50_i128);
+    assert_eq!(res, Err(Ok(Error::ChallengeNotFound.into())));
+
+    client.challenge(&player_a, &player_b, &100_i128);
+    let room = client.accept_challenge(&player_a, &player_b, &50_i128);
+    assert_eq!((room.player_a, room.player_b), (player_a.clone(), player_b.clone()));
+    assert_eq!((room.player_a_points, room.player_b_points), (100, 50));
+    assert_eq!(room.phase, 1);
+    assert_eq!(client.get_challenge(&player_a, &player_b), None);
+}
+
+#[test]
+fn test_pause_blocks_new_rooms_only() {
+    let (env, game_id, player_a, player_b, _hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+
+    client.create_room(&1u32, &player_a, &100_i128);
+    client.join_room(&1u32, &player_b, &100_i128);
+    client.start_room(&1u32, &player_a, &player_b, &100_i128, &100_i128);
+    client.create_room(&2u32, &player_a, &100_i128);
+    client.join_room(&2u32, &player_b, &100_i128);
+
+    client.pause();
+    assert!(client.is_paused());
+    assert!(client.get_config().paused);
+    let res = client.try_create_room(&3u32, &player_a, &100_i128);
+    assert_eq!(res, Err(Ok(Error::Paused.into())));
+    let res = client.try_start_room(&2u32, &player_a, &player_b, &100_i128, &100_i128);
+    assert_eq!(res, Err(Ok(Error::Paused.into())));
+
+    // The game already under way carries on.
+    let salt = make_salt(&env, 1);
+    let commitment = make_commitment(&client, 1, 0, 0, &salt);
+    client.bury_treasure(&1u32, &player_a, &commitment);
+
+    client.unpause();
+    client.start_room(&2u32, &player_a, &player_b, &100_i128, &100_i128);
+
+    // The flag round-trips through the config as well.
+    client.set_config(&Config {
+        paused: true,
+        ..client.get_config()
+    });
+    assert!(client.is_paused());
+}
+
+#[test]
+fn test_private_room() {
+    use soroban_sdk::Bytes;
+
+    let (env, game_id, player_a, player_b, _hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+
+    let code = Bytes::from_slice(&env, b"ahoy");
+    let config = RoomConfig {
+        commitment_scheme: CommitmentScheme::Sha256,
+        dig_mode: DigMode::Alternating,
+        roles: Roles::Duel,
+        dig_budget: 0,
+        hider_odds_bps: 0,
+        turn_timeout_ledgers: 0,
+        bury_timeout_ledgers: 0,
+        reveal_window_ledgers: 0,
+        action_points: 0,
+        min_completed: 0,
+        min_score_bps: 0,
+        event_verbosity: EventVerbosity::Default,
+        signed_burials: false,
+        derived_salts: false,
+        stake_token: None,
+        treasures: 0,
+        guess_mode: false,
+        invite_hash: Some(env.crypto().sha256(&code).to_bytes()),
+        hub: None,
+        hubless: false,
+    };
+    client.create_room_with_config(&1u32, &player_a, &100_i128, &config);
+    assert!(client.get_open_rooms(&Cursor { start: 0, limit: 0 }).items.is_empty());
+
+    let res = client.try_join_room(&1u32, &player_b, &100_i128);
+    assert_eq!(res, Err(Ok(Error::BadInviteCode.into())));
+    let wrong = Bytes::from_slice(&env, b"avast");
+    let res = client.try_join_private_room(&1u32, &player_b, &100_i128, &wrong);
+    assert_eq!(res, Err(Ok(Error::BadInviteCode.into())));
+
+    client.join_private_room(&1u32, &player_b, &100_i128, &code);
+    assert_eq!(client.get_room(&1u32).player_b, player_b);
+}
+
+#[test]
+fn test_room_chooses_approved_hub() {
+    let (env, game_id, player_a, player_b, hub_id) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+
+    let other_hub = env.register(MockGameHub, ());
+    let config = RoomConfig {
+        commitment_scheme: CommitmentScheme::Sha256,
+        dig_mode: DigMode::Alternating,
+        roles: Roles::Duel,
+        dig_budget: 0,
+        hider_odds_bps: 0,
+        turn_timeout_ledgers: 0,
+        bury_timeout_ledgers: 0,
+        reveal_window_ledgers: 0,
+        action_points: 0,
+        min_completed: 0,
+        min_score_bps: 0,
+        event_verbosity: EventVerbosity::Default,
+        signed_burials: false,
+        derived_salts: false,
+        stake_token: None,
+        treasures: 0,
+        guess_mode: false,
+        invite_hash: None,
+        hub: Some(other_hub.clone()),
+        hubless: false,
+    };
+    let res = client.try_create_room_with_config(&1u32, &player_a, &100_i128, &config);
+    assert_eq!(res, Err(Ok(Error::HubNotApproved.into())));
+
+    client.approve_hub(&other_hub);
+    assert_eq!(client.get_approved_hubs(), Vec::from_array(&env, [other_hub.clone()]));
+    client.create_room_with_config(&1u32, &player_a, &100_i128, &config);
+    client.join_room(&1u32, &player_b, &100_i128);
+    client.start_room(&1u32, &player_a, &player_b, &100_i128, &100_i128);
+    let calls = MockGameHubClient::new(&env, &other_hub).calls();
+    assert_eq!(calls, Vec::from_array(&env, [HubCall::Started(1)]));
+    assert!(MockGameHubClient::new(&env, &hub_id).calls().is_empty());
+
+    client.revoke_hub(&other_hub);
+    assert!(client.get_approved_hubs().is_empty());
+}
+
+#[test]
+fn test_standalone_without_hub() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let game_id = env.register(PiratesTreasure, (&admin, None::<Address>));
+    let client = PiratesTreasureClient::new(&env, &game_id);
+    let player_a = Address::generate(&env);
+    let player_b = Address::generate(&env);
+    assert_eq!(client.get_hub(), None);
+
+    client.create_room(&1u32, &player_a, &100_i128);
+    client.join_room(&1u32, &player_b, &100_i128);
+    client.start_room(&1u32, &player_a, &player_b, &100_i128, &100_i128);
+    assert!(client.get_room(&1u32).hubless);
+
+    // A hub set mid-game never hears of a session it did not open.
+    let hub = env.register(MockGameHub, ());
+    client.set_hub(&hub);
+    client.forfeit(&1u32, &player_b);
+    assert!(MockGameHubClient::new(&env, &hub).calls().is_empty());
+    assert_eq!(client.get_room(&1u32).winner, player_a);
+}
+
+#[test]
+fn test_hubless_room() {
+    let (env, game_id, player_a, player_b, hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+
+    let mut config = RoomConfig {
+        commitment_scheme: CommitmentScheme::Sha256,
+        dig_mode: DigMode::Alternating,
+        roles: Roles::Duel,
+        dig_budget: 0,
+        hider_odds_bps: 0,
+        turn_timeout_ledgers: 0,
+        bury_timeout_ledgers: 0,
+        reveal_window_ledgers: 0,
+        action_points: 0,
+        min_completed: 0,
+        min_score_bps: 0,
+        event_verbosity: EventVerbosity::Default,
+        signed_burials: false,
+        derived_salts: false,
+        stake_token: None,
+        treasures: 0,
+        guess_mode: false,
+        invite_hash: None,
+        hub: Some(hub.clone()),
+        hubless: true,
+    };
+    let res = client.try_create_room_with_config(&1u32, &player_a, &100_i128, &config);
+    assert_eq!(res, Err(Ok(Error::InvalidConfig.into())));
+
+    config.hub = None;
+    client.create_room_with_config(&1u32, &player_a, &100_i128, &config);
+    client.join_room(&1u32, &player_b, &100_i128);
+    client.start_room(&1u32, &player_a, &player_b, &100_i128, &100_i128);
+    assert!(MockGameHubClient::new(&env, &hub).calls().is_empty());
+}
+
+#[test]
+fn test_series() {
+    let (env, game_id, player_a, player_b, _hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+    let config = RoomConfig {
+        commitment_scheme: CommitmentScheme::Sha256,
+        dig_mode: DigMode::Alternating,
+        roles: Roles::Duel,
+        dig_budget: 0,
+        hider_odds_bps: 0,
+        turn_timeout_ledgers: 0,
+        bury_timeout_ledgers: 0,
+        reveal_window_ledgers: 0,
+        action_points: 0,
+        min_completed: 0,
+        min_score_bps: 0,
+        event_verbosity: EventVerbosity::Default,
+        signed_burials: false,
+        derived_salts: false,
+        stake_token: None,
+        treasures: 0,
+        guess_mode: false,
+        invite_hash: None,
+        hub: None,
+        hubless: false,
+    };
+
+    // Best of three.
+    client.create_series(&7u32, &player_a, &player_b, &2u32);
+    let room = client.create_series_room(&7u32, &10u32, &100_i128, &100_i128, &config);
+    assert_eq!(room.player_b, player_b);
+    assert_eq!(room.series_id, Some(7));
+    let res = client.try_create_series_room(&7u32, &11u32, &100_i128, &100_i128, &config);
+    assert_eq!(res, Err(Ok(Error::SeriesGameLive.into())));
+
+    client.start_room(&10u32, &player_a, &player_b, &100_i128, &100_i128);
+    client.forfeit(&10u32, &player_b);
+    let series = client.get_series(&7u32);
+    assert_eq!((series.wins_a, series.wins_b), (1, 0));
+    assert_eq!(series.session_id, Some(10));
+    assert_eq!(series.winner, None);
+
+    client.create_series_room(&7u32, &11u32, &100_i128, &100_i128, &config);
+    client.start_room(&11u32, &player_a, &player_b, &100_i128, &100_i128);
+    client.forfeit(&11u32, &player_b);
+    let series = client.get_series(&7u32);
+    assert_eq!(series.winner, Some(player_a.clone()));
+    assert_eq!(series.rooms, Vec::from_array(&env, [10u32, 11]));
+
+    let res = client.try_create_series_room(&7u32, &12u32, &100_i128, &100_i128, &config);
+    assert_eq!(res, Err(Ok(Error::SeriesOver.into())));
+}
+
+#[test]
+fn test_rematch() {
+    let (env, game_id, player_a, player_b, _hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+
+    client.create_room(&1u32, &player_a, &100_i128);
+    client.join_room(&1u32, &player_b, &250_i128);
+    let res = client.try_rematch(&1u32);
+    assert_eq!(res, Err(Ok(Error::WrongPhaseExpectedEnded.into())));
+
+    client.start_room(&1u32, &player_a, &player_b, &100_i128, &250_i128);
+    client.forfeit(&1u32, &player_a);
+
+    let room = client.rematch(&1u32);
+    assert_eq!(room.room_id, 2);
+    assert_eq!(room.rematch_of, Some(1));
+    assert_eq!(room.phase, 1);
+    assert_eq!((room.player_a, room.player_b), (player_a, player_b));
+    assert_eq!((room.player_a_points, room.player_b_points), (100, 250));
+}
+
+#[test]
+fn test_spectator_bets() {
+    let (env, game_id, player_a, player_b, _hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+
+    let token_admin = Address::generate(&env);
+    let token_id = env.register_stellar_asset_contract_v2(token_admin).address();
+    let sac = StellarAssetClient::new(&env, &token_id);
+    let token = TokenClient::new(&env, &token_id);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let carol = Address::generate(&env);
+    for who in [&player_a, &player_b, &alice, &bob, &carol] {
+        sac.mint(who, &1_000);
+    }
+
+    let config = RoomConfig {
+        commitment_scheme: CommitmentScheme::Sha256,
+        dig_mode: DigMode::Alternating,
+        roles: Roles::Duel,
+        dig_budget: 0,
+        hider_odds_bps: 0,
+        turn_timeout_ledgers: 0,
+        bury_timeout_ledgers: 0,
+        reveal_window_ledgers: 0,
+        action_points: 0,
+        min_completed: 0,
+        min_score_bps: 0,
+        event_verbosity: EventVerbosity::Default,
+        signed_burials: false,
+        derived_salts: false,
+        stake_token: Some(token_id.clone()),
+        treasures: 0,
+        guess_mode: false,
+        invite_hash: None,
+        hub: None,
+        hubless: false,
+    };
+    client.create_room_with_config(&1u32, &player_a, &100_i128, &config);
+    client.join_room(&1u32, &player_b, &100_i128);
+    let res = client.try_place_bet(&1u32, &alice, &true, &100_i128);
+    assert_eq!(res, Err(Ok(Error::WrongPhaseExpectedPlaying.into())));
+    client.start_room(&1u32, &player_a, &player_b, &100_i128, &100_i128);
+
+    let res = client.try_place_bet(&1u32, &player_a, &true, &100_i128);
+    assert_eq!(res, Err(Ok(Error::BettorIsPlayer.into())));
+    client.place_bet(&1u32, &alice, &true, &100_i128);
+    client.place_bet(&1u32, &bob, &true, &300_i128);
+    client.place_bet(&1u32, &carol, &false, &400_i128);
+    let res = client.try_place_bet(&1u32, &carol, &true, &10_i128);
+    assert_eq!(res, Err(Ok(Error::BetSideMismatch.into())));
+    let book = client.get_bet_book(&1u32);
+    assert_eq!((book.total_a, book.total_b), (400, 400));
+
+    // Room fixtures carry the book and every bet.
+    let fixture = crate::testutils::dump_room(&env, &game_id, 1);
+    assert_eq!(fixture.get::<BetBook>(&DataKey::BetBook(1)), Some(book));
+    let bets = fixture.entries.keys().iter().filter(|k| matches!(k, DataKey::Bet(..))).count();
+    assert_eq!(bets, 3);
+    let bet: Bet = fixture.get(&DataKey::Bet(1, carol.clone())).unwrap();
+    assert_eq!(bet.amount, 400);
+
+    client.forfeit(&1u32, &player_b);
+
+    // Player A's backers split the 800 book 1:3.
+    assert_eq!(client.claim_bet(&1u32, &alice), 200);
+    assert_eq!(client.claim_bet(&1u32, &bob), 600);
+    assert_eq!(token.balance(&alice), 1_100);
+    let res = client.try_claim_bet(&1u32, &alice);
+    assert_eq!(res, Err(Ok(Error::NothingToClaim.into())));
+    let res = client.try_claim_bet(&1u32, &carol);
+    assert_eq!(res, Err(Ok(Error::NothingToClaim.into())));
+
+    // Nobody backed the winner, so the losing side gets its stakes back.
+    client.create_room_with_config(&2u32, &player_a, &100_i128, &config);
+    client.join_room(&2u32, &player_b, &100_i128);
+    client.start_room(&2u32, &player_a, &player_b, &100_i128, &100_i128);
+    client.place_bet(&2u32, &carol, &false, &50_i128);
+    client.forfeit(&2u32, &player_b);
+    assert_eq!(client.claim_bet(&2u32, &carol), 50);
+    assert_eq!(token.balance(&carol), 600);
+}
+
+#[test]
+fn test_elo_rating() {
+    use crate::contract::INITIAL_RATING;
+
+    let (env, game_id, player_a, player_b, _hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+    assert_eq!(client.get_rating(&player_a), INITIAL_RATING);
+
+    for room_id in 1..=2u32 {
+        client.create_room(&room_id, &player_a, &100_i128);
+        client.join_room(&room_id, &player_b, &100_i128);
+        client.start_room(&room_id, &player_a, &player_b, &100_i128, &100_i128);
+        client.forfeit(&room_id, &player_b);
+    }
+    // Even odds move 16 points; the favourite then gains less.
+    assert_eq!(client.get_rating(&player_a), 1_216 + 14);
+    assert_eq!(client.get_rating(&player_b), 1_184 - 14);
+}
+
+#[test]
+fn test_player_stats() {
+    let (env, game_id, player_a, player_b, _hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+
+    client.create_room(&1u32, &player_a, &100_i128);
+    client.join_room(&1u32, &player_b, &250_i128);
+    client.start_room(&1u32, &player_a, &player_b, &100_i128, &250_i128);
+    client.forfeit(&1u32, &player_b);
+
+    let stats = client.get_player_stats(&player_a);
+    assert_eq!((stats.games, stats.wins, stats.losses), (1, 1, 0));
+    assert_eq!(stats.total_points_won, 250);
+    let stats = client.get_player_stats(&player_b);
+    assert_eq!((stats.games, stats.wins, stats.losses), (1, 0, 1));
+    assert_eq!(stats.total_points_won, 0);
+}
+
+#[test]
+fn test_leaderboard_reporting() {
+    let (env, game_id, player_a, player_b, _hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+
+    let lb_admin = Address::generate(&env);
+    let lb_id = env.register(leaderboard::Leaderboard, (&lb_admin,));
+    let lb = leaderboard::LeaderboardClient::new(&env, &lb_id);
+    lb.set_game(&game_id);
+    client.set_leaderboard(&lb_id);
+    assert_eq!(client.get_leaderboard(), Some(lb_id));
+
+    client.create_room(&1u32, &player_a, &100_i128);
+    client.join_room(&1u32, &player_b, &250_i128);
+    client.start_room(&1u32, &player_a, &player_b, &100_i128, &250_i128);
+    client.forfeit(&1u32, &player_b);
+
+    let entry = lb.get_entry(&player_a);
+    assert_eq!((entry.wins, entry.points), (1, 250));
+    assert_eq!(lb.get_top_by_wins().get(0).unwrap().player, player_a);
+}
+
+#[test]
+fn test_storage_report() {
+    let (env, game_id, player_a, player_b, _hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+
+    client.create_room(&1u32, &player_a, &100_i128);
+    // The room itself plus its event outbox.
+    let created = client.get_storage_report(&1u32);
+    assert_eq!(created.entries, 2);
+
+    client.join_room(&1u32, &player_b, &100_i128);
+    client.start_room(&1u32, &player_a, &player_b, &100_i128, &100_i128);
+    let salt_a = make_salt(&env, 1);
+    client.bury_treasure(&1u32, &player_a, &make_commitment(&client, 1, 0, 5, &salt_a));
+    let buried = client.get_storage_report(&1u32);
+    assert_eq!(buried.entries, 4);
+    assert!(buried.bytes > created.bytes);
+    assert_eq!(client.get_storage_report(&2u32).entries, 0);
+}
+
+#[test]
+fn test_create_rooms_batch() {
+    let (env, game_id, player_a, player_b, _hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+    let config = RoomConfig {
+        commitment_scheme: CommitmentScheme::Sha256,
+        dig_mode: DigMode::Alternating,
+        roles: Roles::Duel,
+        dig_budget: 0,
+        hider_odds_bps: 0,
+        turn_timeout_ledgers: 0,
+        bury_timeout_ledgers: 0,
+        reveal_window_ledgers: 0,
+        action_points: 0,
+        min_completed: 0,
+        min_score_bps: 0,
+        event_verbosity: EventVerbosity::Default,
+        signed_burials: false,
+        derived_salts: false,
+        stake_token: None,
+        treasures: 0,
+        guess_mode: false,
+        invite_hash: None,
+        hub: None,
+        hubless: false,
+    };
+    let request = |room_id: u32, player_a: &Address| RoomRequest {
+        room_id,
+        player_a: player_a.clone(),
+        player_a_points: 100,
+        config: config.clone(),
+    };
+
+    let ids = client.create_rooms_batch(&Vec::from_array(
+        &env,
+        [request(1, &player_a), request(2, &player_b)],
+    ));
+    assert_eq!(ids, Vec::from_array(&env, [1u32, 2u32]));
+    assert_eq!(client.get_room(&2u32).player_a, player_b);
+
+    // A clash anywhere in the batch creates nothing.
+    let res = client.try_create_rooms_batch(&Vec::from_array(
+        &env,
+        [request(3, &player_a), request(1, &player_b)],
+    ));
+    assert_eq!(res, Err(Ok(Error::RoomExists.into())));
+    assert_eq!(client.try_get_room(&3u32), Err(Ok(Error::RoomNotFound.into())));
+
+    // Nobody seated signs the batch, so no stake can be escrowed.
+    let staked = RoomRequest {
+        config: RoomConfig {
+            stake_token: Some(Address::generate(&env)),
+            ..config.clone()
+        },
+        ..request(3, &player_a)
+    };
+    let res = client.try_create_rooms_batch(&Vec::from_array(&env, [staked]));
+    assert_eq!(res, Err(Ok(Error::InvalidConfig.into())));
+}
+
+#[test]
+fn test_get_room_v1() {
+    let (env, game_id, player_a, player_b, _hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+
+    client.create_room(&1u32, &player_a, &100_i128);
+    client.join_room(&1u32, &player_b, &100_i128);
+    client.start_room(&1u32, &player_a, &player_b, &100_i128, &100_i128);
+
+    let room = client.get_room(&1u32);
+    let v1 = client.get_room_v1(&1u32);
+    assert_eq!(v1, RoomV1::from(room.clone()));
+    assert_eq!(v1.player_b, player_b);
+    assert_eq!(v1.phase, room.phase);
+}
+
+#[test]
+fn test_full_view() {
+    let (env, game_id, player_a, player_b, hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+
+    client.create_room(&1u32, &player_a, &100_i128);
+    client.join_room(&1u32, &player_b, &100_i128);
+    client.start_room(&1u32, &player_a, &player_b, &100_i128, &100_i128);
+    env.ledger().set_sequence_number(42);
+    let salt_a = make_salt(&env, 1);
+    client.bury_treasure(&1u32, &player_a, &make_commitment(&client, 1, 0, 5, &salt_a));
+
+    let view = client.get_full_view(&1u32);
+    assert_eq!(view.room, client.get_room(&1u32));
+    assert_eq!(view.burial.player_a, Some(42));
+    assert_eq!(view.burial.player_b, None);
+    assert!(!view.round.committed_a);
+    assert_eq!(view.config.game_hub, Some(hub));
+}
+
+#[test]
+fn test_reputation() {
+    let (env, game_id, player_a, player_b, _hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+    assert_eq!(client.get_reputation(&player_a).started, 0);
+
+    // Room 1 is played to the end; room 2 is abandoned after starting.
+    for room_id in [1u32, 2u32] {
+        client.create_room(&room_id, &player_a, &100_i128);
+        client.join_room(&room_id, &player_b, &100_i128);
+        client.start_room(&room_id, &player_a, &player_b, &100_i128, &100_i128);
+    }
+    let salt_a = make_salt(&env, 1);
+    client.bury_treasure(&1u32, &player_a, &make_commitment(&client, 1, 0, 5, &salt_a));
+    let salt_b = make_salt(&env, 2);
+    client.bury_treasure(&1u32, &player_b, &make_commitment(&client, 1, 2, 15, &salt_b));
+    client.reveal_treasure(&1u32, &player_a, &2u32, &15u32, &salt_b);
+
+    let expected = Reputation {
+        started: 2,
+        completed: 1,
+        score_bps: 5_000,
+    };
+    assert_eq!(client.get_reputation(&player_a), expected);
+    assert_eq!(client.get_reputation(&player_b), expected);
+}
+
+#[test]
+fn test_min_reputation_to_join() {
+    let (env, game_id, player_a, player_b, _hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+
+    // B finishes one game and abandons another: 1 completed, 5 000 bps.
+    for room_id in [1u32, 2u32] {
+        client.create_room(&room_id, &player_a, &100_i128);
+        client.join_room(&room_id, &player_b, &100_i128);
+        client.start_room(&room_id, &player_a, &player_b, &100_i128, &100_i128);
+    }
+    let salt_a = make_salt(&env, 1);
+    client.bury_treasure(&1u32, &player_a, &make_commitment(&client, 1, 0, 5, &salt_a));
+    let salt_b = make_salt(&env, 2);
+    client.bury_treasure(&1u32, &player_b, &make_commitment(&client, 1, 2, 15, &salt_b));
+    client.reveal_treasure(&1u32, &player_a, &2u32, &15u32, &salt_b);
+
+    let mut config = RoomConfig {
+        commitment_scheme: CommitmentScheme::Sha256,
+        dig_mode: DigMode::Alternating,
+        roles: Roles::Duel,
+        dig_budget: 0,
+        hider_odds_bps: 0,
+        turn_timeout_ledgers: 0,
+        bury_timeout_ledgers: 0,
+        reveal_window_ledgers: 0,
+        action_points: 0,
+        min_completed: 1,
+        min_score_bps: 6_000,
+        event_verbosity: EventVerbosity::Default,
+        signed_burials: false,
+        derived_salts: false,
+        stake_token: None,
+        treasures: 0,
+        guess_mode: false,
+        invite_hash: None,
+        hub: None,
+        hubless: false,
+    };
+    client.create_room_with_config(&3u32, &player_a, &100_i128, &config);
+    let newcomer = Address::generate(&env);
+    let res = client.try_join_room(&3u32, &newcomer, &100_i128);
+    assert_eq!(res, Err(Ok(Error::ReputationTooLow.into())));
+    let res = client.try_join_room(&3u32, &player_b, &100_i128);
+    assert_eq!(res, Err(Ok(Error::ReputationTooLow.into())));
+
+    config.min_score_bps = 5_000;
+    client.create_room_with_config(&4u32, &player_a, &100_i128, &config);
+    client.join_room(&4u32, &player_b, &100_i128);
+
+    config.min_score_bps = 10_001;
+    let res = client.try_create_room_with_config(&5u32, &player_a, &100_i128, &config);
+    assert_eq!(res, Err(Ok(Error::InvalidConfig.into())));
+}
+
+#[test]
+fn test_room_fixture_round_trip() {
+    use crate::testutils::{dump_room, restore_room, RoomFixture};
+    use soroban_sdk::xdr::{FromXdr, ToXdr};
+
+    let (env, game_id, player_a, player_b, hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+
+    client.create_room(&1u32, &player_a, &100_i128);
+    client.join_room(&1u32, &player_b, &100_i128);
+    client.start_room(&1u32, &player_a, &player_b, &100_i128, &100_i128);
+    let salt_a = make_salt(&env, 1);
+    client.bury_treasure(&1u32, &player_a, &make_commitment(&client, 1, 0, 5, &salt_a));
+    let salt_b = make_salt(&env, 2);
+    client.bury_treasure(&1u32, &player_b, &make_commitment(&client, 1, 2, 15, &salt_b));
+    client.dig(&1u32, &player_a, &1u32, &3u32);
+    let observer = Address::generate(&env);
+    client.watch_room(&1u32, &observer);
+
+    // Fixtures survive an XDR round trip, as they would when checked in.
+    let fixture = dump_room(&env, &game_id, 1);
+    assert_eq!(fixture.room(), client.get_room(&1u32));
+    let encoded = fixture.clone().to_xdr(&env);
+    assert_eq!(RoomFixture::from_xdr(&env, &encoded).unwrap(), fixture);
+
+    // Restore into a fresh deployment and keep playing from there.
+    let admin = Address::generate(&env);
+    let other_id = env.register(PiratesTreasure, (&admin, Some(hub.clone())));
+    let other = PiratesTreasureClient::new(&env, &other_id);
+    restore_room(&env, &other_id, &fixture);
+    assert_eq!(other.get_room(&1u32), client.get_room(&1u32));
+    assert_eq!(other.get_observers(&1u32), Vec::from_array(&env, [observer]));
+
+    other.reveal_treasure(&1u32, &player_b, &0u32, &5u32, &salt_a);
+    assert_eq!(other.get_room(&1u32).winner, player_b);
+}
+
+#[test]
+fn test_deterministic_env() {
+    use crate::testutils::{deterministic_env, FIXED_SEQUENCE};
+
+    let draw = |seed: [u8; 32]| -> u64 {
+        let env = deterministic_env(seed);
+        assert_eq!(env.ledger().sequence(), FIXED_SEQUENCE);
+        let admin = Address::generate(&env);
+        let id = env.register(PiratesTreasure, (&admin, Some(admin.clone())));
+        env.as_contract(&id, || env.prng().gen())
+    };
+    assert_eq!(draw([7; 32]), draw([7; 32]));
+    assert_ne!(draw([7; 32]), draw([8; 32]));
+}
+
+#[test]
+fn test_play_full_game_script() {
+    use crate::testutils::{play_full_game, Harness, Step};
+
+    let (env, game_id, _player_a, _player_b, _hub) = setup_env();
+    let config = RoomConfig {
+        commitment_scheme: CommitmentScheme::Sha256,
+        dig_mode: DigMode::Alternating,
+        roles: Roles::Duel,
+        dig_budget: 0,
+        hider_odds_bps: 0,
+        turn_timeout_ledgers: 0,
+        bury_timeout_ledgers: 0,
+        reveal_window_ledgers: 0,
+        action_points: 0,
+        min_completed: 0,
+        min_score_bps: 0,
+        event_verbosity: EventVerbosity::Default,
+        signed_burials: false,
+        derived_salts: false,
+        stake_token: None,
+        treasures: 0,
+        guess_mode: false,
+        invite_hash: None,
+        hub: None,
+        hubless: false,
+    };
+    let harness = Harness::start(&env, &game_id, 1, config);
+
+    let (room, events) = play_full_game(
+        &harness,
+        &[
+            Step::Bury { a: true, island: 0, tile: 5, salt: [1; 32] },
+            Step::Bury { a: false, island: 2, tile: 15, salt: [2; 32] },
+            Step::Advance(10),
+            Step::Dig { a: true, island: 1, tile: 0 },
+            Step::Dig { a: false, island: 0, tile: 5 },
+            Step::Reveal { a: true, island: 2, tile: 15, salt: [2; 32] },
+        ],
+    );
+
+    assert_eq!(room.phase, 3);
+    assert_eq!(room.winner, harness.player_a);
+    let kinds = Vec::from_iter(&env, events.iter().map(|e| e.kind));
+    let expected = [
+        symbol_short!("buried"),
+        symbol_short!("buried"),
+        symbol_short!("dug"),
+        symbol_short!("dug"),
+        symbol_short!("revealed"),
+    ];
+    assert_eq!(kinds, Vec::from_array(&env, expected));
+    assert_eq!(events.get(2).unwrap().ledger, env.ledger().sequence());
+}
+
+#[test]
+fn test_get_digs_paginated() {
+    let (env, game_id, player_a, player_b, _hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+
+    client.create_room(&1u32, &player_a, &100_i128);
+    client.join_room(&1u32, &player_b, &100_i128);
+    client.start_room(&1u32, &player_a, &player_b, &100_i128, &100_i128);
+    let salt_a = make_salt(&env, 1);
+    client.bury_treasure(&1u32, &player_a, &make_commitment(&client, 1, 0, 5, &salt_a));
+    let salt_b = make_salt(&env, 2);
+    client.bury_treasure(&1u32, &player_b, &make_commitment(&client, 1, 2, 15, &salt_b));
+    for tile in 0..3u32 {
+        client.dig(&1u32, &player_a, &1u32, &tile);
+        client.dig(&1u32, &player_b, &1u32, &(tile + 10));
+    }
+
+    let first = client.get_digs(&1u32, &Cursor { start: 0, limit: 4 });
+    assert_eq!(first.items.len(), 4);
+    assert_eq!(first.next_cursor, Some(4));
+    let rest = client.get_digs(&1u32, &Cursor { start: 4, limit: 4 });
+    assert_eq!(rest.items.len(), 2);
+    assert_eq!(rest.items.get(1).unwrap().tile_id, 12);
+    assert_eq!(rest.next_cursor, None);
+
+    let empty = client.get_tips(&1u32, &Cursor { start: 0, limit: 0 });
+    assert!(empty.items.is_empty());
+    assert_eq!(empty.next_cursor, None);
+}
+
+#[test]
+fn test_commitment_bound_to_rules() {
+    let (env, game_id, player_a, player_b, _hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+
+    client.create_room(&1u32, &player_a, &100_i128);
+    client.join_room(&1u32, &player_b, &100_i128);
+    client.start_room(&1u32, &player_a, &player_b, &100_i128, &100_i128);
+    let salt_a = make_salt(&env, 1);
+    client.bury_treasure(&1u32, &player_a, &make_commitment(&client, 1, 0, 5, &salt_a));
+
+    // The same choice committed under a smaller board is a different hash.
+    let mut other_rules = client.get_room(&1u32);
+    other_rules.island_tile_counts = Vec::from_array(&env, [10u32, 10, 10]);
+    let foreign =
+        PiratesTreasure::compute_commitment(&env, &other_rules, &player_a, 0, 5, &salt_a);
+    assert_ne!(foreign, make_commitment(&client, 1, 0, 5, &salt_a));
+    assert!(client.verify_commitment(&1u32, &player_a, &0u32, &5u32, &salt_a));
+}
+
+#[test]
+fn test_committed_dig() {
+    let (env, game_id, player_a, player_b, _hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+
+    let config = RoomConfig {
+        commitment_scheme: CommitmentScheme::Sha256,
+        dig_mode: DigMode::Committed,
+        roles: Roles::Duel,
+        dig_budget: 0,
+        hider_odds_bps: 0,
+        turn_timeout_ledgers: 0,
+        bury_timeout_ledgers: 0,
+        reveal_window_ledgers: 0,
+        action_points: 0,
+        min_completed: 0,
+        min_score_bps: 0,
+        event_verbosity: EventVerbosity::Default,
+        signed_burials: false,
+        derived_salts: false,
+        stake_token: None,
+        treasures: 0,
+        guess_mode: false,
+        invite_hash: None,
+        hub: None,
+        hubless: false,
+    };
+    client.create_room_with_config(&1u32, &player_a, &100_i128, &config);
+    client.join_room(&1u32, &player_b, &100_i128);
+    client.start_room(&1u32, &player_a, &player_b, &100_i128, &100_i128);
+    let salt_a = make_salt(&env, 1);
+    client.bury_treasure(&1u32, &player_a, &make_commitment(&client, 1, 0, 5, &salt_a));
+    let salt_b = make_salt(&env, 2);
+    client.bury_treasure(&1u32, &player_b, &make_commitment(&client, 1, 2, 15, &salt_b));
+
+    let res = client.try_dig(&1u32, &player_a, &0u32, &1u32);
+    assert_eq!(res, Err(Ok(Error::WrongMode.into())));
+    let res = client.try_reveal_dig(&1u32, &player_a, &0u32, &1u32, &salt_a);
+    assert_eq!(res, Err(Ok(Error::NoPendingDig.into())));
+
+    let room = client.get_room(&1u32);
+    let dig_salt = make_salt(&env, 3);
+    let hidden = PiratesTreasure::round_dig_commitment(&env, &room, &player_a, 0, 1, &dig_salt);
+    client.commit_dig(&1u32, &player_a, &hidden);
+    // Still A's turn until the dig is revealed.
+    let res = client.try_commit_dig(&1u32, &player_b, &hidden);
+    assert_eq!(res, Err(Ok(Error::NotYourTurn.into())));
+    let res = client.try_reveal_dig(&1u32, &player_a, &0u32, &2u32, &dig_salt);
+    assert_eq!(res, Err(Ok(Error::CommitmentMismatch.into())));
+
+    client.reveal_dig(&1u32, &player_a, &0u32, &1u32, &dig_salt);
+    let room = client.get_room(&1u32);
+    assert_eq!(room.digs.len(), 1);
+    assert!(!room.turn_is_a);
+}
+
+#[test]
+fn test_action_points() {
+    let (env, game_id, player_a, player_b, _hub) = setup_env();
+    let client = PiratesTreasureClient::new(&env, &game_id);
+
+    let mut config = RoomConfig {
+        commitment_scheme: CommitmentScheme::Sha256,
+        dig_mode: DigMode::Alternating,
+        roles: Roles::Duel,
+        dig_budget: 0,
+        hider_odds_bps: 0,
+        turn_timeout_ledgers: 0,
+        bury_timeout_ledgers: 0,
+        reveal_window_ledgers: 0,
+        action_points: 2,
+        min_completed: 0,
+        min_score_bps: 0,
+        event_verbosity: EventVerbosity::Default,
+        signed_burials: false,
+        derived_salts: false,
+        stake_token: None,
+        treasures: 0,
+        guess_mode: false,
+        invite_hash: None,
+        hub: None,
+        hubless: false,
+    };
+    // Too few points to afford the 30-tile island.
+    let res = client.try_create_room_with_config(&1u32, &player_a, &100_i128, &config);
+    assert_eq!(res, Err(Ok(Error::InvalidConfig.into())));
+
+    config.action_points = 4;
+    client.create_room_with_config(&1u32, &player_a, &100_i128, &config);
+    client.join_room(&1u32, &player_b, &100_i128);
+    client.start_room(&1u32, &player_a, &player_b, &100_i128, &100_i128);
+    let salt_a = make_salt(&env, 1);
+    client.bury_treasure(&1u32, &player_a, &make_commitment(&client, 1, 0, 5, &salt_a));
+    let salt_b = make_salt(&env, 2);
+    client.bury_treasure(&1u32, &player_b, &make_commitment(&client, 1, 2, 15, &salt_b));
+
+    // A small-island dig costs 1, leaving 3: the turn continues.
+    client.dig(&1u32, &player_a, &0u32, &1u32);
+    let room = client.get_room(&1u32);
+    assert!(room.turn_is_a);
+    assert_eq!(room.actions_left, 3);
+
+    // The large island costs 3, which spends the turn exactly.
+    client.dig(&1u32, &player_a, &2u32, &1u32);
+    let room = client.get_room(&1u32);
+    assert!(!room.turn_is_a);
+    assert_eq!(room.actions_left, 4);
+
+    client.dig(&1u32, &player_b, &1u32, &1u32);
+    client.dig(&1u32, &player_b, &0u32, &2u32);
+    let res = client.try_dig(&1u32, &player_b, &2u32, &2u32);
+    assert_eq!(res, Err(Ok(Error::NotEnoughActions.into())));
+
+    // B passes with one point left.
+    let res = client.try_end_turn(&1u32, &player_a);
+    assert_eq!(res, Err(Ok(Error::NotYourTurn.into())));
+    client.end_turn(&1u32, &player_b);
+    let room = client.get_room(&1u32);
+    assert!(room.turn_is_a);
+    assert_eq!(room.actions_left, 4);
 }
@@ -0,0 +1,221 @@
+//! Test helpers, available to this crate's tests and to dependents that
+//! enable the `testutils` feature.
+
+use soroban_sdk::{
+    contracttype,
+    testutils::{Address as _, Events as _, Ledger},
+    xdr, Address, BytesN, Env, Map, Symbol, TryFromVal, Val, Vec,
+};
+
+use crate::contract::bump_temp;
+use crate::{
+    DataKey, PiratesTreasure, PiratesTreasureClient, Room, RoomConfig, RoomEvent, RoomEventData,
+};
+
+/// Ledger sequence every [`deterministic_env`] starts at.
+pub const FIXED_SEQUENCE: u32 = 1_000;
+/// Ledger timestamp every [`deterministic_env`] starts at.
+pub const FIXED_TIMESTAMP: u64 = 1_700_000_000;
+
+/// An `Env` with a fixed ledger sequence and timestamp and its PRNG seeded
+/// from `seed`, so the same seed replays the same ledger clock and the
+/// same random draws.
+pub fn deterministic_env(seed: [u8; 32]) -> Env {
+    let env = Env::default();
+    env.host().set_base_prng_seed(seed).unwrap();
+    env.ledger().set_sequence_number(FIXED_SEQUENCE);
+    env.ledger().set_timestamp(FIXED_TIMESTAMP);
+    env
+}
+
+/// Every storage entry belonging to one room.
+///
+/// A fixture is a `contracttype`, so it can be XDR-encoded, checked in, and
+/// restored with [`restore_room`] to start a test from an exact real-world
+/// state.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoomFixture {
+    pub room_id: u32,
+    /// The room's stored entries: those of `PiratesTreasure::room_keys`
+    /// that are set, plus one per bet.
+    pub entries: Map<DataKey, Val>,
+}
+
+impl RoomFixture {
+    /// The stored value under `key`, if the room has one.
+    pub fn get<V: TryFromVal<Env, Val>>(&self, key: &DataKey) -> Option<V> {
+        let value = self.entries.get(key.clone())?;
+        V::try_from_val(self.entries.env(), &value).ok()
+    }
+
+    /// The room itself.
+    pub fn room(&self) -> Room {
+        self.get(&DataKey::Room(self.room_id)).unwrap()
+    }
+}
+
+/// Every key `room_id` can own on the contract at `contract_id`: the
+/// fixed per-room keys, plus the bets, which are keyed by bettor and so
+/// found by scanning the contract's ledger entries.
+fn keys(env: &Env, contract_id: &Address, room_id: u32) -> Vec<DataKey> {
+    let mut keys = Vec::from_array(env, PiratesTreasure::room_keys(room_id));
+    let contract = xdr::ScAddress::from(contract_id);
+    for (key, _) in env.to_ledger_snapshot().ledger_entries {
+        let xdr::LedgerKey::ContractData(data) = *key else {
+            continue;
+        };
+        if data.contract != contract {
+            continue;
+        }
+        if let Ok(DataKey::Bet(id, bettor)) = DataKey::try_from_val(env, &data.key) {
+            if id == room_id {
+                keys.push_back(DataKey::Bet(id, bettor));
+            }
+        }
+    }
+    keys
+}
+
+/// Read all of `room_id`'s storage from the contract at `contract_id`.
+pub fn dump_room(env: &Env, contract_id: &Address, room_id: u32) -> RoomFixture {
+    let keys = keys(env, contract_id, room_id);
+    env.as_contract(contract_id, || {
+        let storage = env.storage().temporary();
+        let mut entries = Map::new(env);
+        for key in keys {
+            if let Some(value) = storage.get::<_, Val>(&key) {
+                entries.set(key, value);
+            }
+        }
+        RoomFixture { room_id, entries }
+    })
+}
+
+/// Write `fixture` into the contract at `contract_id`, replacing whatever
+/// that room held before. Keys missing from the fixture are removed.
+pub fn restore_room(env: &Env, contract_id: &Address, fixture: &RoomFixture) {
+    let stale = keys(env, contract_id, fixture.room_id);
+    env.as_contract(contract_id, || {
+        let storage = env.storage().temporary();
+        for key in stale {
+            storage.remove(&key);
+        }
+        for (key, value) in fixture.entries.iter() {
+            storage.set(&key, &value);
+            bump_temp(env, &key);
+        }
+    });
+}
+
+/// A started room driven by [`play_full_game`].
+pub struct Harness {
+    pub env: Env,
+    pub contract_id: Address,
+    pub room_id: u32,
+    pub player_a: Address,
+    pub player_b: Address,
+}
+
+impl Harness {
+    /// Create, join and start `room_id` on the contract at `contract_id`
+    /// with two fresh players staking 100 points each. The caller must have
+    /// mocked auths.
+    pub fn start(env: &Env, contract_id: &Address, room_id: u32, config: RoomConfig) -> Self {
+        let client = PiratesTreasureClient::new(env, contract_id);
+        let player_a = Address::generate(env);
+        let player_b = Address::generate(env);
+        client.create_room_with_config(&room_id, &player_a, &100, &config);
+        client.join_room(&room_id, &player_b, &100);
+        client.start_room(&room_id, &player_a, &player_b, &100, &100);
+        Harness {
+            env: env.clone(),
+            contract_id: contract_id.clone(),
+            room_id,
+            player_a,
+            player_b,
+        }
+    }
+
+    fn player(&self, is_a: bool) -> &Address {
+        if is_a {
+            &self.player_a
+        } else {
+            &self.player_b
+        }
+    }
+}
+
+/// One action in a [`play_full_game`] script. `a` picks the acting player.
+#[derive(Clone, Debug)]
+pub enum Step {
+    /// Bury at (island, tile) with the given salt; the commitment is
+    /// computed under the room's scheme.
+    Bury { a: bool, island: u32, tile: u32, salt: [u8; 32] },
+    Dig { a: bool, island: u32, tile: u32 },
+    /// Reveal the opponent's treasure.
+    Reveal { a: bool, island: u32, tile: u32, salt: [u8; 32] },
+    /// Move the ledger sequence forward.
+    Advance(u32),
+}
+
+/// Run `script` against the harness's room and return the final room with
+/// every `room_event` the contract published along the way.
+pub fn play_full_game(harness: &Harness, script: &[Step]) -> (Room, Vec<RoomEvent>) {
+    let env = &harness.env;
+    let client = PiratesTreasureClient::new(env, &harness.contract_id);
+    let room_id = harness.room_id;
+    let mut events = Vec::new(env);
+
+    for step in script {
+        match step.clone() {
+            Step::Bury { a, island, tile, salt } => {
+                let owner = harness.player(a);
+                let room = client.get_room(&room_id);
+                let salt = BytesN::from_array(env, &salt);
+                let commitment =
+                    PiratesTreasure::compute_commitment(env, &room, owner, island, tile, &salt);
+                client.bury_treasure(&room_id, owner, &commitment);
+            }
+            Step::Dig { a, island, tile } => {
+                client.dig(&room_id, harness.player(a), &island, &tile);
+            }
+            Step::Reveal { a, island, tile, salt } => {
+                let salt = BytesN::from_array(env, &salt);
+                client.reveal_treasure(&room_id, harness.player(a), &island, &tile, &salt);
+            }
+            Step::Advance(ledgers) => {
+                let sequence = env.ledger().sequence() + ledgers;
+                env.ledger().set_sequence_number(sequence);
+                continue;
+            }
+        }
+        collect_room_events(env, &harness.contract_id, &mut events);
+    }
+
+    (client.get_room(&room_id), events)
+}
+
+/// Append the `room_event`s published by the last invocation.
+fn collect_room_events(env: &Env, contract_id: &Address, out: &mut Vec<RoomEvent>) {
+    let published = env.events().all().filter_by_contract(contract_id);
+    for event in published.events() {
+        let xdr::ContractEventBody::V0(body) = &event.body;
+        let Some(topic) = body.topics.get(2) else {
+            continue;
+        };
+        let kind = Val::try_from_val(env, topic)
+            .ok()
+            .and_then(|v| Symbol::try_from_val(env, &v).ok());
+        let data = Val::try_from_val(env, &body.data)
+            .ok()
+            .and_then(|v| RoomEventData::try_from_val(env, &v).ok());
+        if let (Some(kind), Some(data)) = (kind, data) {
+            out.push_back(RoomEvent {
+                kind,
+                actor: data.actor,
+                ledger: data.ledger,
+            });
+        }
+    }
+}
@@ -0,0 +1,458 @@
+#![no_std]
+
+use soroban_sdk::{
+    contract, contractclient, contracterror, contractimpl, contracttype, panic_with_error, token,
+    Address, Env, Val, Vec,
+};
+
+/// Tournament contract for Pirate's Treasure
+///
+/// Runs single-elimination brackets on top of the game contract. Players
+/// register with an entry stake held here; once the bracket is full each
+/// pairing is played in a `PiratesTreasure` room opened by `start_match`,
+/// `report_match` reads the result back and advances the winner, and the
+/// champion takes the whole prize pool. A match nobody starts in time goes
+/// to the player who still shows up (`claim_walkover`); the organizer can
+/// call the whole tournament off, refunding every stake, before it is
+/// seeded or once a match has been abandoned (`cancel_tournament`).
+#[contract]
+pub struct Tournament;
+
+const DAY_IN_LEDGERS: u32 = 17_280;
+const TTL_BUMP: u32 = 30 * DAY_IN_LEDGERS;
+const TTL_THRESHOLD: u32 = TTL_BUMP - DAY_IN_LEDGERS;
+
+/// Largest bracket, in players.
+pub const MAX_SIZE: u32 = 32;
+
+/// Ledgers a pairing has to start its room before a player who shows up
+/// may claim a walkover. Once twice this has passed with the match still
+/// unclaimed, it counts as abandoned.
+pub const NO_SHOW_LEDGERS: u32 = DAY_IN_LEDGERS;
+
+/// Game room phases `report_match` acts on (see the game's `Room`).
+const PHASE_ENDED: u32 = 3;
+const PHASE_DRAW: u32 = 4;
+
+// ---------------------------------------------------------------------------
+// Game client interface (calls into the PiratesTreasure contract)
+// ---------------------------------------------------------------------------
+
+/// `Room` in the game's stable original layout, as returned by
+/// `get_room_v1`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoomV1 {
+    pub room_id: u32,
+    pub player_a: Address,
+    pub player_b: Address,
+    pub player_a_points: i128,
+    pub player_b_points: i128,
+    pub phase: u32,
+    pub turn_is_a: bool,
+    pub island_tile_counts: Vec<u32>,
+    pub has_commitment_a: bool,
+    pub has_commitment_b: bool,
+    pub game_active: bool,
+    pub winner: Address,
+    pub digs: Vec<DigRecord>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DigRecord {
+    pub digger: Address,
+    pub island_id: u32,
+    pub tile_id: u32,
+}
+
+/// The game entrypoints a tournament uses. Rooms returned by `join_room`
+/// and `start_room` are left undecoded; results are read through
+/// `get_room_v1` only.
+#[contractclient(name = "GameClient")]
+pub trait Game {
+    fn create_room_auto(env: Env, player_a: Address, player_a_points: i128) -> u32;
+    fn join_room(env: Env, room_id: u32, player_b: Address, player_b_points: i128) -> Val;
+    fn start_room(
+        env: Env,
+        room_id: u32,
+        player_a: Address,
+        player_b: Address,
+        player_a_points: i128,
+        player_b_points: i128,
+    ) -> Val;
+    fn get_room_v1(env: Env, room_id: u32) -> RoomV1;
+}
+
+// ---------------------------------------------------------------------------
+// Errors
+// ---------------------------------------------------------------------------
+
+#[contracterror]
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum Error {
+    /// No game contract registered yet
+    GameNotSet = 1,
+    /// Tournament with that ID already exists
+    TournamentExists = 2,
+    /// Tournament not found
+    TournamentNotFound = 3,
+    /// Bracket size must be a power of two from 2 to `MAX_SIZE`
+    InvalidSize = 4,
+    /// Entry stake must be positive
+    InvalidAmount = 5,
+    /// Bracket is already full
+    TournamentFull = 6,
+    /// Player is already registered
+    AlreadyRegistered = 7,
+    /// Bracket is not full yet
+    NotSeeded = 8,
+    /// No match at that index in the current round
+    MatchNotFound = 9,
+    /// Match already has a room
+    MatchStarted = 10,
+    /// Match already has a winner
+    MatchDecided = 11,
+    /// Match room has not been opened yet
+    MatchNotStarted = 12,
+    /// Match room has not ended yet
+    GameNotOver = 13,
+    /// Tournament already has a champion
+    TournamentOver = 14,
+    /// Address is not one of the match's players
+    NotInMatch = 15,
+    /// Match may still be started in time
+    MatchWindowOpen = 16,
+    /// Tournament was cancelled and its stakes refunded
+    TournamentCancelled = 17,
+}
+
+// ---------------------------------------------------------------------------
+// Types
+// ---------------------------------------------------------------------------
+
+/// One pairing of the current round.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Match {
+    pub player_a: Address,
+    pub player_b: Address,
+    /// Game room the match is being played in; cleared after a draw so
+    /// the match can be replayed.
+    pub room_id: Option<u32>,
+    pub winner: Option<Address>,
+    /// Ledger by which the room must be started; reset when a draw
+    /// clears it. See `NO_SHOW_LEDGERS`.
+    pub deadline: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TournamentState {
+    pub tournament_id: u32,
+    pub organizer: Address,
+    /// Token (SAC) the entry stakes are paid in.
+    pub token: Address,
+    pub entry_stake: i128,
+    /// Players in the bracket, a power of two.
+    pub size: u32,
+    /// Registered players, in seeding order.
+    pub players: Vec<Address>,
+    /// Current round, 0 for the first. Meaningless until seeded.
+    pub round: u32,
+    /// Pairings of the current round; empty until the bracket is full.
+    pub matches: Vec<Match>,
+    pub champion: Option<Address>,
+    /// Called off by the organizer; every stake has been refunded.
+    pub cancelled: bool,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Admin,
+    /// Game contract the matches are played on
+    Game,
+    /// Tournament(tournament_id) → TournamentState (persistent)
+    Tournament(u32),
+}
+
+#[contractimpl]
+impl Tournament {
+    pub fn __constructor(env: Env, admin: Address) {
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().extend_ttl(TTL_THRESHOLD, TTL_BUMP);
+    }
+
+    /// Register the game contract matches are played on. Admin only.
+    pub fn set_game(env: Env, game: Address) {
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        env.storage().instance().set(&DataKey::Game, &game);
+        env.storage().instance().extend_ttl(TTL_THRESHOLD, TTL_BUMP);
+    }
+
+    /// Open a bracket for registration.
+    ///
+    /// # Arguments
+    /// * `tournament_id` - Unique identifier for the tournament
+    /// * `organizer` - Address creating the tournament
+    /// * `token` - Token the entry stakes are paid in
+    /// * `entry_stake` - What each player pays in, also their points in
+    ///   every room they play
+    /// * `size` - Number of players, a power of two up to `MAX_SIZE`
+    pub fn create_tournament(
+        env: Env,
+        tournament_id: u32,
+        organizer: Address,
+        token: Address,
+        entry_stake: i128,
+        size: u32,
+    ) -> TournamentState {
+        organizer.require_auth();
+
+        let key = DataKey::Tournament(tournament_id);
+        if env.storage().persistent().has(&key) {
+            panic_with_error!(&env, Error::TournamentExists);
+        }
+        if !(2..=MAX_SIZE).contains(&size) || !size.is_power_of_two() {
+            panic_with_error!(&env, Error::InvalidSize);
+        }
+        if entry_stake <= 0 {
+            panic_with_error!(&env, Error::InvalidAmount);
+        }
+
+        let state = TournamentState {
+            tournament_id,
+            organizer,
+            token,
+            entry_stake,
+            size,
+            players: Vec::new(&env),
+            round: 0,
+            matches: Vec::new(&env),
+            champion: None,
+            cancelled: false,
+        };
+        Self::save(&env, &state);
+        state
+    }
+
+    /// Pay the entry stake and take the next seed. The bracket is seeded
+    /// as soon as the last seat is taken, first seed against second and so
+    /// on.
+    pub fn register(env: Env, tournament_id: u32, player: Address) {
+        player.require_auth();
+
+        let mut state = Self::get_tournament(env.clone(), tournament_id);
+        if state.cancelled {
+            panic_with_error!(&env, Error::TournamentCancelled);
+        }
+        if state.players.len() == state.size {
+            panic_with_error!(&env, Error::TournamentFull);
+        }
+        if state.players.contains(&player) {
+            panic_with_error!(&env, Error::AlreadyRegistered);
+        }
+
+        token::Client::new(&env, &state.token).transfer(
+            &player,
+            env.current_contract_address(),
+            &state.entry_stake,
+        );
+        state.players.push_back(player);
+        if state.players.len() == state.size {
+            state.matches = Self::pair(&env, &state.players);
+        }
+        Self::save(&env, &state);
+    }
+
+    /// Open and start the game room for match `index` of the current
+    /// round, returning its room ID. Both players must authorize, as the
+    /// game seats and starts them.
+    pub fn start_match(env: Env, tournament_id: u32, index: u32) -> u32 {
+        let mut state = Self::get_tournament(env.clone(), tournament_id);
+        let mut m = Self::current_match(&env, &state, index);
+        if m.room_id.is_some() {
+            panic_with_error!(&env, Error::MatchStarted);
+        }
+
+        let game = GameClient::new(&env, &Self::game(&env));
+        let points = state.entry_stake;
+        let room_id = game.create_room_auto(&m.player_a, &points);
+        game.join_room(&room_id, &m.player_b, &points);
+        game.start_room(&room_id, &m.player_a, &m.player_b, &points, &points);
+
+        m.room_id = Some(room_id);
+        state.matches.set(index, m);
+        Self::save(&env, &state);
+        room_id
+    }
+
+    /// Read the result of match `index` from its game room. A win
+    /// advances the winner; once every match of the round is decided the
+    /// next round is paired, or after the final the prize pool is paid to
+    /// the champion. A draw clears the room so the match is replayed.
+    /// Anyone may call this.
+    pub fn report_match(env: Env, tournament_id: u32, index: u32) {
+        let mut state = Self::get_tournament(env.clone(), tournament_id);
+        let mut m = Self::current_match(&env, &state, index);
+        let Some(room_id) = m.room_id else {
+            panic_with_error!(&env, Error::MatchNotStarted);
+        };
+
+        let room = GameClient::new(&env, &Self::game(&env)).get_room_v1(&room_id);
+        match room.phase {
+            PHASE_ENDED => m.winner = Some(room.winner),
+            PHASE_DRAW => {
+                m.room_id = None;
+                m.deadline = env.ledger().sequence().saturating_add(NO_SHOW_LEDGERS);
+            }
+            _ => panic_with_error!(&env, Error::GameNotOver),
+        }
+        state.matches.set(index, m);
+        Self::advance(&env, &mut state);
+        Self::save(&env, &state);
+    }
+
+    /// Take match `index` by walkover: its room was not started by the
+    /// deadline, and `player` is here to claim it while the opponent is
+    /// not. Advances the bracket as `report_match` does.
+    pub fn claim_walkover(env: Env, tournament_id: u32, index: u32, player: Address) {
+        player.require_auth();
+
+        let mut state = Self::get_tournament(env.clone(), tournament_id);
+        let mut m = Self::current_match(&env, &state, index);
+        if m.room_id.is_some() {
+            panic_with_error!(&env, Error::MatchStarted);
+        }
+        if player != m.player_a && player != m.player_b {
+            panic_with_error!(&env, Error::NotInMatch);
+        }
+        if env.ledger().sequence() <= m.deadline {
+            panic_with_error!(&env, Error::MatchWindowOpen);
+        }
+
+        m.winner = Some(player);
+        state.matches.set(index, m);
+        Self::advance(&env, &mut state);
+        Self::save(&env, &state);
+    }
+
+    /// Call the tournament off and refund every registered player's
+    /// stake. Organizer only, and only before the bracket is seeded or
+    /// once a match of the current round has been abandoned: left
+    /// unstarted and unclaimed for `NO_SHOW_LEDGERS` past its deadline.
+    pub fn cancel_tournament(env: Env, tournament_id: u32) {
+        let mut state = Self::get_tournament(env.clone(), tournament_id);
+        state.organizer.require_auth();
+        if state.cancelled {
+            panic_with_error!(&env, Error::TournamentCancelled);
+        }
+        if state.champion.is_some() {
+            panic_with_error!(&env, Error::TournamentOver);
+        }
+        let now = env.ledger().sequence();
+        let abandoned = state.matches.iter().any(|m| {
+            m.winner.is_none()
+                && m.room_id.is_none()
+                && now > m.deadline.saturating_add(NO_SHOW_LEDGERS)
+        });
+        if !state.matches.is_empty() && !abandoned {
+            panic_with_error!(&env, Error::MatchWindowOpen);
+        }
+
+        let token = token::Client::new(&env, &state.token);
+        for player in state.players.iter() {
+            token.transfer(&env.current_contract_address(), &player, &state.entry_stake);
+        }
+        state.cancelled = true;
+        Self::save(&env, &state);
+    }
+
+    pub fn get_tournament(env: Env, tournament_id: u32) -> TournamentState {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Tournament(tournament_id))
+            .unwrap_or_else(|| panic_with_error!(&env, Error::TournamentNotFound))
+    }
+
+    fn save(env: &Env, state: &TournamentState) {
+        let key = DataKey::Tournament(state.tournament_id);
+        env.storage().persistent().set(&key, state);
+        env.storage().persistent().extend_ttl(&key, TTL_THRESHOLD, TTL_BUMP);
+    }
+
+    /// Once every match of the round is decided, pair the next round, or
+    /// after the final pay the prize pool to the champion.
+    fn advance(env: &Env, state: &mut TournamentState) {
+        if state.matches.iter().all(|m| m.winner.is_some()) {
+            let winners: Vec<Address> = Vec::from_iter(
+                env,
+                state.matches.iter().map(|m| m.winner.unwrap()),
+            );
+            if winners.len() == 1 {
+                let champion = winners.get(0).unwrap();
+                let pool = state.entry_stake * state.size as i128;
+                token::Client::new(env, &state.token).transfer(
+                    &env.current_contract_address(),
+                    &champion,
+                    &pool,
+                );
+                state.champion = Some(champion);
+            } else {
+                state.round += 1;
+                state.matches = Self::pair(env, &winners);
+            }
+        }
+    }
+
+    fn game(env: &Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Game)
+            .unwrap_or_else(|| panic_with_error!(env, Error::GameNotSet))
+    }
+
+    /// Undecided match `index` of the current round.
+    fn current_match(env: &Env, state: &TournamentState, index: u32) -> Match {
+        if state.cancelled {
+            panic_with_error!(env, Error::TournamentCancelled);
+        }
+        if state.champion.is_some() {
+            panic_with_error!(env, Error::TournamentOver);
+        }
+        if state.matches.is_empty() {
+            panic_with_error!(env, Error::NotSeeded);
+        }
+        let m = state
+            .matches
+            .get(index)
+            .unwrap_or_else(|| panic_with_error!(env, Error::MatchNotFound));
+        if m.winner.is_some() {
+            panic_with_error!(env, Error::MatchDecided);
+        }
+        m
+    }
+
+    /// Pair `players` in order: first against second, third against
+    /// fourth, and so on. Each match has `NO_SHOW_LEDGERS` to start.
+    fn pair(env: &Env, players: &Vec<Address>) -> Vec<Match> {
+        let deadline = env.ledger().sequence().saturating_add(NO_SHOW_LEDGERS);
+        let mut matches = Vec::new(env);
+        for i in (0..players.len()).step_by(2) {
+            matches.push_back(Match {
+                player_a: players.get(i).unwrap(),
+                player_b: players.get(i + 1).unwrap(),
+                room_id: None,
+                winner: None,
+                deadline,
+            });
+        }
+        matches
+    }
+}
+
+#[cfg(test)]
+mod test;
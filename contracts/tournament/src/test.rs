@@ -0,0 +1,298 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+
+// ---------------------------------------------------------------------------
+// Mock game: just enough of PiratesTreasure to seat, start and end rooms
+// ---------------------------------------------------------------------------
+
+#[contract]
+pub struct MockGame;
+
+#[contracttype]
+enum MockKey {
+    Counter,
+    Room(u32),
+}
+
+#[contractimpl]
+impl MockGame {
+    pub fn create_room_auto(env: Env, player_a: Address, player_a_points: i128) -> u32 {
+        let room_id: u32 = env.storage().instance().get(&MockKey::Counter).unwrap_or(0) + 1;
+        env.storage().instance().set(&MockKey::Counter, &room_id);
+        let room = RoomV1 {
+            room_id,
+            player_a: player_a.clone(),
+            player_b: player_a.clone(),
+            player_a_points,
+            player_b_points: 0,
+            phase: 0,
+            turn_is_a: true,
+            island_tile_counts: Vec::new(&env),
+            has_commitment_a: false,
+            has_commitment_b: false,
+            game_active: false,
+            winner: player_a,
+            digs: Vec::new(&env),
+        };
+        env.storage().instance().set(&MockKey::Room(room_id), &room);
+        room_id
+    }
+
+    pub fn join_room(env: Env, room_id: u32, player_b: Address, player_b_points: i128) -> RoomV1 {
+        let mut room = Self::get_room_v1(env.clone(), room_id);
+        room.player_b = player_b;
+        room.player_b_points = player_b_points;
+        env.storage().instance().set(&MockKey::Room(room_id), &room);
+        room
+    }
+
+    pub fn start_room(
+        env: Env,
+        room_id: u32,
+        _player_a: Address,
+        _player_b: Address,
+        _player_a_points: i128,
+        _player_b_points: i128,
+    ) -> RoomV1 {
+        let mut room = Self::get_room_v1(env.clone(), room_id);
+        room.phase = 1;
+        room.game_active = true;
+        env.storage().instance().set(&MockKey::Room(room_id), &room);
+        room
+    }
+
+    pub fn get_room_v1(env: Env, room_id: u32) -> RoomV1 {
+        env.storage().instance().get(&MockKey::Room(room_id)).unwrap()
+    }
+
+    /// End a room with `winner`, or as a draw when `None`.
+    pub fn finish(env: Env, room_id: u32, winner: Option<Address>) {
+        let mut room = Self::get_room_v1(env.clone(), room_id);
+        room.game_active = false;
+        match winner {
+            Some(winner) => {
+                room.phase = PHASE_ENDED;
+                room.winner = winner;
+            }
+            None => room.phase = PHASE_DRAW,
+        }
+        env.storage().instance().set(&MockKey::Room(room_id), &room);
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+const STAKE: i128 = 100;
+
+struct Setup<'a> {
+    env: Env,
+    client: TournamentClient<'a>,
+    game: MockGameClient<'a>,
+    token: TokenClient<'a>,
+    organizer: Address,
+}
+
+fn setup() -> Setup<'static> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let contract_id = env.register(Tournament, (&admin,));
+    let client = TournamentClient::new(&env, &contract_id);
+    let game_id = env.register(MockGame, ());
+    client.set_game(&game_id);
+
+    let sac = env.register_stellar_asset_contract_v2(admin);
+    let token = TokenClient::new(&env, &sac.address());
+    Setup {
+        game: MockGameClient::new(&env, &game_id),
+        organizer: Address::generate(&env),
+        client,
+        token,
+        env,
+    }
+}
+
+/// Create tournament 1 of `size` and fill it with funded players.
+fn fill(s: &Setup, size: u32) -> Vec<Address> {
+    s.client
+        .create_tournament(&1u32, &s.organizer, &s.token.address, &STAKE, &size);
+    let sac = StellarAssetClient::new(&s.env, &s.token.address);
+    let mut players = Vec::new(&s.env);
+    for _ in 0..size {
+        let player = Address::generate(&s.env);
+        sac.mint(&player, &STAKE);
+        s.client.register(&1u32, &player);
+        players.push_back(player);
+    }
+    players
+}
+
+/// Play match `index` of tournament 1 to a win for `winner`.
+fn play(s: &Setup, index: u32, winner: &Address) {
+    let room_id = s.client.start_match(&1u32, &index);
+    s.game.finish(&room_id, &Some(winner.clone()));
+    s.client.report_match(&1u32, &index);
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_bracket_runs_to_champion() {
+    let s = setup();
+    let players = fill(&s, 4);
+    assert_eq!(s.token.balance(&s.client.address), 4 * STAKE);
+
+    let state = s.client.get_tournament(&1u32);
+    assert_eq!(state.matches.len(), 2);
+    assert_eq!(state.matches.get(1).unwrap().player_a, players.get(2).unwrap());
+
+    play(&s, 0, &players.get(1).unwrap());
+    assert_eq!(s.client.get_tournament(&1u32).round, 0);
+    play(&s, 1, &players.get(2).unwrap());
+
+    let state = s.client.get_tournament(&1u32);
+    assert_eq!(state.round, 1);
+    assert_eq!(state.matches.len(), 1);
+    let last = state.matches.get(0).unwrap();
+    assert_eq!(last.player_a, players.get(1).unwrap());
+    assert_eq!(last.player_b, players.get(2).unwrap());
+
+    play(&s, 0, &players.get(2).unwrap());
+    let state = s.client.get_tournament(&1u32);
+    assert_eq!(state.champion, Some(players.get(2).unwrap()));
+    assert_eq!(s.token.balance(&players.get(2).unwrap()), 4 * STAKE);
+    assert_eq!(s.token.balance(&s.client.address), 0);
+
+    let res = s.client.try_start_match(&1u32, &0u32);
+    assert_eq!(res, Err(Ok(Error::TournamentOver.into())));
+}
+
+#[test]
+fn test_draw_replays_match() {
+    let s = setup();
+    let players = fill(&s, 2);
+
+    let first = s.client.start_match(&1u32, &0u32);
+    let res = s.client.try_report_match(&1u32, &0u32);
+    assert_eq!(res, Err(Ok(Error::GameNotOver.into())));
+
+    s.game.finish(&first, &None);
+    s.client.report_match(&1u32, &0u32);
+    assert_eq!(s.client.get_tournament(&1u32).matches.get(0).unwrap().room_id, None);
+
+    let second = s.client.start_match(&1u32, &0u32);
+    assert_ne!(first, second);
+    s.game.finish(&second, &Some(players.get(0).unwrap()));
+    s.client.report_match(&1u32, &0u32);
+    assert_eq!(s.client.get_tournament(&1u32).champion, Some(players.get(0).unwrap()));
+}
+
+#[test]
+fn test_registration_rules() {
+    let s = setup();
+    let res = s
+        .client
+        .try_create_tournament(&1u32, &s.organizer, &s.token.address, &STAKE, &3u32);
+    assert_eq!(res, Err(Ok(Error::InvalidSize.into())));
+
+    s.client
+        .create_tournament(&1u32, &s.organizer, &s.token.address, &STAKE, &2u32);
+    let player = Address::generate(&s.env);
+    StellarAssetClient::new(&s.env, &s.token.address).mint(&player, &(2 * STAKE));
+    s.client.register(&1u32, &player);
+
+    let res = s.client.try_register(&1u32, &player);
+    assert_eq!(res, Err(Ok(Error::AlreadyRegistered.into())));
+    let res = s.client.try_start_match(&1u32, &0u32);
+    assert_eq!(res, Err(Ok(Error::NotSeeded.into())));
+}
+
+#[test]
+fn test_cancel_before_seeding_refunds() {
+    let s = setup();
+    s.client
+        .create_tournament(&1u32, &s.organizer, &s.token.address, &STAKE, &4u32);
+    let sac = StellarAssetClient::new(&s.env, &s.token.address);
+    let players = [Address::generate(&s.env), Address::generate(&s.env)];
+    for player in &players {
+        sac.mint(player, &STAKE);
+        s.client.register(&1u32, player);
+    }
+
+    s.client.cancel_tournament(&1u32);
+    for player in &players {
+        assert_eq!(s.token.balance(player), STAKE);
+    }
+    assert_eq!(s.token.balance(&s.client.address), 0);
+    assert!(s.client.get_tournament(&1u32).cancelled);
+
+    let late = Address::generate(&s.env);
+    let res = s.client.try_register(&1u32, &late);
+    assert_eq!(res, Err(Ok(Error::TournamentCancelled.into())));
+    let res = s.client.try_cancel_tournament(&1u32);
+    assert_eq!(res, Err(Ok(Error::TournamentCancelled.into())));
+}
+
+#[test]
+fn test_no_show_walkover() {
+    let s = setup();
+    let players = fill(&s, 4);
+    let present = players.get(1).unwrap();
+    let deadline = s.client.get_tournament(&1u32).matches.get(0).unwrap().deadline;
+
+    let res = s.client.try_claim_walkover(&1u32, &0u32, &present);
+    assert_eq!(res, Err(Ok(Error::MatchWindowOpen.into())));
+    // A seeded bracket cannot be called off while matches can still start.
+    let res = s.client.try_cancel_tournament(&1u32);
+    assert_eq!(res, Err(Ok(Error::MatchWindowOpen.into())));
+
+    s.env.ledger().set_sequence_number(deadline + 1);
+    let outsider = players.get(2).unwrap();
+    let res = s.client.try_claim_walkover(&1u32, &0u32, &outsider);
+    assert_eq!(res, Err(Ok(Error::NotInMatch.into())));
+    s.client.claim_walkover(&1u32, &0u32, &present);
+    let state = s.client.get_tournament(&1u32);
+    assert_eq!(state.matches.get(0).unwrap().winner, Some(present.clone()));
+
+    // A match already under way is left to the game's own timeouts.
+    let room_id = s.client.start_match(&1u32, &1u32);
+    let res = s.client.try_claim_walkover(&1u32, &1u32, &outsider);
+    assert_eq!(res, Err(Ok(Error::MatchStarted.into())));
+    s.game.finish(&room_id, &Some(outsider.clone()));
+    s.client.report_match(&1u32, &1u32);
+
+    let state = s.client.get_tournament(&1u32);
+    assert_eq!(state.round, 1);
+    let last = state.matches.get(0).unwrap();
+    assert_eq!((last.player_a, last.player_b), (present, outsider));
+    assert_eq!(last.deadline, deadline + 1 + NO_SHOW_LEDGERS);
+}
+
+#[test]
+fn test_abandoned_match_allows_cancel() {
+    let s = setup();
+    let players = fill(&s, 2);
+    let deadline = s.client.get_tournament(&1u32).matches.get(0).unwrap().deadline;
+
+    // Past the deadline a player could still claim the walkover.
+    s.env.ledger().set_sequence_number(deadline + NO_SHOW_LEDGERS);
+    let res = s.client.try_cancel_tournament(&1u32);
+    assert_eq!(res, Err(Ok(Error::MatchWindowOpen.into())));
+
+    // Nobody did, so the organizer refunds everyone.
+    s.env.ledger().set_sequence_number(deadline + NO_SHOW_LEDGERS + 1);
+    s.client.cancel_tournament(&1u32);
+    for player in players.iter() {
+        assert_eq!(s.token.balance(&player), STAKE);
+    }
+    let res = s.client.try_start_match(&1u32, &0u32);
+    assert_eq!(res, Err(Ok(Error::TournamentCancelled.into())));
+}
@@ -179,11 +179,13 @@ export function MyGameGame({
 
     let cancelled = false;
     (async () => {
+      const rulesHash = await myGameService.getRulesHash(roomId);
       for (const dig of myDigs) {
         if (cancelled) return;
         const ownerHash = await addressToFieldHash(opponentLocation.ownerAddress);
         const computed = await generateCommitment(
-          roomId, dig.island_id, dig.tile_id, ownerHash, opponentLocation.salt,
+          roomId, room.nonce, rulesHash, dig.island_id, dig.tile_id, ownerHash,
+          opponentLocation.salt,
         );
         if (computed === opponentLocation.commitment) {
           if (!cancelled) setDiscoveredTreasure(opponentLocation);
@@ -205,9 +207,13 @@ export function MyGameGame({
       try {
         setLoading(true);
         setError(null);
+        if (!room) throw new Error('Room not loaded');
         const salt = generateSalt();
         const ownerHash = await addressToFieldHash(userAddress);
-        const commitment = await generateCommitment(roomId, buryIsland, buryTile, ownerHash, salt);
+        const rulesHash = await myGameService.getRulesHash(roomId);
+        const commitment = await generateCommitment(
+          roomId, room.nonce, rulesHash, buryIsland, buryTile, ownerHash, salt,
+        );
 
         // Save locally (never sent to server)
         const location: TreasureLocation = {
@@ -244,15 +250,17 @@ export function MyGameGame({
         await loadRoom();
 
         // ---- Client-side discovery check (hash verification) ----
-        // Compute hash(room_id, island_id, tile_id, salt) for the just-dug tile
+        // Compute hash(room_id, nonce, rules_hash, island_id, tile_id, salt) for the just-dug tile
         // and compare against the opponent's stored commitment.
         if (!discoveredTreasure && room) {
           const opponentAddress = isPlayerA ? room.player_b : room.player_a;
           const opponentLocation = treasureVault.getByOwner(roomId, opponentAddress);
           if (opponentLocation) {
             const ownerHash = await addressToFieldHash(opponentLocation.ownerAddress);
+            const rulesHash = await myGameService.getRulesHash(roomId);
             const computed = await generateCommitment(
-              roomId, digIsland, digTile, ownerHash, opponentLocation.salt,
+              roomId, room.nonce, rulesHash, digIsland, digTile, ownerHash,
+              opponentLocation.salt,
             );
             if (computed === opponentLocation.commitment) {
               setDiscoveredTreasure(opponentLocation);
@@ -293,8 +301,11 @@ export function MyGameGame({
 
         // ---- Hash pre-verification (MANDATORY before contract call) ----
         const ownerHash = await addressToFieldHash(discoveredTreasure.ownerAddress);
+        const rulesHash = await myGameService.getRulesHash(roomId);
         const recomputed = await generateCommitment(
           roomId,
+          room.nonce,
+          rulesHash,
           discoveredTreasure.islandId,
           discoveredTreasure.tileId,
           ownerHash,
@@ -40,7 +40,190 @@ export const networks = {
   },
 } as const;
 
-export type DataKey = {tag: "Room", values: readonly [u32]} | {tag: "Commitment", values: readonly [u32, boolean]} | {tag: "Admin", values: void} | {tag: "GameHubAddress", values: void};
+export const Errors = {
+  1: {message:"RoomExists"},
+  2: {message:"RoomNotFound"},
+  3: {message:"RoomFull"},
+  4: {message:"SelfPlay"},
+  5: {message:"WrongPhase"},
+  6: {message:"NotYourTurn"},
+  7: {message:"AlreadyDug"},
+  8: {message:"AlreadyBuried"},
+  9: {message:"InvalidIsland"},
+  10: {message:"InvalidTile"},
+  11: {message:"CommitmentMismatch"},
+  12: {message:"NotAPlayer"},
+  13: {message:"GameEnded"},
+  14: {message:"NoOpponent"},
+  15: {message:"Unauthorized"},
+  16: {message:"BadSignature"},
+  17: {message:"BlsKeyMissing"},
+  18: {message:"InvalidAmount"},
+  19: {message:"AlreadyReported"},
+  20: {message:"TooManyReports"},
+  21: {message:"NotBuried"},
+  22: {message:"WrongMode"},
+  23: {message:"RoundNotReady"},
+  24: {message:"AlreadyCommitted"},
+  25: {message:"InvalidConfig"},
+  26: {message:"BudgetExhausted"},
+  27: {message:"BudgetRemaining"},
+  28: {message:"WrongPhaseExpectedWaiting"},
+  29: {message:"WrongPhaseExpectedBurying"},
+  30: {message:"WrongPhaseExpectedPlaying"},
+  31: {message:"WrongPhaseExpectedEnded"},
+  32: {message:"NotPlayerA"},
+  33: {message:"NotPlayerB"},
+  34: {message:"CommitmentMissing"},
+  35: {message:"AdminStillActive"},
+  36: {message:"NoPendingDig"},
+  37: {message:"NotEnoughActions"},
+  38: {message:"ReputationTooLow"},
+  39: {message:"TooManyObservers"},
+  40: {message:"RoomFrozen"},
+  41: {message:"NoDigToRollBack"},
+  42: {message:"BurialKeyMissing"},
+  43: {message:"BoardNotExhausted"},
+  44: {message:"RevealWindowOpen"},
+  45: {message:"RevealWindowClosed"},
+  46: {message:"StakeMismatch"},
+  47: {message:"TurnNotExpired"},
+  48: {message:"BurialWindowOpen"},
+  49: {message:"AlreadyFound"},
+  50: {message:"BadInviteCode"},
+  51: {message:"SeriesNotFound"},
+  52: {message:"SeriesExists"},
+  53: {message:"SeriesOver"},
+  54: {message:"SeriesGameLive"},
+  55: {message:"BettorIsPlayer"},
+  56: {message:"BetSideMismatch"},
+  57: {message:"NothingToClaim"},
+  58: {message:"AlreadyQueued"},
+  59: {message:"NotQueued"},
+  60: {message:"ChallengeExists"},
+  61: {message:"ChallengeNotFound"},
+  62: {message:"Paused"},
+  63: {message:"NoPendingAdmin"},
+  64: {message:"HubNotApproved"},
+  65: {message:"NoPendingReport"},
+  66: {message:"HubUnavailable"},
+  67: {message:"FeeTooHigh"},
+  68: {message:"HubEscrows"},
+  69: {message:"BadOpening"},
+};
+
+/**
+ * A record of a single dig action.
+ */
+export interface DigRecord {
+  digger: string;
+  island_id: u32;
+  tile_id: u32;
+}
+
+/**
+ * How burial commitments are computed for a room.
+ */
+export enum CommitmentScheme {
+  Sha256 = 0,
+  Poseidon2 = 1,
+  LegacySha256 = 2,
+}
+
+/**
+ * How players take turns digging.
+ */
+export enum DigMode {
+  Alternating = 0,
+  Simultaneous = 1,
+  Committed = 2,
+}
+
+/**
+ * Who hides and who seeks.
+ */
+export enum Roles {
+  Duel = 0,
+  AHides = 1,
+  BHides = 2,
+}
+
+/**
+ * How much room events carry. Minimal events keep the topics, so
+ * indexers can still follow a room, but drop the payload to save fees.
+ */
+export enum EventVerbosity {
+  Default = 0,
+  Full = 1,
+  Minimal = 2,
+}
+
+/**
+ * Options fixed when a room is created.
+ */
+export interface RoomConfig {
+  action_points: u32;
+  bury_timeout_ledgers: u32;
+  commitment_scheme: CommitmentScheme;
+  derived_salts: boolean;
+  dig_budget: u32;
+  dig_mode: DigMode;
+  event_verbosity: EventVerbosity;
+  guess_mode: boolean;
+  hider_odds_bps: u32;
+  hub: Option<string>;
+  hubless: boolean;
+  invite_hash: Option<Buffer>;
+  min_completed: u32;
+  min_score_bps: u32;
+  reveal_window_ledgers: u32;
+  roles: Roles;
+  signed_burials: boolean;
+  stake_token: Option<string>;
+  treasures: u32;
+  turn_timeout_ledgers: u32;
+}
+
+/**
+ * One of a player's own treasures, opened once the game no longer
+ * depends on it to prove their burial held real treasures.
+ */
+export interface TreasureLeaf {
+  island_id: u32;
+  salt: Buffer;
+  tile_id: u32;
+}
+
+/**
+ * One room in a `create_rooms_batch` call, seating `player_a`.
+ */
+export interface RoomRequest {
+  config: RoomConfig;
+  player_a: string;
+  player_a_points: i128;
+  room_id: u32;
+}
+
+/**
+ * A committed dig awaiting its reveal, in simultaneous or committed-dig
+ * rooms.
+ */
+export interface PendingDig {
+  commitment: Buffer;
+  island_id: u32;
+  revealed: boolean;
+  tile_id: u32;
+}
+
+/**
+ * Progress of the current simultaneous-dig round.
+ */
+export interface RoundState {
+  committed_a: boolean;
+  committed_b: boolean;
+  revealed_a: boolean;
+  revealed_b: boolean;
+}
 
 /**
  * Full room state.
@@ -48,71 +231,472 @@ export type DataKey = {tag: "Room", values: readonly [u32]} | {tag: "Commitment"
  * `phase` values:
  * 0 = Waiting (created, waiting for Player B or start)
  * 1 = Burying  (both players submit commitments)
- * 2 = Playing  (turn-based digging)
+ * 2 = Playing  (turn-based or simultaneous-round digging)
  * 3 = Ended
+ * 4 = Draw     (board exhausted, reveal window closed unclaimed; or
+ * aborted, nobody having buried in time)
  */
 export interface Room {
+  actions_left: u32;
+  config: RoomConfig;
+  created_nonce: Buffer;
   digs: Array<DigRecord>;
+  exhausted_at: Option<u32>;
+  frozen: boolean;
+  frozen_at: u32;
   game_active: boolean;
   has_commitment_a: boolean;
   has_commitment_b: boolean;
+  hubless: boolean;
   island_tile_counts: Array<u32>;
+  nonce: Buffer;
   phase: u32;
   player_a: string;
   player_a_points: i128;
   player_b: string;
   player_b_points: i128;
+  rematch_of: Option<u32>;
   room_id: u32;
+  series_id: Option<u32>;
+  turn_deadline: u32;
   turn_is_a: boolean;
   winner: string;
 }
 
-export const Errors = {
-  1: {message:"RoomExists"},
-  2: {message:"RoomNotFound"},
-  3: {message:"RoomFull"},
-  4: {message:"SelfPlay"},
-  5: {message:"WrongPhase"},
-  6: {message:"NotYourTurn"},
-  7: {message:"AlreadyDug"},
-  8: {message:"AlreadyBuried"},
-  9: {message:"InvalidIsland"},
-  10: {message:"InvalidTile"},
-  11: {message:"CommitmentMismatch"},
-  12: {message:"NotAPlayer"},
-  13: {message:"GameEnded"},
-  14: {message:"NoOpponent"},
-  15: {message:"Unauthorized"},
-};
+/**
+ * `Room` as it was first released, before the config, nonce and
+ * admin flags were added. Returned by `get_room_v1` so consumers built
+ * against that layout keep decoding while they migrate.
+ */
+export interface RoomV1 {
+  digs: Array<DigRecord>;
+  game_active: boolean;
+  has_commitment_a: boolean;
+  has_commitment_b: boolean;
+  island_tile_counts: Array<u32>;
+  phase: u32;
+  player_a: string;
+  player_a_points: i128;
+  player_b: string;
+  player_b_points: i128;
+  room_id: u32;
+  turn_is_a: boolean;
+  winner: string;
+}
 
 /**
- * A record of a single dig action.
+ * Which Game Hub interface a hub implements; see `set_hub_kind`.
  */
-export interface DigRecord {
-  digger: string;
+export enum HubKind {
+  Legacy = 0,
+  Extended = 1,
+  Session = 2,
+}
+
+/**
+ * A game outcome owed to a Game Hub, by session.
+ */
+export type HubReport = {tag: "Ended", values: readonly [u32, boolean]} | {tag: "Drawn", values: readonly [u32]} | {tag: "Aborted", values: readonly [u32]};
+
+/**
+ * A hub report that failed when its game finished, kept for
+ * `retry_hub_report`.
+ */
+export interface PendingHubReport {
+  hub: string;
+  report: HubReport;
+}
+
+/**
+ * A post-game tip from one player to the other.
+ */
+export interface Tip {
+  amount: i128;
+  from: string;
+  to: string;
+  token: string;
+}
+
+/**
+ * Spectator stakes on a room, per side, in the room's stake token.
+ */
+export interface BetBook {
+  total_a: i128;
+  total_b: i128;
+}
+
+/**
+ * One spectator's stake on a room.
+ */
+export interface Bet {
+  amount: i128;
+  on_player_a: boolean;
+}
+
+/**
+ * A player waiting in the matchmaking queue.
+ */
+export interface QueueEntry {
+  player: string;
+  points: i128;
+}
+
+/**
+ * Ledger sequence at which each player buried, or `None` if not yet.
+ */
+export interface BurialLedgers {
+  player_a: Option<u32>;
+  player_b: Option<u32>;
+}
+
+/**
+ * A verified pre-image of a player's commitment.
+ */
+export interface Reveal {
   island_id: u32;
+  owner: string;
+  salt: Buffer;
   tile_id: u32;
 }
 
+/**
+ * Both commitments and all reveals of an ended room, for third-party
+ * verification.
+ */
+export interface PublishedCommitments {
+  commitment_a: Option<Buffer>;
+  commitment_b: Option<Buffer>;
+  reveals: Array<Reveal>;
+}
+
+/**
+ * A report filed against a finished game.
+ */
+export interface GameReport {
+  ledger: u32;
+  reason: string;
+  reporter: string;
+}
+
+/**
+ * Reports one address has filed in its current window.
+ */
+export interface ReportWindow {
+  count: u32;
+  start: u32;
+}
+
+/**
+ * The contract's effective configuration, read in one call by
+ * `get_config`. `set_config` writes the admin-tunable settings; the fixed
+ * ones are reported for reference and must be passed back unchanged.
+ */
+export interface Config {
+  bury_timeout_ledgers: u32;
+  event_verbosity: EventVerbosity;
+  fee_bps: u32;
+  game_hub: Option<string>;
+  max_recent_events: u32;
+  max_reports_per_reporter: u32;
+  paused: boolean;
+  reveal_window_ledgers: u32;
+  treasury: Option<string>;
+  turn_timeout_ledgers: u32;
+  version: u32;
+}
+
+/**
+ * Where a page starts in a list endpoint and how many items it holds.
+ * A `limit` of 0, or above the contract's page cap, returns a full page.
+ * Lists with a fixed cap, such as observers and recent events, are
+ * returned whole instead.
+ */
+export interface Cursor {
+  limit: u32;
+  start: u32;
+}
+
+/**
+ * A page of a room's dig log. `next_cursor` is the `start` of the
+ * following page, or `None` on the last one.
+ */
+export interface DigPage {
+  items: Array<DigRecord>;
+  next_cursor: Option<u32>;
+}
+
+/**
+ * A page of a room's tips; see `DigPage`.
+ */
+export interface TipPage {
+  items: Array<Tip>;
+  next_cursor: Option<u32>;
+}
+
+/**
+ * A page of a room's reports; see `DigPage`.
+ */
+export interface ReportPage {
+  items: Array<GameReport>;
+  next_cursor: Option<u32>;
+}
+
+/**
+ * A page of the lobby's open rooms; see `DigPage`. Rooms that expired
+ * while listed are skipped, so a page may hold fewer than `limit`.
+ */
+export interface RoomPage {
+  items: Array<Room>;
+  next_cursor: Option<u32>;
+}
+
+/**
+ * A page of a player's room ids; see `RoomPage`.
+ */
+export interface RoomIdPage {
+  items: Array<u32>;
+  next_cursor: Option<u32>;
+}
+
+/**
+ * A room together with its per-player burial status, the current
+ * simultaneous-dig round and the effective config, returned by
+ * `get_full_view`.
+ */
+export interface FullView {
+  burial: BurialLedgers;
+  config: Config;
+  room: Room;
+  round: RoundState;
+}
+
+/**
+ * Ledger entries a room currently occupies, returned by
+ * `get_storage_report`. `bytes` is the XDR size of keys and values, a
+ * close estimate of what rent is charged on.
+ */
+export interface StorageReport {
+  bytes: u32;
+  entries: u32;
+}
+
+/**
+ * What `migrate` would do with a list of rooms, from
+ * `preview_migration`.
+ */
+export interface MigrationPreview {
+  converted: Array<u32>;
+  current: u32;
+  from_version: u32;
+  invalid: Array<u32>;
+  missing: u32;
+  scheme: CommitmentScheme;
+  to_version: u32;
+}
+
+/**
+ * A backup admin and how long the admin must be inactive before the
+ * backup can claim the role.
+ */
+export interface AdminBackup {
+  backup: string;
+  inactivity_ledgers: u32;
+}
+
+/**
+ * A best-of-N match between two players, one room per game. The hub
+ * sees the whole series as one session: `start_game` on the first
+ * game and `end_game` once a player reaches `wins_needed`. Drawn games
+ * count for neither player.
+ */
+export interface Series {
+  player_a: string;
+  player_b: string;
+  rooms: Array<u32>;
+  series_id: u32;
+  session_id: Option<u32>;
+  winner: Option<string>;
+  wins_a: u32;
+  wins_b: u32;
+  wins_needed: u32;
+}
+
+/**
+ * How reliably an address finishes the games it starts. Every start
+ * counts until the game ends with a winner, so abandoned rooms (and any
+ * still in play) weigh the score down.
+ */
+export interface Reputation {
+  completed: u32;
+  score_bps: u32;
+  started: u32;
+}
+
+/**
+ * A player's results across every game that ended with a winner or a
+ * draw. `total_points_won` sums the opponent's points of each win.
+ */
+export interface PlayerStats {
+  draws: u32;
+  games: u32;
+  losses: u32;
+  total_points_won: i128;
+  wins: u32;
+}
+
+/**
+ * Canonical record of a finished room, exported XDR-encoded by
+ * `export_attestation` for co-signing and consumption by other chains.
+ */
+export interface Attestation {
+  contract: string;
+  phase: u32;
+  player_a: string;
+  player_a_points: i128;
+  player_b: string;
+  player_b_points: i128;
+  room_id: u32;
+  transcript_hash: Buffer;
+  winner: string;
+}
+
+export type DataKey = {tag: "Room", values: readonly [u32]} | {tag: "Commitment", values: readonly [u32, boolean]} | {tag: "Opened", values: readonly [u32, boolean]} | {tag: "Admin", values: void} | {tag: "PendingAdmin", values: void} | {tag: "StateVersion", values: void} | {tag: "GameHubAddress", values: void} | {tag: "ApprovedHubs", values: void} | {tag: "HubKind", values: readonly [string]} | {tag: "Events", values: readonly [u32]} | {tag: "BlsKey", values: readonly [string]} | {tag: "Tips", values: readonly [u32]} | {tag: "Reports", values: readonly [u32]} | {tag: "BuriedAt", values: readonly [u32, boolean]} | {tag: "Reveals", values: readonly [u32]} | {tag: "NonceCounter", values: void} | {tag: "RoundDig", values: readonly [u32, boolean]} | {tag: "MaxRecentEvents", values: void} | {tag: "MaxReportsPerReporter", values: void} | {tag: "TurnTimeout", values: void} | {tag: "BuryTimeout", values: void} | {tag: "RevealWindow", values: void} | {tag: "ReportWindow", values: readonly [string]} | {tag: "AdminBackup", values: void} | {tag: "AdminLastActive", values: void} | {tag: "Reputation", values: readonly [string]} | {tag: "Observers", values: readonly [u32]} | {tag: "EventVerbosity", values: void} | {tag: "BurialKey", values: readonly [string]} | {tag: "OpenRooms", values: void} | {tag: "PlayerRooms", values: readonly [string]} | {tag: "RoomCounter", values: void} | {tag: "Series", values: readonly [u32]} | {tag: "BetBook", values: readonly [u32]} | {tag: "PendingHubReport", values: readonly [u32]} | {tag: "Bet", values: readonly [u32, string]} | {tag: "Rating", values: readonly [string]} | {tag: "Stats", values: readonly [string]} | {tag: "LeaderboardAddress", values: void} | {tag: "Queue", values: readonly [u32]} | {tag: "Challenge", values: readonly [string, string]} | {tag: "Paused", values: void} | {tag: "FeeBps", values: void} | {tag: "Treasury", values: void} | {tag: "Fees", values: void};
+
+/**
+ * The data body of a published [`RoomEventPublished`] or
+ * [`ObservedRoomEvent`].
+ */
+export interface RoomEventData {
+  actor: string;
+  ledger: u32;
+}
+
+/**
+ * An entry in a room's event outbox.
+ *
+ * Mirrors the `room_event` contract events so clients that poll instead of
+ * subscribing can still catch up on what happened in a room.
+ */
+export interface RoomEvent {
+  actor: string;
+  kind: string;
+  ledger: u32;
+}
+
 export interface Client {
-  get_hub: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
-  set_hub: ({new_hub}: {new_hub: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
-  upgrade: ({new_wasm_hash}: {new_wasm_hash: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
-  get_game: ({room_id}: {room_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Room>>
-  get_room: ({room_id}: {room_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Room>>
   get_admin: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
-  set_admin: ({new_admin}: {new_admin: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  propose_admin: ({new_admin}: {new_admin: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  accept_admin: (options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  get_pending_admin: (options?: MethodOptions) => Promise<AssembledTransaction<Option<string>>>
+  get_hub: (options?: MethodOptions) => Promise<AssembledTransaction<Option<string>>>
+  set_hub: ({new_hub}: {new_hub: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  approve_hub: ({hub}: {hub: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  revoke_hub: ({hub}: {hub: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  get_approved_hubs: (options?: MethodOptions) => Promise<AssembledTransaction<Array<string>>>
+  set_hub_kind: ({hub, kind}: {hub: string, kind: HubKind}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  get_hub_kind: ({hub}: {hub: string}, options?: MethodOptions) => Promise<AssembledTransaction<HubKind>>
+  get_leaderboard: (options?: MethodOptions) => Promise<AssembledTransaction<Option<string>>>
+  set_leaderboard: ({leaderboard}: {leaderboard: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  get_config: (options?: MethodOptions) => Promise<AssembledTransaction<Config>>
+  set_config: ({config}: {config: Config}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  set_admin_backup: ({backup, inactivity_ledgers}: {backup: string, inactivity_ledgers: u32}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  get_admin_backup: (options?: MethodOptions) => Promise<AssembledTransaction<Option<AdminBackup>>>
+  claim_admin: ({backup}: {backup: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  set_room_hubless: ({room_id}: {room_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  set_room_frozen: ({room_id, frozen}: {room_id: u32, frozen: boolean}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  pause: (options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  unpause: (options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  is_paused: (options?: MethodOptions) => Promise<AssembledTransaction<boolean>>
+  set_fee_bps: ({fee_bps}: {fee_bps: u32}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  get_fee_bps: (options?: MethodOptions) => Promise<AssembledTransaction<u32>>
+  set_treasury: ({treasury}: {treasury: Option<string>}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  get_treasury: (options?: MethodOptions) => Promise<AssembledTransaction<Option<string>>>
+  get_fees: ({token}: {token: string}, options?: MethodOptions) => Promise<AssembledTransaction<i128>>
+  withdraw_fees: ({to}: {to: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  create_room: ({room_id, player_a, player_a_points}: {room_id: u32, player_a: string, player_a_points: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Room>>
+  create_room_auto: ({player_a, player_a_points}: {player_a: string, player_a_points: i128}, options?: MethodOptions) => Promise<AssembledTransaction<u32>>
+  create_room_with_config: ({room_id, player_a, player_a_points, config}: {room_id: u32, player_a: string, player_a_points: i128, config: RoomConfig}, options?: MethodOptions) => Promise<AssembledTransaction<Room>>
+  create_rooms_batch: ({rooms}: {rooms: Array<RoomRequest>}, options?: MethodOptions) => Promise<AssembledTransaction<Array<u32>>>
   join_room: ({room_id, player_b, player_b_points}: {room_id: u32, player_b: string, player_b_points: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Room>>
+  join_private_room: ({room_id, player_b, player_b_points, invite_code}: {room_id: u32, player_b: string, player_b_points: i128, invite_code: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Room>>
+  cancel_room: ({room_id, player_a}: {room_id: u32, player_a: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  rematch: ({room_id}: {room_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Room>>
   start_room: ({room_id, player_a, player_b, player_a_points, player_b_points}: {room_id: u32, player_a: string, player_b: string, player_a_points: i128, player_b_points: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Room>>
-  create_room: ({room_id, player_a, player_a_points}: {room_id: u32, player_a: string, player_a_points: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Room>>
+  register_bls_key: ({player, public_key, proof_of_possession}: {player: string, public_key: Buffer, proof_of_possession: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  register_burial_key: ({player, public_key}: {player: string, public_key: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  get_start_message: ({room_id, player_a_points, player_b_points}: {room_id: u32, player_a_points: i128, player_b_points: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Buffer>>
+  start_room_aggregated: ({room_id, player_a_points, player_b_points, signature}: {room_id: u32, player_a_points: i128, player_b_points: i128, signature: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Room>>
   bury_treasure: ({room_id, player, commitment}: {room_id: u32, player: string, commitment: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  bury_treasure_signed: ({room_id, player, commitment, signature}: {room_id: u32, player: string, commitment: Buffer, signature: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  rebury_treasure: ({room_id, player, commitment}: {room_id: u32, player: string, commitment: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
   dig: ({room_id, player, island_id, tile_id}: {room_id: u32, player: string, island_id: u32, tile_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  end_turn: ({room_id, player}: {room_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  rollback_dig: ({room_id}: {room_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  commit_dig: ({room_id, player, commitment}: {room_id: u32, player: string, commitment: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  reveal_dig: ({room_id, player, island_id, tile_id, salt}: {room_id: u32, player: string, island_id: u32, tile_id: u32, salt: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  commit_round_dig: ({room_id, player, commitment}: {room_id: u32, player: string, commitment: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  reveal_round_dig: ({room_id, player, island_id, tile_id, salt}: {room_id: u32, player: string, island_id: u32, tile_id: u32, salt: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  get_round_state: ({room_id}: {room_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<RoundState>>
+  claim_hider_win: ({room_id, hider, leaves}: {room_id: u32, hider: string, leaves: Array<TreasureLeaf>}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  open_treasures: ({room_id, player, leaves}: {room_id: u32, player: string, leaves: Array<TreasureLeaf>}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  finalize_draw: ({room_id, caller}: {room_id: u32, caller: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  claim_timeout: ({room_id, claimant}: {room_id: u32, claimant: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  forfeit: ({room_id, player}: {room_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  claim_abandoned: ({room_id, player}: {room_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  abort_room: ({room_id, player}: {room_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  retry_hub_report: ({room_id}: {room_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  get_pending_hub_report: ({room_id}: {room_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Option<PendingHubReport>>>
   reveal_treasure: ({room_id, player, island_id, tile_id, salt}: {room_id: u32, player: string, island_id: u32, tile_id: u32, salt: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  reveal_treasure_proof: ({room_id, player, island_id, tile_id, salt, proof, leaves}: {room_id: u32, player: string, island_id: u32, tile_id: u32, salt: Buffer, proof: Array<Buffer>, leaves: Array<TreasureLeaf>}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  reveal_treasure_derived: ({room_id, player, island_id, tile_id, salt_signature}: {room_id: u32, player: string, island_id: u32, tile_id: u32, salt_signature: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  tip: ({room_id, from, token, amount}: {room_id: u32, from: string, token: string, amount: i128}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  report_game: ({room_id, reporter, reason}: {room_id: u32, reporter: string, reason: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  enter_queue: ({player, points}: {player: string, points: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Option<u32>>>
+  leave_queue: ({player, points}: {player: string, points: i128}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  get_queue: ({points}: {points: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Option<QueueEntry>>>
+  challenge: ({challenger, opponent, points}: {challenger: string, opponent: string, points: i128}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  accept_challenge: ({challenger, opponent, opponent_points}: {challenger: string, opponent: string, opponent_points: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Room>>
+  decline_challenge: ({challenger, opponent}: {challenger: string, opponent: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  get_challenge: ({challenger, opponent}: {challenger: string, opponent: string}, options?: MethodOptions) => Promise<AssembledTransaction<Option<i128>>>
+  create_series: ({series_id, player_a, player_b, wins_needed}: {series_id: u32, player_a: string, player_b: string, wins_needed: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Series>>
+  create_series_room: ({series_id, room_id, player_a_points, player_b_points, config}: {series_id: u32, room_id: u32, player_a_points: i128, player_b_points: i128, config: RoomConfig}, options?: MethodOptions) => Promise<AssembledTransaction<Room>>
+  get_series: ({series_id}: {series_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Series>>
+  place_bet: ({room_id, bettor, on_player_a, amount}: {room_id: u32, bettor: string, on_player_a: boolean, amount: i128}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  claim_bet: ({room_id, bettor}: {room_id: u32, bettor: string}, options?: MethodOptions) => Promise<AssembledTransaction<i128>>
+  get_bet_book: ({room_id}: {room_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<BetBook>>
+  watch_room: ({room_id, observer}: {room_id: u32, observer: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  unwatch_room: ({room_id, observer}: {room_id: u32, observer: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  get_observers: ({room_id}: {room_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Array<string>>>
+  get_room: ({room_id}: {room_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Room>>
+  get_game: ({room_id}: {room_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Room>>
+  get_tips: ({room_id, cursor}: {room_id: u32, cursor: Cursor}, options?: MethodOptions) => Promise<AssembledTransaction<TipPage>>
+  get_burial_ledgers: ({room_id}: {room_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<BurialLedgers>>
+  get_commitments: ({room_id}: {room_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<PublishedCommitments>>
+  get_reports: ({room_id, cursor}: {room_id: u32, cursor: Cursor}, options?: MethodOptions) => Promise<AssembledTransaction<ReportPage>>
+  get_digs: ({room_id, cursor}: {room_id: u32, cursor: Cursor}, options?: MethodOptions) => Promise<AssembledTransaction<DigPage>>
+  get_recent_events: ({room_id}: {room_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Array<RoomEvent>>>
+  get_open_rooms: ({cursor}: {cursor: Cursor}, options?: MethodOptions) => Promise<AssembledTransaction<RoomPage>>
+  get_rooms_by_player: ({player, cursor}: {player: string, cursor: Cursor}, options?: MethodOptions) => Promise<AssembledTransaction<RoomIdPage>>
+  verify_commitment: ({room_id, owner, island_id, tile_id, salt}: {room_id: u32, owner: string, island_id: u32, tile_id: u32, salt: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<boolean>>
+  export_attestation: ({room_id}: {room_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Buffer>>
+  get_state_hash: ({room_id}: {room_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Buffer>>
+  get_rules_hash: ({room_id}: {room_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Buffer>>
+  get_salt_message: ({room_id}: {room_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Buffer>>
+  get_full_view: ({room_id}: {room_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<FullView>>
+  get_reputation: ({player}: {player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Reputation>>
+  get_rating: ({player}: {player: string}, options?: MethodOptions) => Promise<AssembledTransaction<u32>>
+  get_player_stats: ({player}: {player: string}, options?: MethodOptions) => Promise<AssembledTransaction<PlayerStats>>
+  get_storage_report: ({room_id}: {room_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<StorageReport>>
+  audit_room: ({room_id}: {room_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Array<string>>>
+  upgrade: ({new_wasm_hash}: {new_wasm_hash: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  migrate: ({room_ids}: {room_ids: Array<u32>}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  preview_migration: ({room_ids}: {room_ids: Array<u32>}, options?: MethodOptions) => Promise<AssembledTransaction<MigrationPreview>>
+  get_version: (options?: MethodOptions) => Promise<AssembledTransaction<u32>>
 }
 
 export class Client extends ContractClient {
   static async deploy<T = Client>(
-    {admin, game_hub}: {admin: string, game_hub: string},
+    {admin, game_hub}: {admin: string, game_hub: Option<string>},
     options: MethodOptions &
       Omit<ContractClientOptions, "contractId"> & {
         wasmHash: Buffer | string;
@@ -124,40 +708,278 @@ export class Client extends ContractClient {
   }
   constructor(public readonly options: ContractClientOptions) {
     super(
-      new ContractSpec([ "AAAAAQAAALFGdWxsIHJvb20gc3RhdGUuCgpgcGhhc2VgIHZhbHVlczoKMCA9IFdhaXRpbmcgKGNyZWF0ZWQsIHdhaXRpbmcgZm9yIFBsYXllciBCIG9yIHN0YXJ0KQoxID0gQnVyeWluZyAgKGJvdGggcGxheWVycyBzdWJtaXQgY29tbWl0bWVudHMpCjIgPSBQbGF5aW5nICAodHVybi1iYXNlZCBkaWdnaW5nKQozID0gRW5kZWQAAAAAAAAAAAAABFJvb20AAAANAAAAAAAAAARkaWdzAAAD6gAAB9AAAAAJRGlnUmVjb3JkAAAAAAAAAAAAAAtnYW1lX2FjdGl2ZQAAAAABAAAAMFdoZXRoZXIgUGxheWVyIEEgaGFzIHN1Ym1pdHRlZCB0aGVpciBjb21taXRtZW50LgAAABBoYXNfY29tbWl0bWVudF9hAAAAAQAAADBXaGV0aGVyIFBsYXllciBCIGhhcyBzdWJtaXR0ZWQgdGhlaXIgY29tbWl0bWVudC4AAAAQaGFzX2NvbW1pdG1lbnRfYgAAAAEAAAAsTnVtYmVyIG9mIHRpbGVzIG9uIGVhY2ggaXNsYW5kIChsZW5ndGggPSAzKS4AAAASaXNsYW5kX3RpbGVfY291bnRzAAAAAAPqAAAABAAAAAAAAAAFcGhhc2UAAAAAAAAEAAAAAAAAAAhwbGF5ZXJfYQAAABMAAAAAAAAAD3BsYXllcl9hX3BvaW50cwAAAAALAAAAAAAAAAhwbGF5ZXJfYgAAABMAAAAAAAAAD3BsYXllcl9iX3BvaW50cwAAAAALAAAAAAAAAAdyb29tX2lkAAAAAAQAAAAAAAAACXR1cm5faXNfYQAAAAAAAAEAAAAAAAAABndpbm5lcgAAAAAAEw==",
-        "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAADwAAABNSb29tIGFscmVhZHkgZXhpc3RzAAAAAApSb29tRXhpc3RzAAAAAAABAAAADlJvb20gbm90IGZvdW5kAAAAAAAMUm9vbU5vdEZvdW5kAAAAAgAAACZSb29tIGlzIGZ1bGwgKFBsYXllciBCIGFscmVhZHkgam9pbmVkKQAAAAAACFJvb21GdWxsAAAAAwAAABlDYW5ub3Qgam9pbiB5b3VyIG93biByb29tAAAAAAAACFNlbGZQbGF5AAAABAAAACBXcm9uZyBnYW1lIHBoYXNlIGZvciB0aGlzIGFjdGlvbgAAAApXcm9uZ1BoYXNlAAAAAAAFAAAADU5vdCB5b3VyIHR1cm4AAAAAAAALTm90WW91clR1cm4AAAAABgAAABBUaWxlIGFscmVhZHkgZHVnAAAACkFscmVhZHlEdWcAAAAAAAcAAAAcQ29tbWl0bWVudCBhbHJlYWR5IHN1Ym1pdHRlZAAAAA1BbHJlYWR5QnVyaWVkAAAAAAAACAAAABRJbnZhbGlkIGlzbGFuZCBpbmRleAAAAA1JbnZhbGlkSXNsYW5kAAAAAAAACQAAABJJbnZhbGlkIHRpbGUgaW5kZXgAAAAAAAtJbnZhbGlkVGlsZQAAAAAKAAAAR0NvbW1pdG1lbnQgbWlzbWF0Y2gg4oCUIHRoZSByZXZlYWwgZG9lcyBub3QgbWF0Y2ggdGhlIGJ1cmllZCBjb21taXRtZW50AAAAABJDb21taXRtZW50TWlzbWF0Y2gAAAAAAAsAAAAjQ2FsbGVyIGlzIG5vdCBhIHBsYXllciBpbiB0aGlzIHJvb20AAAAACk5vdEFQbGF5ZXIAAAAAAAwAAAASR2FtZSBhbHJlYWR5IGVuZGVkAAAAAAAJR2FtZUVuZGVkAAAAAAAADQAAABtQbGF5ZXIgQiBoYXMgbm90IGpvaW5lZCB5ZXQAAAAACk5vT3Bwb25lbnQAAAAAAA4AAAATVW5hdXRob3JpemVkIGNhbGxlcgAAAAAMVW5hdXRob3JpemVkAAAADw==",
-        "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAABAAAAAEAAAAWUm9vbShyb29tX2lkKSDihpIgUm9vbQAAAAAABFJvb20AAAABAAAABAAAAAEAAAAvQ29tbWl0bWVudChyb29tX2lkLCBpc19wbGF5ZXJfYSkg4oaSIEJ5dGVzTjwzMj4AAAAACkNvbW1pdG1lbnQAAAAAAAIAAAAEAAAAAQAAAAAAAAANQWRtaW4gYWRkcmVzcwAAAAAAAAVBZG1pbgAAAAAAAAAAAAAZR2FtZSBIdWIgY29udHJhY3QgYWRkcmVzcwAAAAAAAA5HYW1lSHViQWRkcmVzcwAA",
+      new ContractSpec([ "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAARQAAABNSb29tIGFscmVhZHkgZXhpc3RzAAAAAApSb29tRXhpc3RzAAAAAAABAAAADlJvb20gbm90IGZvdW5kAAAAAAAMUm9vbU5vdEZvdW5kAAAAAgAAACZSb29tIGlzIGZ1bGwgKFBsYXllciBCIGFscmVhZHkgam9pbmVkKQAAAAAACFJvb21GdWxsAAAAAwAAABlDYW5ub3Qgam9pbiB5b3VyIG93biByb29tAAAAAAAACFNlbGZQbGF5AAAABAAAAFtXcm9uZyBnYW1lIHBoYXNlIGZvciB0aGlzIGFjdGlvbi4gTm8gbG9uZ2VyIHJhaXNlZDsgc2VlIHRoZQpgV3JvbmdQaGFzZUV4cGVjdGVkKmAgdmFyaWFudHMuAAAAAApXcm9uZ1BoYXNlAAAAAAAFAAAADU5vdCB5b3VyIHR1cm4AAAAAAAALTm90WW91clR1cm4AAAAABgAAABBUaWxlIGFscmVhZHkgZHVnAAAACkFscmVhZHlEdWcAAAAAAAcAAAAcQ29tbWl0bWVudCBhbHJlYWR5IHN1Ym1pdHRlZAAAAA1BbHJlYWR5QnVyaWVkAAAAAAAACAAAABRJbnZhbGlkIGlzbGFuZCBpbmRleAAAAA1JbnZhbGlkSXNsYW5kAAAAAAAACQAAABJJbnZhbGlkIHRpbGUgaW5kZXgAAAAAAAtJbnZhbGlkVGlsZQAAAAAKAAAAR0NvbW1pdG1lbnQgbWlzbWF0Y2gg4oCUIHRoZSByZXZlYWwgZG9lcyBub3QgbWF0Y2ggdGhlIGJ1cmllZCBjb21taXRtZW50AAAAABJDb21taXRtZW50TWlzbWF0Y2gAAAAAAAsAAAAjQ2FsbGVyIGlzIG5vdCBhIHBsYXllciBpbiB0aGlzIHJvb20AAAAACk5vdEFQbGF5ZXIAAAAAAAwAAAASR2FtZSBhbHJlYWR5IGVuZGVkAAAAAAAJR2FtZUVuZGVkAAAAAAAADQAAABtQbGF5ZXIgQiBoYXMgbm90IGpvaW5lZCB5ZXQAAAAACk5vT3Bwb25lbnQAAAAAAA4AAAATVW5hdXRob3JpemVkIGNhbGxlcgAAAAAMVW5hdXRob3JpemVkAAAADwAAAD1CTFMgcHJvb2Ygb2YgcG9zc2Vzc2lvbiBvciBhZ2dyZWdhdGUgc2lnbmF0dXJlIGRpZCBub3QgdmVyaWZ5AAAAAAAADEJhZFNpZ25hdHVyZQAAABAAAAAqUGxheWVyIGhhcyBub3QgcmVnaXN0ZXJlZCBhIEJMUyBwdWJsaWMga2V5AAAAAAANQmxzS2V5TWlzc2luZwAAAAAAABEAAAAXQW1vdW50IG11c3QgYmUgcG9zaXRpdmUAAAAADUludmFsaWRBbW91bnQAAAAAAAASAAAALVJlcG9ydGVyIGFscmVhZHkgZmlsZWQgYSByZXBvcnQgZm9yIHRoaXMgcm9vbQAAAAAAAA9BbHJlYWR5UmVwb3J0ZWQAAAAAEwAAAD9SZXBvcnRlciBoYXMgdXNlZCB1cCB0aGVpciByZXBvcnRzIGZvciBub3csIG9yIHRoZSByb29tIGlzIGZ1bGwAAAAADlRvb01hbnlSZXBvcnRzAAAAAAAUAAAAJlBsYXllciBoYXMgbm90IGJ1cmllZCBhIGNvbW1pdG1lbnQgeWV0AAAAAAAJTm90QnVyaWVkAAAAAAAAFQAAAC9BY3Rpb24gaXMgbm90IGF2YWlsYWJsZSBpbiB0aGlzIHJvb20ncyBkaWcgbW9kZQAAAAAJV3JvbmdNb2RlAAAAAAAAFgAAADhCb3RoIHBsYXllcnMgbXVzdCBjb21taXQgYmVmb3JlIGVpdGhlciByZXZlYWxzIHRoZWlyIGRpZwAAAA1Sb3VuZE5vdFJlYWR5AAAAAAAAFwAAADdQbGF5ZXIgYWxyZWFkeSBjb21taXR0ZWQgKG9yIHJldmVhbGVkKSBhIGRpZyB0aGlzIHJvdW5kAAAAABBBbHJlYWR5Q29tbWl0dGVkAAAAGAAAACtSb29tIGNvbmZpZ3VyYXRpb24gb3B0aW9ucyBhcmUgaW5jb25zaXN0ZW50AAAAAA1JbnZhbGlkQ29uZmlnAAAAAAAAGQAAACNTZWVrZXIgaGFzIHVzZWQgdXAgdGhlaXIgZGlnIGJ1ZGdldAAAAAAPQnVkZ2V0RXhoYXVzdGVkAAAAABoAAAAaU2Vla2VyIHN0aWxsIGhhcyBkaWdzIGxlZnQAAAAAAA9CdWRnZXRSZW1haW5pbmcAAAAAGwAAAC9BY3Rpb24gbmVlZHMgdGhlIHJvb20gdG8gYmUgV2FpdGluZyBmb3IgcGxheWVycwAAAAAZV3JvbmdQaGFzZUV4cGVjdGVkV2FpdGluZwAAAAAAABwAAAAwQWN0aW9uIG5lZWRzIHRoZSByb29tIHRvIGJlIGluIHRoZSBCdXJ5aW5nIHBoYXNlAAAAGVdyb25nUGhhc2VFeHBlY3RlZEJ1cnlpbmcAAAAAAAAdAAAAMEFjdGlvbiBuZWVkcyB0aGUgcm9vbSB0byBiZSBpbiB0aGUgUGxheWluZyBwaGFzZQAAABlXcm9uZ1BoYXNlRXhwZWN0ZWRQbGF5aW5nAAAAAAAAHgAAACNBY3Rpb24gbmVlZHMgdGhlIGdhbWUgdG8gaGF2ZSBlbmRlZAAAAAAXV3JvbmdQaGFzZUV4cGVjdGVkRW5kZWQAAAAAHwAAADVBZGRyZXNzIGdpdmVuIGFzIFBsYXllciBBIGlzIG5vdCB0aGlzIHJvb20ncyBQbGF5ZXIgQQAAAAAAAApOb3RQbGF5ZXJBAAAAAAAgAAAANUFkZHJlc3MgZ2l2ZW4gYXMgUGxheWVyIEIgaXMgbm90IHRoaXMgcm9vbSdzIFBsYXllciBCAAAAAAAACk5vdFBsYXllckIAAAAAACEAAAAwVGhlIG9wcG9uZW50IGhhcyBubyBjb21taXRtZW50IHRvIHJldmVhbCBhZ2FpbnN0AAAAEUNvbW1pdG1lbnRNaXNzaW5nAAAAAAAAIgAAADhBZG1pbiBoYXMgYWN0ZWQgd2l0aGluIHRoZSBiYWNrdXAncyBpbmFjdGl2aXR5IHRocmVzaG9sZAAAABBBZG1pblN0aWxsQWN0aXZlAAAAIwAAACVQbGF5ZXIgaGFzIG5vIGNvbW1pdHRlZCBkaWcgdG8gcmV2ZWFsAAAAAAAADE5vUGVuZGluZ0RpZwAAACQAAAA3Tm90IGVub3VnaCBhY3Rpb24gcG9pbnRzIGxlZnQgdGhpcyB0dXJuIGZvciB0aGF0IGlzbGFuZAAAAAAQTm90RW5vdWdoQWN0aW9ucwAAACUAAAAvSm9pbmVyJ3MgcmVwdXRhdGlvbiBpcyBiZWxvdyB0aGUgcm9vbSdzIG1pbmltdW0AAAAAEFJlcHV0YXRpb25Ub29Mb3cAAAAmAAAAMFJvb20gYWxyZWFkeSBoYXMgdGhlIG1heGltdW0gbnVtYmVyIG9mIG9ic2VydmVycwAAABBUb29NYW55T2JzZXJ2ZXJzAAAAJwAAABtSb29tIGlzIGZyb3plbiBieSB0aGUgYWRtaW4AAAAAClJvb21Gcm96ZW4AAAAAACgAAAATTm8gZGlnIHRvIHJvbGwgYmFjawAAAAAPTm9EaWdUb1JvbGxCYWNrAAAAACkAAAAvUGxheWVyIGhhcyBub3QgcmVnaXN0ZXJlZCBhbiBlZDI1NTE5IGJ1cmlhbCBrZXkAAAAAEEJ1cmlhbEtleU1pc3NpbmcAAAAqAAAAG0JvYXJkIHN0aWxsIGhhcyB1bmR1ZyB0aWxlcwAAAAARQm9hcmROb3RFeGhhdXN0ZWQAAAAAAAArAAAAKEVuZGdhbWUgcmV2ZWFsIHdpbmRvdyBoYXMgbm90IGNsb3NlZCB5ZXQAAAAQUmV2ZWFsV2luZG93T3BlbgAAACwAAAAgRW5kZ2FtZSByZXZlYWwgd2luZG93IGhhcyBjbG9zZWQAAAASUmV2ZWFsV2luZG93Q2xvc2VkAAAAAAAtAAAAOVN0YXJ0IHBvaW50cyBkaWZmZXIgZnJvbSB0aGUgc3Rha2VzIGVzY3Jvd2VkIGZvciB0aGUgcm9vbQAAAAAAAA1TdGFrZU1pc21hdGNoAAAAAAAALgAAAChPcHBvbmVudCBpcyBub3QgcGFzdCB0aGVpciB0dXJuIGRlYWRsaW5lAAAADlR1cm5Ob3RFeHBpcmVkAAAAAAAvAAAAIkJ1cmlhbCBkZWFkbGluZSBoYXMgbm90IHBhc3NlZCB5ZXQAAAAAABBCdXJpYWxXaW5kb3dPcGVuAAAAMAAAAB1UcmVhc3VyZSB3YXMgYWxyZWFkeSByZXZlYWxlZAAAAAAAAAxBbHJlYWR5Rm91bmQAAAAxAAAAL0ludml0ZSBjb2RlIG1pc3Npbmcgb3Igd3JvbmcgZm9yIGEgcHJpdmF0ZSByb29tAAAAAA1CYWRJbnZpdGVDb2RlAAAAAAAAMgAAABBTZXJpZXMgbm90IGZvdW5kAAAADlNlcmllc05vdEZvdW5kAAAAAAAzAAAAIlNlcmllcyB3aXRoIHRoYXQgSUQgYWxyZWFkeSBleGlzdHMAAAAAAAxTZXJpZXNFeGlzdHMAAAA0AAAAG1NlcmllcyBhbHJlYWR5IGhhcyBhIHdpbm5lcgAAAAAKU2VyaWVzT3ZlcgAAAAAANQAAACNTZXJpZXMnIHByZXZpb3VzIGdhbWUgaGFzIG5vdCBlbmRlZAAAAAAOU2VyaWVzR2FtZUxpdmUAAAAAADYAAAAkUGxheWVycyBjYW5ub3QgYmV0IG9uIHRoZWlyIG93biByb29tAAAADkJldHRvcklzUGxheWVyAAAAAAA3AAAAJUJldHRvciBhbHJlYWR5IGJhY2tzIHRoZSBvdGhlciBwbGF5ZXIAAAAAAAAPQmV0U2lkZU1pc21hdGNoAAAAADgAAAAlTm8gd2lubmluZyBvciByZWZ1bmRhYmxlIGJldCB0byBjbGFpbQAAAAAAAA5Ob3RoaW5nVG9DbGFpbQAAAAAAOQAAACdQbGF5ZXIgaXMgYWxyZWFkeSB3YWl0aW5nIGluIHRoYXQgcXVldWUAAAAADUFscmVhZHlRdWV1ZWQAAAAAAAA6AAAAI1BsYXllciBpcyBub3Qgd2FpdGluZyBpbiB0aGF0IHF1ZXVlAAAAAAlOb3RRdWV1ZWQAAAAAAAA7AAAAO0NoYWxsZW5nZXIgYWxyZWFkeSBoYXMgYSBwZW5kaW5nIGNoYWxsZW5nZSB0byB0aGF0IG9wcG9uZW50AAAAAA9DaGFsbGVuZ2VFeGlzdHMAAAAAPAAAACpObyBwZW5kaW5nIGNoYWxsZW5nZSBiZXR3ZWVuIHRob3NlIHBsYXllcnMAAAAAABFDaGFsbGVuZ2VOb3RGb3VuZAAAAAAAAD0AAAA1Q29udHJhY3QgaXMgcGF1c2VkOiBubyByb29tIGNhbiBiZSBjcmVhdGVkIG9yIHN0YXJ0ZWQAAAAAAAAGUGF1c2VkAAAAAAA+AAAAI05vIGFkbWluIHRyYW5zZmVyIGhhcyBiZWVuIHByb3Bvc2VkAAAAAA5Ob1BlbmRpbmdBZG1pbgAAAAAAPwAAACdIdWIgaXMgbm90IG9uIHRoZSBhZG1pbidzIGFwcHJvdmVkIGxpc3QAAAAADkh1Yk5vdEFwcHJvdmVkAAAAAABAAAAAK1Jvb20gaGFzIG5vIGh1YiByZXBvcnQgd2FpdGluZyB0byBiZSByZXNlbnQAAAAAD05vUGVuZGluZ1JlcG9ydAAAAABBAAAAFUh1YiBjYWxsIGZhaWxlZCBhZ2FpbgAAAAAAAA5IdWJVbmF2YWlsYWJsZQAAAAAAQgAAAB9GZWUgcmF0ZSBpcyBhYm92ZSBgTUFYX0ZFRV9CUFNgAAAAAApGZWVUb29IaWdoAAAAAABDAAAAQlRoZSBodWIgZXNjcm93cyBwb2ludHMsIHdoaWNoIHRha2VzIGJvdGggcGxheWVycyBzaWduaW5nIHRoZSBzdGFydAAAAAAACkh1YkVzY3Jvd3MAAAAAAEQAAABBT3BlbmVkIHRyZWFzdXJlcyBhcmUgbm90IHRoZSBidXJpYWwncyBkaXN0aW5jdCB0aWxlcyBvbiB0aGUgYm9hcmQAAAAAAAAKQmFkT3BlbmluZwAAAAAARQ==",
         "AAAAAQAAACBBIHJlY29yZCBvZiBhIHNpbmdsZSBkaWcgYWN0aW9uLgAAAAAAAAAJRGlnUmVjb3JkAAAAAAAAAwAAAAAAAAAGZGlnZ2VyAAAAAAATAAAAAAAAAAlpc2xhbmRfaWQAAAAAAAAEAAAAAAAAAAd0aWxlX2lkAAAAAAQ=",
-        "AAAAAAAAACZEaWcgYSB0aWxlLiBNdXN0IGJlIHRoZSBjYWxsZXIncyB0dXJuLgAAAAAAA2RpZwAAAAAEAAAAAAAAAAdyb29tX2lkAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAJaXNsYW5kX2lkAAAAAAAABAAAAAAAAAAHdGlsZV9pZAAAAAAEAAAAAA==",
-        "AAAAAAAAAAAAAAAHZ2V0X2h1YgAAAAAAAAAAAQAAABM=",
-        "AAAAAAAAAAAAAAAHc2V0X2h1YgAAAAABAAAAAAAAAAduZXdfaHViAAAAABMAAAAA",
-        "AAAAAAAAAAAAAAAHdXBncmFkZQAAAAABAAAAAAAAAA1uZXdfd2FzbV9oYXNoAAAAAAAD7gAAACAAAAAA",
-        "AAAAAAAAAClBbGlhcyB1c2VkIGJ5IHRoZSBmcm9udGVuZCBzZXJ2aWNlIGxheWVyLgAAAAAAAAhnZXRfZ2FtZQAAAAEAAAAAAAAAB3Jvb21faWQAAAAABAAAAAEAAAfQAAAABFJvb20=",
-        "AAAAAAAAADRSZWFkIHJvb20gc3RhdGUgKHJldHVybnMgdGhlIFJvb20gc3RydWN0IG9yIHBhbmljcykuAAAACGdldF9yb29tAAAAAQAAAAAAAAAHcm9vbV9pZAAAAAAEAAAAAQAAB9AAAAAEUm9vbQ==",
+        "AAAAAwAAAC9Ib3cgYnVyaWFsIGNvbW1pdG1lbnRzIGFyZSBjb21wdXRlZCBmb3IgYSByb29tLgAAAAAAAAAAEENvbW1pdG1lbnRTY2hlbWUAAAADAAAATVNIQS0yNTYocm9vbV9pZCDigJYgbm9uY2Ug4oCWIHJ1bGVzX2hhc2gg4oCWIGlzbGFuZF9pZCDigJYgdGlsZV9pZCDigJYgc2FsdCkuAAAAAAAABlNoYTI1NgAAAAAAAAAAAIpQb3NlaWRvbjIocm9vbV9pZCwgaXNsYW5kX2lkLCB0aWxlX2lkLCBvd25lcl9oYXNoLCBzYWx0LCBub25jZSwKcnVsZXNfaGFzaCkgb3ZlciBCTjI1NCwgY2hlYXAgdG8gcmUtcHJvdmUgaW5zaWRlIGEgemVyby1rbm93bGVkZ2UKY2lyY3VpdC4AAAAAAAlQb3NlaWRvbjIAAAAAAAABAAAAsVNIQS0yNTYocm9vbV9pZCDigJYgaXNsYW5kX2lkIOKAliB0aWxlX2lkIOKAliBzYWx0KSwgdGhlIG9yaWdpbmFsIHNjaGVtZS4KT25seSBzZXQgYnkgYG1pZ3JhdGVgLCBzbyByb29tcyBidXJpZWQgYmVmb3JlIHRoZSB1cGdyYWRlIGNhbiBzdGlsbApyZXZlYWw7IG5ldyByb29tcyBjYW5ub3QgY2hvb3NlIGl0LgAAAAAAAAxMZWdhY3lTaGEyNTYAAAAC",
+        "AAAAAwAAAB9Ib3cgcGxheWVycyB0YWtlIHR1cm5zIGRpZ2dpbmcuAAAAAAAAAAAHRGlnTW9kZQAAAAADAAAALlBsYXllcnMgYWx0ZXJuYXRlIHNpbmdsZSBkaWdzLCBQbGF5ZXIgQSBmaXJzdC4AAAAAAAtBbHRlcm5hdGluZwAAAAAAAAAAgEVhY2ggcm91bmQgYm90aCBwbGF5ZXJzIGNvbW1pdCBhIGRpZywgdGhlbiBib3RoIHJldmVhbDsgdGhlIGRpZ3MgYXJlCmFwcGxpZWQgdG9nZXRoZXIgc28gbmVpdGhlciBwbGF5ZXIgZ2FpbnMgZnJvbSBtb3ZpbmcgZmlyc3QuAAAADFNpbXVsdGFuZW91cwAAAAEAAACVUGxheWVycyBhbHRlcm5hdGUsIGJ1dCBlYWNoIGRpZyBpcyBjb21taXR0ZWQgd2l0aCBgY29tbWl0X2RpZ2AgYW5kCnRoZW4gb3BlbmVkIHdpdGggYHJldmVhbF9kaWdgLCBzbyB0aGUgdGFyZ2V0IGNhbm5vdCBiZSByZWFjdGVkIHRvCmJlZm9yZSBpdCBsYW5kcy4AAAAAAAAJQ29tbWl0dGVkAAAAAAAAAg==",
+        "AAAAAwAAABhXaG8gaGlkZXMgYW5kIHdobyBzZWVrcy4AAAAAAAAABVJvbGVzAAAAAAAAAwAAACpCb3RoIHBsYXllcnMgaGlkZSBhIHRyZWFzdXJlIGFuZCBib3RoIGRpZy4AAAAAAAREdWVsAAAAAAAAAFtQbGF5ZXIgQSBvbmx5IGhpZGVzLCBidXJ5aW5nIGB0cmVhc3VyZXNgIG9mIHRoZW07IFBsYXllciBCIG9ubHkKc2Vla3MsIHdpdGhpbiBhIGRpZyBidWRnZXQuAAAAAAZBSGlkZXMAAAAAAAEAAABbUGxheWVyIEIgb25seSBoaWRlcywgYnVyeWluZyBgdHJlYXN1cmVzYCBvZiB0aGVtOyBQbGF5ZXIgQSBvbmx5CnNlZWtzLCB3aXRoaW4gYSBkaWcgYnVkZ2V0LgAAAAAGQkhpZGVzAAAAAAAC",
+        "AAAAAwAAAINIb3cgbXVjaCByb29tIGV2ZW50cyBjYXJyeS4gTWluaW1hbCBldmVudHMga2VlcCB0aGUgdG9waWNzLCBzbwppbmRleGVycyBjYW4gc3RpbGwgZm9sbG93IGEgcm9vbSwgYnV0IGRyb3AgdGhlIHBheWxvYWQgdG8gc2F2ZSBmZWVzLgAAAAAAAAAADkV2ZW50VmVyYm9zaXR5AAAAAAADAAAAQlBlciByb29tOiBmb2xsb3cgdGhlIGNvbnRyYWN0LXdpZGUgc2V0dGluZy4gQ29udHJhY3Qtd2lkZTogYEZ1bGxgLgAAAAAAB0RlZmF1bHQAAAAAAAAAAAAAAAAERnVsbAAAAAEAAAAAAAAAB01pbmltYWwAAAAAAg==",
+        "AAAAAQAAACVPcHRpb25zIGZpeGVkIHdoZW4gYSByb29tIGlzIGNyZWF0ZWQuAAAAAAAAAAAAAApSb29tQ29uZmlnAAAAAAAUAAAAt0FjdGlvbiBwb2ludHMgcGVyIHR1cm4sIHNwZW50IGJ5IGRpZ3MgYXQgb25lIHBvaW50IHBlciB0ZW4gdGlsZXMgb2YKdGhlIGlzbGFuZCBkdWcuIDAgbWVhbnMgb25lIGRpZyBwZXIgdHVybi4gRHVlbHMgd2l0aG91dCBzaW11bHRhbmVvdXMKZGlnZ2luZyBvbmx5OyBtdXN0IGNvdmVyIHRoZSBjb3N0bGllc3QgaXNsYW5kLgAAAAANYWN0aW9uX3BvaW50cwAAAAAAAAQAAABDTGVkZ2VycyBib3RoIHBsYXllcnMgaGF2ZSB0byBidXJ5IG9uY2UgdGhlIHJvb20gc3RhcnRzOyAwIGFzIGFib3ZlLgAAAAAUYnVyeV90aW1lb3V0X2xlZGdlcnMAAAAEAAAAAAAAABFjb21taXRtZW50X3NjaGVtZQAAAAAAB9AAAAAQQ29tbWl0bWVudFNjaGVtZQAAAHdTYWx0cyBtdXN0IGZvbGxvdyB0aGUgZGVyaXZlZC1zYWx0IHNjaGVtZSAoc2VlIGBnZXRfc2FsdF9tZXNzYWdlYCksCmNoZWNrZWQgb24tY2hhaW4gdGhyb3VnaCBgcmV2ZWFsX3RyZWFzdXJlX2Rlcml2ZWRgLgAAAAANZGVyaXZlZF9zYWx0cwAAAAAAAAEAAABzRGlncyB0aGUgc2Vla2VyIGdldHMgYmVmb3JlIHRoZSBoaWRlciBjYW4gY2xhaW0gdGhlIHdpbi4gTXVzdCBiZQpub24temVybyBmb3IgaGlkZXIvc2Vla2VyIHJvb21zLCBpZ25vcmVkIGluIGR1ZWxzLgAAAAAKZGlnX2J1ZGdldAAAAAAABAAAAAAAAAAIZGlnX21vZGUAAAfQAAAAB0RpZ01vZGUAAAAAAAAAAA9ldmVudF92ZXJib3NpdHkAAAAH0AAAAA5FdmVudFZlcmJvc2l0eQAAAAAAg0EgcmV2ZWFsIHRoYXQgbWlzc2VzIGlzIHJlY29yZGVkIGFzIGEgZGlnIG9mIHRoZSBndWVzc2VkIHRpbGUgYW5kCmNvc3RzIHRoZSB0dXJuLCBpbnN0ZWFkIG9mIHJldmVydGluZy4gTm90IGZvciBzaW11bHRhbmVvdXMgcm9vbXMuAAAAAApndWVzc19tb2RlAAAAAAABAAABI09kZHMgdGhlIGhpZGVyIHBsYXlzIGF0LCBpbiBiYXNpcyBwb2ludHMsIHdlaWdodGluZyBlc2Nyb3dlZCBzdGFrZXMKYnkgcm9sZTogYSBzdXJ2aXZpbmcgaGlkZXIgd2lucyBpdHMgc3Rha2UgdGltZXMgdGhlc2Ugb2RkcyBvdXQgb2YKdGhlIHNlZWtlcidzIHN0YWtlLCBhbmQgdGhlIHNlZWtlciBnZXRzIHRoZSByZXN0IGJhY2ssIHdoaWxlIGEKc2Vla2VyIHdobyBmaW5kcyB0aGUgdHJlYXN1cmUgdGFrZXMgdGhlIHdob2xlIHBvdC4gMCBtZWFucyBldmVuCm9kZHMuIEhpZGVyL3NlZWtlciByb29tcyBvbmx5LgAAAAAOaGlkZXJfb2Rkc19icHMAAAAAAAQAAABvR2FtZSBIdWIgdGhlIHJvb20gcmVwb3J0cyB0bywgb25lIHRoZSBhZG1pbiBoYXMgYXBwcm92ZWQuIGBOb25lYApyZXBvcnRzIHRvIHRoZSBjb250cmFjdCdzIGh1YiAoc2VlIGBzZXRfaHViYCkuAAAAAANodWIAAAAD6AAAABMAAABoVGhlIHJvb20gbmV2ZXIgY2FsbHMgYSBHYW1lIEh1YiwgZm9yIGNhc3VhbCBwbGF5OiBpdHMgcmVzdWx0IHN0YXlzCmxvY2FsLiBDYW5ub3QgYmUgY29tYmluZWQgd2l0aCBgaHViYC4AAAAHaHVibGVzcwAAAAABAAAAlFNIQS0yNTYgb2YgdGhlIGludml0ZSBjb2RlIG9mIGEgcHJpdmF0ZSByb29tLiBQcml2YXRlIHJvb21zIGFyZQpqb2luZWQgdGhyb3VnaCBgam9pbl9wcml2YXRlX3Jvb21gIHdpdGggdGhlIGNvZGUsIGFuZCBhcmUgbmV2ZXIKbGlzdGVkIGluIHRoZSBsb2JieS4AAAALaW52aXRlX2hhc2gAAAAD6AAAA+4AAAAgAAAAQ0NvbXBsZXRlZCBnYW1lcyBhIGpvaW5lciBuZWVkcyAoc2VlIGBSZXB1dGF0aW9uYCk7IDAgYWRtaXRzIGFueW9uZS4AAAAADW1pbl9jb21wbGV0ZWQAAAAAAAAEAAAAQlJlcHV0YXRpb24gc2NvcmUgYSBqb2luZXIgbmVlZHMsIGluIGJhc2lzIHBvaW50czsgMCBhZG1pdHMgYW55b25lLgAAAAAADW1pbl9zY29yZV9icHMAAAAAAAAEAAAAT0xlZGdlcnMgZWl0aGVyIHBsYXllciBtYXkgc3RpbGwgcmV2ZWFsIG9uY2UgdGhlIGJvYXJkIGlzCmV4aGF1c3RlZDsgMCBhcyBhYm92ZS4AAAAAFXJldmVhbF93aW5kb3dfbGVkZ2VycwAAAAAAAAQAAAAAAAAABXJvbGVzAAAAAAAH0AAAAAVSb2xlcwAAAAAAAHdCdXJpYWxzIG11c3QgY29tZSB0aHJvdWdoIGBidXJ5X3RyZWFzdXJlX3NpZ25lZGAsIHNpZ25lZCBieSB0aGUKcGxheWVyJ3MgcmVnaXN0ZXJlZCBidXJpYWwga2V5LCBhbmQgY2Fubm90IGJlIHJlYnVyaWVkLgAAAAAOc2lnbmVkX2J1cmlhbHMAAAAAAAEAAADGVG9rZW4gKFNBQykgZWFjaCBwbGF5ZXIncyBwb2ludHMgYXJlIGVzY3Jvd2VkIGluLiBTdGFrZXMgbW92ZSBpbnRvCnRoZSBjb250cmFjdCBvbiBjcmVhdGUgYW5kIGpvaW4sIHRoZSBwb3QgZ29lcyB0byB0aGUgd2lubmVyLCBhbmQgYQpkcmF3IHJlZnVuZHMgYm90aC4gYE5vbmVgIGtlZXBzIHBvaW50cyBhcyBodWItc2lkZSBudW1iZXJzIG9ubHkuAAAAAAALc3Rha2VfdG9rZW4AAAAD6AAAABMAAAGWVHJlYXN1cmVzIGVhY2ggcGxheWVyIGJ1cmllcywgMCBvciAxIGZvciB0aGUgY2xhc3NpYyBzaW5nbGUKdHJlYXN1cmUuIEFib3ZlIDEsIGEgYnVyaWFsIGNvbW1pdHMgdG8gdGhlIE1lcmtsZSByb290IG9mIG9uZQpjb21taXRtZW50IHBlciB0cmVhc3VyZSwgcmV2ZWFscyBnbyB0aHJvdWdoCmByZXZlYWxfdHJlYXN1cmVfcHJvb2ZgLCBhbmQgZmluZGluZyBhIG1ham9yaXR5IG9mIHRoZSBvcHBvbmVudCdzCnRyZWFzdXJlcyB3aW5zLiBOb3RoaW5nIHNob3dzIGEgcm9vdCBob2xkcyByZWFsIHRyZWFzdXJlcyB1bnRpbCBpdAppcyBvcGVuZWQsIHNvIGEgd2luIG9yIGEgZHJhdyBhbHNvIHRha2VzIG9wZW5pbmcgb25lJ3Mgb3duIChzZWUKYG9wZW5fdHJlYXN1cmVzYCkuIEF0IG1vc3QgYE1BWF9UUkVBU1VSRVNgLgAAAAAACXRyZWFzdXJlcwAAAAAAAAQAAACiTGVkZ2VycyB0aGUgcGxheWVyIG9uIHR1cm4gaGFzIHRvIG1vdmUuIDAgdGFrZXMgdGhlIGNvbnRyYWN0J3MKZGVmYXVsdCAoc2VlIGBDb25maWdgKSB3aGVuIHRoZSByb29tIGlzIGNyZWF0ZWQuIEF0IG1vc3QKYE1BWF9USU1FT1VUX0xFREdFUlNgLCBsaWtlIHRoZSB0d28gYmVsb3cuAAAAAAAUdHVybl90aW1lb3V0X2xlZGdlcnMAAAAE",
+        "AAAAAQAAAHhPbmUgb2YgYSBwbGF5ZXIncyBvd24gdHJlYXN1cmVzLCBvcGVuZWQgb25jZSB0aGUgZ2FtZSBubyBsb25nZXIKZGVwZW5kcyBvbiBpdCB0byBwcm92ZSB0aGVpciBidXJpYWwgaGVsZCByZWFsIHRyZWFzdXJlcy4AAAAAAAAADFRyZWFzdXJlTGVhZgAAAAMAAAAAAAAACWlzbGFuZF9pZAAAAAAAAAQAAAAAAAAABHNhbHQAAAPuAAAAIAAAAAAAAAAHdGlsZV9pZAAAAAAE",
+        "AAAAAQAAADxPbmUgcm9vbSBpbiBhIGBjcmVhdGVfcm9vbXNfYmF0Y2hgIGNhbGwsIHNlYXRpbmcgYHBsYXllcl9hYC4AAAAAAAAAC1Jvb21SZXF1ZXN0AAAAAAQAAAAAAAAABmNvbmZpZwAAAAAH0AAAAApSb29tQ29uZmlnAAAAAAAAAAAACHBsYXllcl9hAAAAEwAAAAAAAAAPcGxheWVyX2FfcG9pbnRzAAAAAAsAAAAAAAAAB3Jvb21faWQAAAAABA==",
+        "AAAAAQAAAExBIGNvbW1pdHRlZCBkaWcgYXdhaXRpbmcgaXRzIHJldmVhbCwgaW4gc2ltdWx0YW5lb3VzIG9yIGNvbW1pdHRlZC1kaWcKcm9vbXMuAAAAAAAAAApQZW5kaW5nRGlnAAAAAAAEAAAAWFNIQS0yNTYoImRpZyIg4oCWIHJvb21faWQg4oCWIG5vbmNlIOKAliB4ZHIoZGlnZ2VyKSDigJYgaXNsYW5kX2lkIOKAliB0aWxlX2lkIOKAlgpzYWx0KS4AAAAKY29tbWl0bWVudAAAAAAD7gAAACAAAAAAAAAACWlzbGFuZF9pZAAAAAAAAAQAAAAAAAAACHJldmVhbGVkAAAAAQAAAAAAAAAHdGlsZV9pZAAAAAAE",
+        "AAAAAQAAAC9Qcm9ncmVzcyBvZiB0aGUgY3VycmVudCBzaW11bHRhbmVvdXMtZGlnIHJvdW5kLgAAAAAAAAAAClJvdW5kU3RhdGUAAAAAAAQAAAAAAAAAC2NvbW1pdHRlZF9hAAAAAAEAAAAAAAAAC2NvbW1pdHRlZF9iAAAAAAEAAAAAAAAACnJldmVhbGVkX2EAAAAAAAEAAAAAAAAACnJldmVhbGVkX2IAAAAAAAE=",
+        "AAAAAQAAATBGdWxsIHJvb20gc3RhdGUuCgpgcGhhc2VgIHZhbHVlczoKMCA9IFdhaXRpbmcgKGNyZWF0ZWQsIHdhaXRpbmcgZm9yIFBsYXllciBCIG9yIHN0YXJ0KQoxID0gQnVyeWluZyAgKGJvdGggcGxheWVycyBzdWJtaXQgY29tbWl0bWVudHMpCjIgPSBQbGF5aW5nICAodHVybi1iYXNlZCBvciBzaW11bHRhbmVvdXMtcm91bmQgZGlnZ2luZykKMyA9IEVuZGVkCjQgPSBEcmF3ICAgICAoYm9hcmQgZXhoYXVzdGVkLCByZXZlYWwgd2luZG93IGNsb3NlZCB1bmNsYWltZWQ7IG9yCmFib3J0ZWQsIG5vYm9keSBoYXZpbmcgYnVyaWVkIGluIHRpbWUpAAAAAAAAAARSb29tAAAAGAAAADpBY3Rpb24gcG9pbnRzIGxlZnQgaW4gdGhlIGN1cnJlbnQgdHVybiAoc2VlIGBSb29tQ29uZmlnYCkuAAAAAAAMYWN0aW9uc19sZWZ0AAAABAAAAAAAAAAGY29uZmlnAAAAAAfQAAAAClJvb21Db25maWcAAAAAAKhDb250cmFjdC1nZW5lcmF0ZWQgbm9uY2UgZHJhd24gd2hlbiB0aGUgcm9vbSBpcyBjcmVhdGVkLiBJdCBiaW5kcwpgc3RhcnRfcm9vbV9hZ2dyZWdhdGVkYCBzaWduYXR1cmVzIHRvIHRoaXMgcm9vbSwgc28gdGhleSBjYW5ub3QKc3RhcnQgYSBsYXRlciByb29tIHRoYXQgcmV1c2VzIHRoZSBpZC4AAAANY3JlYXRlZF9ub25jZQAAAAAAA+4AAAAgAAAAAAAAAARkaWdzAAAD6gAAB9AAAAAJRGlnUmVjb3JkAAAAAAAAkExlZGdlciB0aGUgbGFzdCB0aWxlIHdhcyBkdWcgYXQuIEZyb20gdGhlbiBvbiBlaXRoZXIgcGxheWVyIG1heQpyZXZlYWwsIGZvciB0aGUgY29uZmlnJ3MgYHJldmVhbF93aW5kb3dfbGVkZ2Vyc2A7IGFmdGVyIHRoYXQgdGhlCmdhbWUgaXMgYSBkcmF3LgAAAAxleGhhdXN0ZWRfYXQAAAPoAAAABAAAAGVTZXQgYnkgdGhlIGFkbWluIHdoaWxlIGFuIGluY2lkZW50IGlzIGludmVzdGlnYXRlZDsgbm8gcGxheWVyCmFjdGlvbiBpcyBhY2NlcHRlZCB1bnRpbCBpdCBpcyBjbGVhcmVkLgAAAAAAAAZmcm96ZW4AAAAAAAEAAAB0TGVkZ2VyIHRoZSByb29tIHdhcyBsYXN0IGZyb3plbiBhdC4gVGhhd2luZyBwdXNoZXMgYHR1cm5fZGVhZGxpbmVgCmFuZCBgZXhoYXVzdGVkX2F0YCBiYWNrIGJ5IHRoZSB0aW1lIHNwZW50IGZyb3plbi4AAAAJZnJvemVuX2F0AAAAAAAABAAAAAAAAAALZ2FtZV9hY3RpdmUAAAAAAQAAADBXaGV0aGVyIFBsYXllciBBIGhhcyBzdWJtaXR0ZWQgdGhlaXIgY29tbWl0bWVudC4AAAAQaGFzX2NvbW1pdG1lbnRfYQAAAAEAAAAwV2hldGhlciBQbGF5ZXIgQiBoYXMgc3VibWl0dGVkIHRoZWlyIGNvbW1pdG1lbnQuAAAAEGhhc19jb21taXRtZW50X2IAAAABAAAAZFNldCBieSB0aGUgYWRtaW4gZHVyaW5nIGEgaHViIG91dGFnZTogdGhlIGdhbWUgc2V0dGxlcyBsb2NhbGx5IGFuZApuZXZlciBjYWxscyB0aGUgaHViJ3MgYGVuZF9nYW1lYC4AAAAHaHVibGVzcwAAAAABAAAALE51bWJlciBvZiB0aWxlcyBvbiBlYWNoIGlzbGFuZCAobGVuZ3RoID0gMykuAAAAEmlzbGFuZF90aWxlX2NvdW50cwAAAAAD6gAAAAQAAACIQ29udHJhY3QtZ2VuZXJhdGVkIG5vbmNlIG1peGVkIGludG8gZXZlcnkgY29tbWl0bWVudCBwcmUtaW1hZ2UsIHNvCnRoZSBzYW1lIGNob2ljZSBuZXZlciBoYXNoZXMgdGhlIHNhbWUgaW4gdHdvIHJvb21zLiBaZXJvIHVudGlsIHN0YXJ0LgAAAAVub25jZQAAAAAAA+4AAAAgAAAAAAAAAAVwaGFzZQAAAAAAAAQAAAAAAAAACHBsYXllcl9hAAAAEwAAAAAAAAAPcGxheWVyX2FfcG9pbnRzAAAAAAsAAAAAAAAACHBsYXllcl9iAAAAEwAAAAAAAAAPcGxheWVyX2JfcG9pbnRzAAAAAAsAAAAsVGhlIHJvb20gdGhpcyBvbmUgaXMgYSBgcmVtYXRjaGAgb2YsIGlmIGFueS4AAAAKcmVtYXRjaF9vZgAAAAAD6AAAAAQAAAAAAAAAB3Jvb21faWQAAAAABAAAACpUaGUgc2VyaWVzIHRoaXMgcm9vbSBpcyBhIGdhbWUgb2YsIGlmIGFueS4AAAAAAAlzZXJpZXNfaWQAAAAAAAPoAAAABAAAAMBMZWRnZXIgYnkgd2hpY2ggdGhlIHBsYXllciBvbiB0dXJuIG11c3QgbW92ZSwgYWZ0ZXIgd2hpY2ggdGhlCm9wcG9uZW50IG1heSBgY2xhaW1fdGltZW91dGAuIFdoaWxlIEJ1cnlpbmcsIHRoZSBkZWFkbGluZSBmb3IgYm90aApidXJpYWxzIGluc3RlYWQgKHNlZSBgY2xhaW1fYWJhbmRvbmVkYCkuIFplcm8gYmVmb3JlIHRoZSBzdGFydC4AAAANdHVybl9kZWFkbGluZQAAAAAAAAQAAAAAAAAACXR1cm5faXNfYQAAAAAAAAEAAAAAAAAABndpbm5lcgAAAAAAEw==",
+        "AAAAAQAAALhgUm9vbWAgYXMgaXQgd2FzIGZpcnN0IHJlbGVhc2VkLCBiZWZvcmUgdGhlIGNvbmZpZywgbm9uY2UgYW5kCmFkbWluIGZsYWdzIHdlcmUgYWRkZWQuIFJldHVybmVkIGJ5IGBnZXRfcm9vbV92MWAgc28gY29uc3VtZXJzIGJ1aWx0CmFnYWluc3QgdGhhdCBsYXlvdXQga2VlcCBkZWNvZGluZyB3aGlsZSB0aGV5IG1pZ3JhdGUuAAAAAAAAAAZSb29tVjEAAAAAAA0AAAAAAAAABGRpZ3MAAAPqAAAH0AAAAAlEaWdSZWNvcmQAAAAAAAAAAAAAC2dhbWVfYWN0aXZlAAAAAAEAAAAAAAAAEGhhc19jb21taXRtZW50X2EAAAABAAAAAAAAABBoYXNfY29tbWl0bWVudF9iAAAAAQAAAAAAAAASaXNsYW5kX3RpbGVfY291bnRzAAAAAAPqAAAABAAAAAAAAAAFcGhhc2UAAAAAAAAEAAAAAAAAAAhwbGF5ZXJfYQAAABMAAAAAAAAAD3BsYXllcl9hX3BvaW50cwAAAAALAAAAAAAAAAhwbGF5ZXJfYgAAABMAAAAAAAAAD3BsYXllcl9iX3BvaW50cwAAAAALAAAAAAAAAAdyb29tX2lkAAAAAAQAAAAAAAAACXR1cm5faXNfYQAAAAAAAAEAAAAAAAAABndpbm5lcgAAAAAAEw==",
+        "AAAAAwAAAD5XaGljaCBHYW1lIEh1YiBpbnRlcmZhY2UgYSBodWIgaW1wbGVtZW50czsgc2VlIGBzZXRfaHViX2tpbmRgLgAAAAAAAAAAAAdIdWJLaW5kAAAAAAMAAAC9YHN0YXJ0X2dhbWVgIGFuZCBgZW5kX2dhbWVgIG9ubHksIGxpa2UgdGhlIG9mZmljaWFsIGh1Yi4gRHJhd3MgYW5kCmFib3J0cyBjbG9zZSB0aGUgc2Vzc2lvbiB3aXRoIGBlbmRfZ2FtZShzZXNzaW9uX2lkLCBmYWxzZSlgLCBhcyB0aGUKaW50ZXJmYWNlIGhhcyBubyB3YXkgdG8gcmVwb3J0IG5vIHdpbm5lci4gVGhlIGRlZmF1bHQuAAAAAAAABkxlZ2FjeQAAAAAAAAAAACJBbHNvIGBkcmF3X2dhbWVgIGFuZCBgYWJvcnRfZ2FtZWAuAAAAAAAIRXh0ZW5kZWQAAAABAAAAzFNldHRsZXMgYnkgZ2FtZSBhcyB3ZWxsIGFzIHNlc3Npb24sIHRocm91Z2ggYGVuZF9zZXNzaW9uYCwKYGRyYXdfc2Vzc2lvbmAgYW5kIGBhYm9ydF9zZXNzaW9uYCwgc28gYW5vdGhlciBnYW1lJ3Mgc2Vzc2lvbiB3aXRoCnRoZSBzYW1lIGlkIG5ldmVyIGdldHMgaW4gdGhlIHdheS4gTWF5IGVzY3JvdyBwb2ludHM7IHNlZQpgRXJyb3I6Okh1YkVzY3Jvd3NgLgAAAAdTZXNzaW9uAAAAAAI=",
+        "AAAAAgAAAC5BIGdhbWUgb3V0Y29tZSBvd2VkIHRvIGEgR2FtZSBIdWIsIGJ5IHNlc3Npb24uAAAAAAAAAAAACUh1YlJlcG9ydAAAAAAAAAMAAAABAAAAI2BlbmRfZ2FtZShzZXNzaW9uX2lkLCBwbGF5ZXIxX3dvbilgAAAAAAVFbmRlZAAAAAAAAAIAAAAEAAAAAQAAAAEAAAAXYGRyYXdfZ2FtZShzZXNzaW9uX2lkKWAAAAAABURyYXduAAAAAAAAAQAAAAQAAAABAAAAGGBhYm9ydF9nYW1lKHNlc3Npb25faWQpYAAAAAdBYm9ydGVkAAAAAAEAAAAE",
+        "AAAAAQAAAE1BIGh1YiByZXBvcnQgdGhhdCBmYWlsZWQgd2hlbiBpdHMgZ2FtZSBmaW5pc2hlZCwga2VwdCBmb3IKYHJldHJ5X2h1Yl9yZXBvcnRgLgAAAAAAAAAAAAAQUGVuZGluZ0h1YlJlcG9ydAAAAAIAAAAAAAAAA2h1YgAAAAATAAAAAAAAAAZyZXBvcnQAAAAAB9AAAAAJSHViUmVwb3J0AAAA",
+        "AAAAAQAAAC1BIHBvc3QtZ2FtZSB0aXAgZnJvbSBvbmUgcGxheWVyIHRvIHRoZSBvdGhlci4AAAAAAAAAAAAAA1RpcAAAAAAEAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAABGZyb20AAAATAAAAAAAAAAJ0bwAAAAAAEwAAAAAAAAAFdG9rZW4AAAAAAAAT",
+        "AAAAAQAAAEBTcGVjdGF0b3Igc3Rha2VzIG9uIGEgcm9vbSwgcGVyIHNpZGUsIGluIHRoZSByb29tJ3Mgc3Rha2UgdG9rZW4uAAAAAAAAAAdCZXRCb29rAAAAAAIAAAAAAAAAB3RvdGFsX2EAAAAACwAAAAAAAAAHdG90YWxfYgAAAAAL",
+        "AAAAAQAAACBPbmUgc3BlY3RhdG9yJ3Mgc3Rha2Ugb24gYSByb29tLgAAAAAAAAADQmV0AAAAAAIAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAALb25fcGxheWVyX2EAAAAAAQ==",
+        "AAAAAQAAACpBIHBsYXllciB3YWl0aW5nIGluIHRoZSBtYXRjaG1ha2luZyBxdWV1ZS4AAAAAAAAAAAAKUXVldWVFbnRyeQAAAAAAAgAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAZwb2ludHMAAAAAAAs=",
+        "AAAAAQAAAEJMZWRnZXIgc2VxdWVuY2UgYXQgd2hpY2ggZWFjaCBwbGF5ZXIgYnVyaWVkLCBvciBgTm9uZWAgaWYgbm90IHlldC4AAAAAAAAAAAANQnVyaWFsTGVkZ2VycwAAAAAAAAIAAAAAAAAACHBsYXllcl9hAAAD6AAAAAQAAAAAAAAACHBsYXllcl9iAAAD6AAAAAQ=",
+        "AAAAAQAAAC5BIHZlcmlmaWVkIHByZS1pbWFnZSBvZiBhIHBsYXllcidzIGNvbW1pdG1lbnQuAAAAAAAAAAAABlJldmVhbAAAAAAABAAAAAAAAAAJaXNsYW5kX2lkAAAAAAAABAAAABxXaG9zZSB0cmVhc3VyZSB0aGlzIHJldmVhbHMuAAAABW93bmVyAAAAAAAAEwAAAAAAAAAEc2FsdAAAA+4AAAAgAAAAAAAAAAd0aWxlX2lkAAAAAAQ=",
+        "AAAAAQAAAFBCb3RoIGNvbW1pdG1lbnRzIGFuZCBhbGwgcmV2ZWFscyBvZiBhbiBlbmRlZCByb29tLCBmb3IgdGhpcmQtcGFydHkKdmVyaWZpY2F0aW9uLgAAAAAAAAAUUHVibGlzaGVkQ29tbWl0bWVudHMAAAADAAAAAAAAAAxjb21taXRtZW50X2EAAAPoAAAD7gAAACAAAAAAAAAADGNvbW1pdG1lbnRfYgAAA+gAAAPuAAAAIAAAAAAAAAAHcmV2ZWFscwAAAAPqAAAH0AAAAAZSZXZlYWwAAA==",
+        "AAAAAQAAACdBIHJlcG9ydCBmaWxlZCBhZ2FpbnN0IGEgZmluaXNoZWQgZ2FtZS4AAAAAAAAAAApHYW1lUmVwb3J0AAAAAAADAAAAAAAAAAZsZWRnZXIAAAAAAAQAAAAtU2hvcnQgcmVhc29uIGNvZGUsIGUuZy4gYGNvbGx1c2lvbmAgb3IgYGJvdGAuAAAAAAAABnJlYXNvbgAAAAAAEQAAAAAAAAAIcmVwb3J0ZXIAAAAT",
+        "AAAAAQAAADRSZXBvcnRzIG9uZSBhZGRyZXNzIGhhcyBmaWxlZCBpbiBpdHMgY3VycmVudCB3aW5kb3cuAAAAAAAAAAxSZXBvcnRXaW5kb3cAAAACAAAAAAAAAAVjb3VudAAAAAAAAAQAAAAcTGVkZ2VyIHRoZSB3aW5kb3cgb3BlbmVkIGF0LgAAAAVzdGFydAAAAAAAAAQ=",
+        "AAAAAQAAAMZUaGUgY29udHJhY3QncyBlZmZlY3RpdmUgY29uZmlndXJhdGlvbiwgcmVhZCBpbiBvbmUgY2FsbCBieQpgZ2V0X2NvbmZpZ2AuIGBzZXRfY29uZmlnYCB3cml0ZXMgdGhlIGFkbWluLXR1bmFibGUgc2V0dGluZ3M7IHRoZSBmaXhlZApvbmVzIGFyZSByZXBvcnRlZCBmb3IgcmVmZXJlbmNlIGFuZCBtdXN0IGJlIHBhc3NlZCBiYWNrIHVuY2hhbmdlZC4AAAAAAAAAAAAGQ29uZmlnAAAAAAALAAAAP0xlZGdlcnMgYm90aCBwbGF5ZXJzIGhhdmUgdG8gYnVyeSBvbmNlIGEgcm9vbSBzdGFydHMsIGFzIGFib3ZlLgAAAAAUYnVyeV90aW1lb3V0X2xlZGdlcnMAAAAEAAAAMkFwcGxpZXMgdG8gcm9vbXMgd2hvc2Ugb3duIHZlcmJvc2l0eSBpcyBgRGVmYXVsdGAuAAAAAAAPZXZlbnRfdmVyYm9zaXR5AAAAB9AAAAAORXZlbnRWZXJib3NpdHkAAAAAADlCYXNpcyBwb2ludHMgdGFrZW4gZnJvbSBldmVyeSB3b24gcG90LCAwLi49YE1BWF9GRUVfQlBTYC4AAAAAAAAHZmVlX2JwcwAAAAAEAAAAVWBOb25lYCBydW5zIHRoZSBjb250cmFjdCBzdGFuZGFsb25lOiByb29tcyB3aXRob3V0IGEgaHViIG9mIHRoZWlyCm93biBzZXR0bGUgbG9jYWxseS4AAAAAAAAIZ2FtZV9odWIAAAPoAAAAEwAAADtFdmVudHMga2VwdCBpbiBlYWNoIHJvb20ncyBvdXRib3gsIDEuLj1gUkVDRU5UX0VWRU5UU19DQVBgLgAAAAARbWF4X3JlY2VudF9ldmVudHMAAAAAAAAEAAAATFJlcG9ydHMgb25lIGFkZHJlc3MgbWF5IGZpbGUgcGVyIGBSRVBPUlRfV0lORE9XX0xFREdFUlNgLAoxLi49YFJFUE9SVFNfQ0FQYC4AAAAYbWF4X3JlcG9ydHNfcGVyX3JlcG9ydGVyAAAABAAAACNOZXcgcm9vbXMgYXJlIGJsb2NrZWQ7IHNlZSBgcGF1c2VgLgAAAAAGcGF1c2VkAAAAAAABAAAATUxlZGdlcnMgZWl0aGVyIHBsYXllciBtYXkgc3RpbGwgcmV2ZWFsIG9uY2UgdGhlIGJvYXJkIGlzCmV4aGF1c3RlZCwgYXMgYWJvdmUuAAAAAAAAFXJldmVhbF93aW5kb3dfbGVkZ2VycwAAAAAAAAQAAAAyV2hvIHdpdGhkcmF3cyBmZWVzOyBgTm9uZWAgbGVhdmVzIGl0IHRvIHRoZSBhZG1pbi4AAAAAAAh0cmVhc3VyeQAAA+gAAAATAAAAZ0xlZGdlcnMgdGhlIHBsYXllciBvbiB0dXJuIGhhcyB0byBtb3ZlLCBmb3Igcm9vbXMgY3JlYXRlZCB3aXRob3V0CnRoZWlyIG93bjsgMS4uPWBNQVhfVElNRU9VVF9MRURHRVJTYC4AAAAAFHR1cm5fdGltZW91dF9sZWRnZXJzAAAABAAAADlGaXhlZDogbGF5b3V0IHZlcnNpb24gb2YgdGhlIHN0b3JlZCBzdGF0ZTsgc2VlIGBtaWdyYXRlYC4AAAAAAAAHdmVyc2lvbgAAAAAE",
+        "AAAAAQAAAONXaGVyZSBhIHBhZ2Ugc3RhcnRzIGluIGEgbGlzdCBlbmRwb2ludCBhbmQgaG93IG1hbnkgaXRlbXMgaXQgaG9sZHMuCkEgYGxpbWl0YCBvZiAwLCBvciBhYm92ZSB0aGUgY29udHJhY3QncyBwYWdlIGNhcCwgcmV0dXJucyBhIGZ1bGwgcGFnZS4KTGlzdHMgd2l0aCBhIGZpeGVkIGNhcCwgc3VjaCBhcyBvYnNlcnZlcnMgYW5kIHJlY2VudCBldmVudHMsIGFyZQpyZXR1cm5lZCB3aG9sZSBpbnN0ZWFkLgAAAAAAAAAABkN1cnNvcgAAAAAAAgAAAAAAAAAFbGltaXQAAAAAAAAEAAAAAAAAAAVzdGFydAAAAAAAAAQ=",
+        "AAAAAQAAAGpBIHBhZ2Ugb2YgYSByb29tJ3MgZGlnIGxvZy4gYG5leHRfY3Vyc29yYCBpcyB0aGUgYHN0YXJ0YCBvZiB0aGUKZm9sbG93aW5nIHBhZ2UsIG9yIGBOb25lYCBvbiB0aGUgbGFzdCBvbmUuAAAAAAAAAAAAB0RpZ1BhZ2UAAAAAAgAAAAAAAAAFaXRlbXMAAAAAAAPqAAAH0AAAAAlEaWdSZWNvcmQAAAAAAAAAAAAAC25leHRfY3Vyc29yAAAAA+gAAAAE",
+        "AAAAAQAAACdBIHBhZ2Ugb2YgYSByb29tJ3MgdGlwczsgc2VlIGBEaWdQYWdlYC4AAAAAAAAAAAdUaXBQYWdlAAAAAAIAAAAAAAAABWl0ZW1zAAAAAAAD6gAAB9AAAAADVGlwAAAAAAAAAAALbmV4dF9jdXJzb3IAAAAD6AAAAAQ=",
+        "AAAAAQAAACpBIHBhZ2Ugb2YgYSByb29tJ3MgcmVwb3J0czsgc2VlIGBEaWdQYWdlYC4AAAAAAAAAAAAKUmVwb3J0UGFnZQAAAAAAAgAAAAAAAAAFaXRlbXMAAAAAAAPqAAAH0AAAAApHYW1lUmVwb3J0AAAAAAAAAAAAC25leHRfY3Vyc29yAAAAA+gAAAAE",
+        "AAAAAQAAAIRBIHBhZ2Ugb2YgdGhlIGxvYmJ5J3Mgb3BlbiByb29tczsgc2VlIGBEaWdQYWdlYC4gUm9vbXMgdGhhdCBleHBpcmVkCndoaWxlIGxpc3RlZCBhcmUgc2tpcHBlZCwgc28gYSBwYWdlIG1heSBob2xkIGZld2VyIHRoYW4gYGxpbWl0YC4AAAAAAAAACFJvb21QYWdlAAAAAgAAAAAAAAAFaXRlbXMAAAAAAAPqAAAH0AAAAARSb29tAAAAAAAAAAtuZXh0X2N1cnNvcgAAAAPoAAAABA==",
+        "AAAAAQAAAC5BIHBhZ2Ugb2YgYSBwbGF5ZXIncyByb29tIGlkczsgc2VlIGBSb29tUGFnZWAuAAAAAAAAAAAAClJvb21JZFBhZ2UAAAAAAAIAAAAAAAAABWl0ZW1zAAAAAAAD6gAAAAQAAAAAAAAAC25leHRfY3Vyc29yAAAAA+gAAAAE",
+        "AAAAAQAAAIxBIHJvb20gdG9nZXRoZXIgd2l0aCBpdHMgcGVyLXBsYXllciBidXJpYWwgc3RhdHVzLCB0aGUgY3VycmVudApzaW11bHRhbmVvdXMtZGlnIHJvdW5kIGFuZCB0aGUgZWZmZWN0aXZlIGNvbmZpZywgcmV0dXJuZWQgYnkKYGdldF9mdWxsX3ZpZXdgLgAAAAAAAAAIRnVsbFZpZXcAAAAEAAAAAAAAAAZidXJpYWwAAAAAB9AAAAANQnVyaWFsTGVkZ2VycwAAAAAAAAAAAAAGY29uZmlnAAAAAAfQAAAABkNvbmZpZwAAAAAAAAAAAARyb29tAAAH0AAAAARSb29tAAAAAAAAAAVyb3VuZAAAAAAAB9AAAAAKUm91bmRTdGF0ZQAA",
+        "AAAAAQAAAKRMZWRnZXIgZW50cmllcyBhIHJvb20gY3VycmVudGx5IG9jY3VwaWVzLCByZXR1cm5lZCBieQpgZ2V0X3N0b3JhZ2VfcmVwb3J0YC4gYGJ5dGVzYCBpcyB0aGUgWERSIHNpemUgb2Yga2V5cyBhbmQgdmFsdWVzLCBhCmNsb3NlIGVzdGltYXRlIG9mIHdoYXQgcmVudCBpcyBjaGFyZ2VkIG9uLgAAAAAAAAANU3RvcmFnZVJlcG9ydAAAAAAAAAIAAAAAAAAABWJ5dGVzAAAAAAAABAAAAAAAAAAHZW50cmllcwAAAAAE",
+        "AAAAAQAAAEdXaGF0IGBtaWdyYXRlYCB3b3VsZCBkbyB3aXRoIGEgbGlzdCBvZiByb29tcywgZnJvbQpgcHJldmlld19taWdyYXRpb25gLgAAAAAAAAAAEE1pZ3JhdGlvblByZXZpZXcAAAAHAAAAPFJvb21zIGluIHRoZSBvcmlnaW5hbCBsYXlvdXQsIHdoaWNoIGBtaWdyYXRlYCB3b3VsZCByZXdyaXRlLgAAAAljb252ZXJ0ZWQAAAAAAAPqAAAABAAAADBSb29tcyBhbHJlYWR5IGluIHRoZSBjdXJyZW50IGxheW91dCwgbGVmdCBhbG9uZS4AAAAHY3VycmVudAAAAAAEAAAAGkxheW91dCB2ZXJzaW9uIHN0b3JlZCBub3cuAAAAAAAMZnJvbV92ZXJzaW9uAAAABAAAADNDb252ZXJ0ZWQgcm9vbXMgYGF1ZGl0X3Jvb21gIHdvdWxkIGZsYWcgYWZ0ZXJ3YXJkcy4AAAAAB2ludmFsaWQAAAAD6gAAAAQAAAAhSWRzIHdpdGggbm8gc3RvcmVkIHJvb20sIHNraXBwZWQuAAAAAAAAB21pc3NpbmcAAAAABAAAADFDb21taXRtZW50IHNjaGVtZSBldmVyeSBjb252ZXJ0ZWQgcm9vbSBpcyBwdXQgb24uAAAAAAAABnNjaGVtZQAAAAAH0AAAABBDb21taXRtZW50U2NoZW1lAAAAIUxheW91dCB2ZXJzaW9uIGBtaWdyYXRlYCByZWNvcmRzLgAAAAAAAAp0b192ZXJzaW9uAAAAAAAE",
+        "AAAAAQAAAFxBIGJhY2t1cCBhZG1pbiBhbmQgaG93IGxvbmcgdGhlIGFkbWluIG11c3QgYmUgaW5hY3RpdmUgYmVmb3JlIHRoZQpiYWNrdXAgY2FuIGNsYWltIHRoZSByb2xlLgAAAAAAAAALQWRtaW5CYWNrdXAAAAAAAgAAAAAAAAAGYmFja3VwAAAAAAATAAAAAAAAABJpbmFjdGl2aXR5X2xlZGdlcnMAAAAAAAQ=",
+        "AAAAAQAAAOBBIGJlc3Qtb2YtTiBtYXRjaCBiZXR3ZWVuIHR3byBwbGF5ZXJzLCBvbmUgcm9vbSBwZXIgZ2FtZS4gVGhlIGh1YgpzZWVzIHRoZSB3aG9sZSBzZXJpZXMgYXMgb25lIHNlc3Npb246IGBzdGFydF9nYW1lYCBvbiB0aGUgZmlyc3QKZ2FtZSBhbmQgYGVuZF9nYW1lYCBvbmNlIGEgcGxheWVyIHJlYWNoZXMgYHdpbnNfbmVlZGVkYC4gRHJhd24gZ2FtZXMKY291bnQgZm9yIG5laXRoZXIgcGxheWVyLgAAAAAAAAAGU2VyaWVzAAAAAAAJAAAAAAAAAAhwbGF5ZXJfYQAAABMAAAAAAAAACHBsYXllcl9iAAAAEwAAACtSb29tIGlkcyBvZiB0aGUgZ2FtZXMgc28gZmFyLCBvbGRlc3QgZmlyc3QuAAAAAAVyb29tcwAAAAAAA+oAAAAEAAAAAAAAAAlzZXJpZXNfaWQAAAAAAAAEAAAAPEh1YiBzZXNzaW9uIG9mIHRoZSBzZXJpZXM6IHRoZSBpZCBvZiBpdHMgZmlyc3Qgc3RhcnRlZCByb29tLgAAAApzZXNzaW9uX2lkAAAAAAPoAAAABAAAAAAAAAAGd2lubmVyAAAAAAPoAAAAEwAAAAAAAAAGd2luc19hAAAAAAAEAAAAAAAAAAZ3aW5zX2IAAAAAAAQAAAAAAAAAC3dpbnNfbmVlZGVkAAAAAAQ=",
+        "AAAAAQAAAKxIb3cgcmVsaWFibHkgYW4gYWRkcmVzcyBmaW5pc2hlcyB0aGUgZ2FtZXMgaXQgc3RhcnRzLiBFdmVyeSBzdGFydApjb3VudHMgdW50aWwgdGhlIGdhbWUgZW5kcyB3aXRoIGEgd2lubmVyLCBzbyBhYmFuZG9uZWQgcm9vbXMgKGFuZCBhbnkKc3RpbGwgaW4gcGxheSkgd2VpZ2ggdGhlIHNjb3JlIGRvd24uAAAAAAAAAApSZXB1dGF0aW9uAAAAAAADAAAAAAAAAAljb21wbGV0ZWQAAAAAAAAEAAAAP2Bjb21wbGV0ZWQgLyBzdGFydGVkYCBpbiBiYXNpcyBwb2ludHM7IDAgYmVmb3JlIHRoZSBmaXJzdCBnYW1lLgAAAAAJc2NvcmVfYnBzAAAAAAAABAAAAAAAAAAHc3RhcnRlZAAAAAAE",
+        "AAAAAQAAAINBIHBsYXllcidzIHJlc3VsdHMgYWNyb3NzIGV2ZXJ5IGdhbWUgdGhhdCBlbmRlZCB3aXRoIGEgd2lubmVyIG9yIGEKZHJhdy4gYHRvdGFsX3BvaW50c193b25gIHN1bXMgdGhlIG9wcG9uZW50J3MgcG9pbnRzIG9mIGVhY2ggd2luLgAAAAAAAAAAC1BsYXllclN0YXRzAAAAAAUAAAAAAAAABWRyYXdzAAAAAAAABAAAAAAAAAAFZ2FtZXMAAAAAAAAEAAAAAAAAAAZsb3NzZXMAAAAAAAQAAAAAAAAAEHRvdGFsX3BvaW50c193b24AAAALAAAAAAAAAAR3aW5zAAAABA==",
+        "AAAAAQAAAIFDYW5vbmljYWwgcmVjb3JkIG9mIGEgZmluaXNoZWQgcm9vbSwgZXhwb3J0ZWQgWERSLWVuY29kZWQgYnkKYGV4cG9ydF9hdHRlc3RhdGlvbmAgZm9yIGNvLXNpZ25pbmcgYW5kIGNvbnN1bXB0aW9uIGJ5IG90aGVyIGNoYWlucy4AAAAAAAAAAAAAC0F0dGVzdGF0aW9uAAAAAAkAAAA2VGhlIFBpcmF0ZXNUcmVhc3VyZSBjb250cmFjdCB0aGF0IHByb2R1Y2VkIHRoZSByZXN1bHQuAAAAAAAIY29udHJhY3QAAAATAAAATzMgaWYgYHdpbm5lcmAgd29uOyA0IGZvciBhIGRyYXcgb3IgYWJvcnQsIHdoZXJlIGB3aW5uZXJgIGlzIG9ubHkgYQpwbGFjZWhvbGRlci4AAAAABXBoYXNlAAAAAAAABAAAAAAAAAAIcGxheWVyX2EAAAATAAAAAAAAAA9wbGF5ZXJfYV9wb2ludHMAAAAACwAAAAAAAAAIcGxheWVyX2IAAAATAAAAAAAAAA9wbGF5ZXJfYl9wb2ludHMAAAAACwAAAAAAAAAHcm9vbV9pZAAAAAAEAAAAQVNIQS0yNTYgb3ZlciB0aGUgWERSIG9mIHRoZSBkaWcgbG9nIGZvbGxvd2VkIGJ5IGJvdGggY29tbWl0bWVudHMuAAAAAAAAD3RyYW5zY3JpcHRfaGFzaAAAAAPuAAAAIAAAAAAAAAAGd2lubmVyAAAAAAAT",
+        "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAALQAAAAEAAAAWUm9vbShyb29tX2lkKSDihpIgUm9vbQAAAAAABFJvb20AAAABAAAABAAAAAEAAAAvQ29tbWl0bWVudChyb29tX2lkLCBpc19wbGF5ZXJfYSkg4oaSIEJ5dGVzTjwzMj4AAAAACkNvbW1pdG1lbnQAAAAAAAIAAAAEAAAAAQAAAAEAAAA9T3BlbmVkKHJvb21faWQsIGlzX3BsYXllcl9hKSDihpIgYm9vbCAodGhlIGJ1cmlhbCB3YXMgb3BlbmVkKQAAAAAAAAZPcGVuZWQAAAAAAAIAAAAEAAAAAQAAAAAAAAANQWRtaW4gYWRkcmVzcwAAAAAAAAVBZG1pbgAAAAAAAAAAAAA0QWRkcmVzcyBwcm9wb3NlZCBhcyB0aGUgbmV4dCBhZG1pbiwgdW50aWwgaXQgYWNjZXB0cwAAAAxQZW5kaW5nQWRtaW4AAAAAAAAAMkxheW91dCB2ZXJzaW9uIG9mIHRoZSBzdG9yZWQgc3RhdGU7IGFic2VudCBtZWFucyAxAAAAAAAMU3RhdGVWZXJzaW9uAAAAAAAAADpHYW1lIEh1YiBjb250cmFjdCBhZGRyZXNzIChhYnNlbnQgd2hlbiBydW5uaW5nIHN0YW5kYWxvbmUpAAAAAAAOR2FtZUh1YkFkZHJlc3MAAAAAAAAAAAA9QXBwcm92ZWRIdWJzIOKGkiBWZWM8QWRkcmVzcz4gKGZ1cnRoZXIgaHVicyByb29tcyBtYXkgY2hvb3NlKQAAAAAAAAxBcHByb3ZlZEh1YnMAAAABAAAAL0h1YktpbmQoaHViKSDihpIgSHViS2luZDsgYWJzZW50IG1lYW5zIGBMZWdhY3lgAAAAAAdIdWJLaW5kAAAAAAEAAAATAAAAAQAAAD1FdmVudHMocm9vbV9pZCkg4oaSIFZlYzxSb29tRXZlbnQ+IChyaW5nIGJ1ZmZlciwgbmV3ZXN0IGxhc3QpAAAAAAAABkV2ZW50cwAAAAAAAQAAAAQAAAABAAAAOUJsc0tleShwbGF5ZXIpIOKGkiBCeXRlc048OTY+IChwZXJzaXN0ZW50LCBHMSBwdWJsaWMga2V5KQAAAAAAAAZCbHNLZXkAAAAAAAEAAAATAAAAAQAAABpUaXBzKHJvb21faWQpIOKGkiBWZWM8VGlwPgAAAAAABFRpcHMAAAABAAAABAAAAAEAAAAkUmVwb3J0cyhyb29tX2lkKSDihpIgVmVjPEdhbWVSZXBvcnQ+AAAAB1JlcG9ydHMAAAAAAQAAAAQAAAABAAAAOEJ1cmllZEF0KHJvb21faWQsIGlzX3BsYXllcl9hKSDihpIgdTMyIChsZWRnZXIgc2VxdWVuY2UpAAAACEJ1cmllZEF0AAAAAgAAAAQAAAABAAAAAQAAACBSZXZlYWxzKHJvb21faWQpIOKGkiBWZWM8UmV2ZWFsPgAAAAdSZXZlYWxzAAAAAAEAAAAEAAAAAAAAAEROdW1iZXIgb2Ygcm9vbSBub25jZXMgZHJhd24gc28gZmFyIChvbmUgcGVyIGNyZWF0aW9uLCBvbmUgcGVyIHN0YXJ0KQAAAAxOb25jZUNvdW50ZXIAAAABAAAAVFJvdW5kRGlnKHJvb21faWQsIGlzX3BsYXllcl9hKSDihpIgUGVuZGluZ0RpZyAoc2ltdWx0YW5lb3VzIGFuZApjb21taXR0ZWQtZGlnIG1vZGVzKQAAAAhSb3VuZERpZwAAAAIAAAAEAAAAAQAAAAAAAAA7T3V0Ym94IGxlbmd0aCBwZXIgcm9vbTsgZGVmYXVsdHMgdG8gYERFRkFVTFRfUkVDRU5UX0VWRU5UU2AAAAAAD01heFJlY2VudEV2ZW50cwAAAAAAAAAARVJlcG9ydCBsaW1pdCBwZXIgcmVwb3J0ZXI7IGRlZmF1bHRzIHRvIGBERUZBVUxUX1JFUE9SVFNfUEVSX1JFUE9SVEVSYAAAAAAAABVNYXhSZXBvcnRzUGVyUmVwb3J0ZXIAAAAAAAAAAAAARlR1cm4gdGltZW91dCBmb3IgbmV3IHJvb21zOyBkZWZhdWx0cyB0byBgREVGQVVMVF9UVVJOX1RJTUVPVVRfTEVER0VSU2AAAAAAAAtUdXJuVGltZW91dAAAAAAAAAAARkJ1cnkgdGltZW91dCBmb3IgbmV3IHJvb21zOyBkZWZhdWx0cyB0byBgREVGQVVMVF9CVVJZX1RJTUVPVVRfTEVER0VSU2AAAAAAAAtCdXJ5VGltZW91dAAAAAAAAAAASFJldmVhbCB3aW5kb3cgZm9yIG5ldyByb29tczsgZGVmYXVsdHMgdG8KYERFRkFVTFRfUkVWRUFMX1dJTkRPV19MRURHRVJTYAAAAAxSZXZlYWxXaW5kb3cAAAABAAAAM1JlcG9ydFdpbmRvdyhyZXBvcnRlcikg4oaSIFJlcG9ydFdpbmRvdyAodGVtcG9yYXJ5KQAAAAAMUmVwb3J0V2luZG93AAAAAQAAABMAAAAAAAAANEJhY2t1cCBhZG1pbiBmb3IgdGhlIGRlYWQtbWFuIHN3aXRjaCDihpIgQWRtaW5CYWNrdXAAAAALQWRtaW5CYWNrdXAAAAAAAAAAADVMZWRnZXIgc2VxdWVuY2Ugb2YgdGhlIGFkbWluJ3MgbGFzdCBhdXRob3JpemVkIGFjdGlvbgAAAAAAAA9BZG1pbkxhc3RBY3RpdmUAAAAAAQAAAC5SZXB1dGF0aW9uKHBsYXllcikg4oaSIFJlcHV0YXRpb24gKHBlcnNpc3RlbnQpAAAAAAAKUmVwdXRhdGlvbgAAAAAAAQAAABMAAAABAAAAI09ic2VydmVycyhyb29tX2lkKSDihpIgVmVjPEFkZHJlc3M+AAAAAAlPYnNlcnZlcnMAAAAAAAABAAAABAAAAAAAAAAwQ29udHJhY3Qtd2lkZSBFdmVudFZlcmJvc2l0eTsgZGVmYXVsdHMgdG8gYEZ1bGxgAAAADkV2ZW50VmVyYm9zaXR5AAAAAAABAAAAQUJ1cmlhbEtleShwbGF5ZXIpIOKGkiBCeXRlc048MzI+IChwZXJzaXN0ZW50LCBlZDI1NTE5IHB1YmxpYyBrZXkpAAAAAAAACUJ1cmlhbEtleQAAAAAAAAEAAAATAAAAAAAAAFdPcGVuUm9vbXMg4oaSIFZlYzx1MzI+IChpZHMgb2Ygcm9vbXMgd2FpdGluZyBmb3IgYW4gb3Bwb25lbnQsIGF0Cm1vc3QgYE1BWF9PUEVOX1JPT01TYCkAAAAACU9wZW5Sb29tcwAAAAAAAAEAAABiUGxheWVyUm9vbXMocGxheWVyKSDihpIgVmVjPHUzMj4gKHBlcnNpc3RlbnQsIGlkcyBvZiByb29tcyB0aGUKcGxheWVyIHNpdHMgaW4gdGhhdCBoYXZlIG5vdCBlbmRlZCkAAAAAAAtQbGF5ZXJSb29tcwAAAAABAAAAEwAAAAAAAAAtTGFzdCByb29tIGlkIGhhbmRlZCBvdXQgYnkgYGNyZWF0ZV9yb29tX2F1dG9gAAAAAAAAC1Jvb21Db3VudGVyAAAAAAEAAAAcU2VyaWVzKHNlcmllc19pZCkg4oaSIFNlcmllcwAAAAZTZXJpZXMAAAAAAAEAAAAEAAAAAQAAABxCZXRCb29rKHJvb21faWQpIOKGkiBCZXRCb29rAAAAB0JldEJvb2sAAAAAAQAAAAQAAAABAAAALlBlbmRpbmdIdWJSZXBvcnQocm9vbV9pZCkg4oaSIFBlbmRpbmdIdWJSZXBvcnQAAAAAABBQZW5kaW5nSHViUmVwb3J0AAAAAQAAAAQAAAABAAAAM0JldChyb29tX2lkLCBiZXR0b3IpIOKGkiBCZXQgKHJlbW92ZWQgb25jZSBjbGFpbWVkKQAAAAADQmV0AAAAAAIAAAAEAAAAEwAAAAEAAAAvUmF0aW5nKHBsYXllcikg4oaSIHUzMiAocGVyc2lzdGVudCwgRWxvIHJhdGluZykAAAAABlJhdGluZwAAAAAAAQAAABMAAAABAAAAKlN0YXRzKHBsYXllcikg4oaSIFBsYXllclN0YXRzIChwZXJzaXN0ZW50KQAAAAAABVN0YXRzAAAAAAAAAQAAABMAAAAAAAAAN0xlYWRlcmJvYXJkIGNvbnRyYWN0IHRoYXQgZGVjaWRlZCBnYW1lcyBhcmUgcmVwb3J0ZWQgdG8AAAAAEkxlYWRlcmJvYXJkQWRkcmVzcwAAAAAAAQAAAFZRdWV1ZShidWNrZXQpIOKGkiBRdWV1ZUVudHJ5ICh0aGUgcGxheWVyIHdhaXRpbmcgZm9yIGEgbWF0Y2ggYXQKc3Rha2VzIGluIHRoYXQgYnVja2V0KQAAAAAABVF1ZXVlAAAAAAAAAQAAAAQAAAABAAAAQkNoYWxsZW5nZShjaGFsbGVuZ2VyLCBvcHBvbmVudCkg4oaSIGkxMjggKHRoZSBjaGFsbGVuZ2VyJ3MgcG9pbnRzKQAAAAAACUNoYWxsZW5nZQAAAAAAAAIAAAATAAAAEwAAAAAAAAA3U2V0IHdoaWxlIHRoZSBhZG1pbiBoYXMgcGF1c2VkIHJvb20gY3JlYXRpb24gYW5kIHN0YXJ0cwAAAAAGUGF1c2VkAAAAAAAAAAAAQEZlZSB0YWtlbiBmcm9tIGV2ZXJ5IHdvbiBwb3QsIGluIGJhc2lzIHBvaW50czsgYWJzZW50IG1lYW5zIG5vbmUAAAAGRmVlQnBzAAAAAAAAAAAAMUFkZHJlc3MgdGhhdCB3aXRoZHJhd3MgZmVlcyBpbiBwbGFjZSBvZiB0aGUgYWRtaW4AAAAAAAAIVHJlYXN1cnkAAAAAAAAAT0ZlZXMg4oaSIE1hcDxBZGRyZXNzLCBpMTI4PiAoZmVlcyBjb2xsZWN0ZWQgcGVyIHN0YWtlIHRva2VuLCBub3QgeWV0CndpdGhkcmF3bikAAAAABEZlZXM=",
+        "AAAAAQAAAE1UaGUgZGF0YSBib2R5IG9mIGEgcHVibGlzaGVkIFtgUm9vbUV2ZW50UHVibGlzaGVkYF0gb3IKW2BPYnNlcnZlZFJvb21FdmVudGBdLgAAAAAAAAAAAAANUm9vbUV2ZW50RGF0YQAAAAAAAAIAAAAAAAAABWFjdG9yAAAAAAAAEwAAAAAAAAAGbGVkZ2VyAAAAAAAE",
+        "AAAAAQAAAKdBbiBlbnRyeSBpbiBhIHJvb20ncyBldmVudCBvdXRib3guCgpNaXJyb3JzIHRoZSBgcm9vbV9ldmVudGAgY29udHJhY3QgZXZlbnRzIHNvIGNsaWVudHMgdGhhdCBwb2xsIGluc3RlYWQgb2YKc3Vic2NyaWJpbmcgY2FuIHN0aWxsIGNhdGNoIHVwIG9uIHdoYXQgaGFwcGVuZWQgaW4gYSByb29tLgAAAAAAAAAACVJvb21FdmVudAAAAAAAAAMAAAAAAAAABWFjdG9yAAAAAAAAEwAAAAAAAAAEa2luZAAAABEAAAAAAAAABmxlZGdlcgAAAAAABA==",
+        "AAAABQAAAV1QdWJsaXNoZWQgb24gZXZlcnkgcm9vbSBzdGF0ZSBjaGFuZ2UuIGBraW5kYCBpcyBvbmUgb2YgYGNyZWF0ZWRgLApgam9pbmVkYCwgYHN0YXJ0ZWRgLCBgYnVyaWVkYCwgYHJlYnVyaWVkYCwgYGR1Z2AsIGByZXZlYWxlZGAsIGB0aXBwZWRgLApgcmVwb3J0ZWRgLCBgc3Vydml2ZWRgLCBgaHVibGVzc2AsIGBmcm96ZW5gLCBgdW5mcm96ZW5gLCBgcm9sbGJhY2tgLApgZHJhd2AsIGB0aW1lb3V0YCwgYGZvcmZlaXRlZGAsIGBjYW5jZWxsZWRgLCBgYWJhbmRvbmVkYCwgYGFib3J0ZWRgLApgZm91bmRgLCBgbWlzc2VkYCwgYHNlcmllc3dvbmAsIGBiZXRgLCBgYmV0cGFpZGAsIGBodWJmYWlsZWRgLApgaHVic2VudGAuAAAAAAAAAAAAABJSb29tRXZlbnRQdWJsaXNoZWQAAAAAAAEAAAAKcm9vbV9ldmVudAAAAAAABAAAAAAAAAAHcm9vbV9pZAAAAAAEAAAAAQAAAAAAAAAEa2luZAAAABEAAAABAAAAAAAAAAVhY3RvcgAAAAAAABMAAAAAAAAAAAAAAAZsZWRnZXIAAAAAAAQAAAAAAAAAAg==",
+        "AAAABQAAAD5Db3B5IG9mIGEgcm9vbSBldmVudCBhZGRyZXNzZWQgdG8gb25lIG9mIHRoZSByb29tJ3Mgb2JzZXJ2ZXJzLgAAAAAAAAAAABFPYnNlcnZlZFJvb21FdmVudAAAAAAAAAEAAAAIb2JzZXJ2ZWQAAAAFAAAAAAAAAAhvYnNlcnZlcgAAABMAAAABAAAAAAAAAAdyb29tX2lkAAAAAAQAAAABAAAAAAAAAARraW5kAAAAEQAAAAEAAAAAAAAABWFjdG9yAAAAAAAAEwAAAAAAAAAAAAAABmxlZGdlcgAAAAAABAAAAAAAAAAC",
+        "AAAABQAAAENgUm9vbUV2ZW50UHVibGlzaGVkYCB3aXRob3V0IGl0cyBib2R5LCBmb3IgbWluaW1hbC12ZXJib3NpdHkgcm9vbXMuAAAAAAAAAAAQTWluaW1hbFJvb21FdmVudAAAAAEAAAAKcm9vbV9ldmVudAAAAAAAAgAAAAAAAAAHcm9vbV9pZAAAAAAEAAAAAQAAAAAAAAAEa2luZAAAABEAAAABAAAAAg==",
+        "AAAABQAAAEJgT2JzZXJ2ZWRSb29tRXZlbnRgIHdpdGhvdXQgaXRzIGJvZHksIGZvciBtaW5pbWFsLXZlcmJvc2l0eSByb29tcy4AAAAAAAAAAAAYTWluaW1hbE9ic2VydmVkUm9vbUV2ZW50AAAAAQAAAAhvYnNlcnZlZAAAAAMAAAAAAAAACG9ic2VydmVyAAAAEwAAAAEAAAAAAAAAB3Jvb21faWQAAAAABAAAAAEAAAAAAAAABGtpbmQAAAARAAAAAQAAAAI=",
+        "AAAABQAAACBBIHJvb20gd2FzIG9wZW5lZCBieSBgcGxheWVyX2FgLgAAAAAAAAALUm9vbUNyZWF0ZWQAAAAAAQAAAAxyb29tX2NyZWF0ZWQAAAADAAAAAAAAAAdyb29tX2lkAAAAAAQAAAABAAAAAAAAAAhwbGF5ZXJfYQAAABMAAAAAAAAAAAAAAA9wbGF5ZXJfYV9wb2ludHMAAAAACwAAAAAAAAAC",
+        "AAAABQAAAChgcGxheWVyX2JgIHRvb2sgdGhlIG9wZW4gc2VhdCBpbiBhIHJvb20uAAAAAAAAAAxQbGF5ZXJKb2luZWQAAAABAAAADXBsYXllcl9qb2luZWQAAAAAAAADAAAAAAAAAAdyb29tX2lkAAAAAAQAAAABAAAAAAAAAAhwbGF5ZXJfYgAAABMAAAAAAAAAAAAAAA9wbGF5ZXJfYl9wb2ludHMAAAAACwAAAAAAAAAC",
+        "AAAABQAAACNBIHBsYXllcidzIGJ1cmlhbCBjb21taXRtZW50IGlzIGluLgAAAAAAAAAADlRyZWFzdXJlQnVyaWVkAAAAAAABAAAAD3RyZWFzdXJlX2J1cmllZAAAAAADAAAAAAAAAAdyb29tX2lkAAAAAAQAAAABAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAAAAAAAApjb21taXRtZW50AAAAAAPuAAAAIAAAAAAAAAAC",
+        "AAAABQAAAA9BIHRpbGUgd2FzIGR1Zy4AAAAAAAAAAAdUaWxlRHVnAAAAAAEAAAAIdGlsZV9kdWcAAAAEAAAAAAAAAAdyb29tX2lkAAAAAAQAAAABAAAAAAAAAAZkaWdnZXIAAAAAABMAAAAAAAAAAAAAAAlpc2xhbmRfaWQAAAAAAAAEAAAAAAAAAAAAAAAHdGlsZV9pZAAAAAAEAAAAAAAAAAI=",
+        "AAAABQAAACBBIHdpbm5pbmcgcmV2ZWFsIGVuZGVkIHRoZSBnYW1lLgAAAAAAAAAQVHJlYXN1cmVSZXZlYWxlZAAAAAEAAAARdHJlYXN1cmVfcmV2ZWFsZWQAAAAAAAAEAAAAAAAAAAdyb29tX2lkAAAAAAQAAAABAAAAAAAAAAZ3aW5uZXIAAAAAABMAAAAAAAAAAAAAAAlpc2xhbmRfaWQAAAAAAAAEAAAAAAAAAAAAAAAHdGlsZV9pZAAAAAAEAAAAAAAAAAI=",
+        "AAAAAAAAAAAAAAANX19jb25zdHJ1Y3RvcgAAAAAAAAIAAAAAAAAABWFkbWluAAAAAAAAEwAAAAAAAAAIZ2FtZV9odWIAAAPoAAAAEwAAAAA=",
         "AAAAAAAAAAAAAAAJZ2V0X2FkbWluAAAAAAAAAAAAAAEAAAAT",
-        "AAAAAAAAACBQbGF5ZXIgQiBqb2lucyBhbiBleGlzdGluZyByb29tLgAAAAlqb2luX3Jvb20AAAAAAAADAAAAAAAAAAdyb29tX2lkAAAAAAQAAAAAAAAACHBsYXllcl9iAAAAEwAAAAAAAAAPcGxheWVyX2JfcG9pbnRzAAAAAAsAAAABAAAH0AAAAARSb29t",
-        "AAAAAAAAAAAAAAAJc2V0X2FkbWluAAAAAAAAAQAAAAAAAAAJbmV3X2FkbWluAAAAAAAAEwAAAAA=",
-        "AAAAAAAAADtCb3RoIHBsYXllcnMgY28tc2lnbiB0byBzdGFydC4gQ2FsbHMgR2FtZSBIdWIgYHN0YXJ0X2dhbWVgLgAAAAAKc3RhcnRfcm9vbQAAAAAABQAAAAAAAAAHcm9vbV9pZAAAAAAEAAAAAAAAAAhwbGF5ZXJfYQAAABMAAAAAAAAACHBsYXllcl9iAAAAEwAAAAAAAAAPcGxheWVyX2FfcG9pbnRzAAAAAAsAAAAAAAAAD3BsYXllcl9iX3BvaW50cwAAAAALAAAAAQAAB9AAAAAEUm9vbQ==",
+        "AAAAAAAAAKRPZmZlciB0aGUgYWRtaW4gcm9sZSB0byBgbmV3X2FkbWluYC4gTm90aGluZyBjaGFuZ2VzIHVudGlsIHRoZXkKYGFjY2VwdF9hZG1pbmAsIHNvIGEgbWlzdHlwZWQgYWRkcmVzcyBjYW5ub3QgbG9jayB0aGUgYWRtaW4gb3V0OyBhCmxhdGVyIHByb3Bvc2FsIHJlcGxhY2VzIHRoaXMgb25lLgAAAA1wcm9wb3NlX2FkbWluAAAAAAAAAQAAAAAAAAAJbmV3X2FkbWluAAAAAAAAEwAAAAA=",
+        "AAAAAAAAAC1UYWtlIHVwIHRoZSBhZG1pbiByb2xlIGFzIHRoZSBwcm9wb3NlZCBhZG1pbi4AAAAAAAAMYWNjZXB0X2FkbWluAAAAAAAAAAA=",
+        "AAAAAAAAAAAAAAARZ2V0X3BlbmRpbmdfYWRtaW4AAAAAAAAAAAAAAQAAA+gAAAAT",
+        "AAAAAAAAAAAAAAAHZ2V0X2h1YgAAAAAAAAAAAQAAA+gAAAAT",
+        "AAAAAAAAAAAAAAAHc2V0X2h1YgAAAAABAAAAAAAAAAduZXdfaHViAAAAABMAAAAA",
+        "AAAAAAAAADpMZXQgcm9vbXMgY2hvb3NlIGBodWJgIHRvIHJlcG9ydCB0bywgdGhyb3VnaCB0aGVpciBjb25maWcuAAAAAAALYXBwcm92ZV9odWIAAAAAAQAAAAAAAAADaHViAAAAABMAAAAA",
+        "AAAAAAAAAFVUYWtlIGBodWJgIG9mZiB0aGUgYXBwcm92ZWQgbGlzdC4gUm9vbXMgYWxyZWFkeSBjcmVhdGVkIHdpdGggaXQKa2VlcCByZXBvcnRpbmcgdG8gaXQuAAAAAAAACnJldm9rZV9odWIAAAAAAAEAAAAAAAAAA2h1YgAAAAATAAAAAA==",
+        "AAAAAAAAAAAAAAARZ2V0X2FwcHJvdmVkX2h1YnMAAAAAAAAAAAAAAQAAA+oAAAAT",
+        "AAAAAAAAAINSZWNvcmQgd2hpY2ggaW50ZXJmYWNlIGBodWJgIGltcGxlbWVudHMsIHNvIGRyYXdzIGFuZCBhYm9ydHMgcmVhY2gKYSBsZWdhY3kgaHViIHRocm91Z2ggYGVuZF9nYW1lYC4gQXBwbGllcyB0byBwZW5kaW5nIHJlcG9ydHMgdG9vLgAAAAAMc2V0X2h1Yl9raW5kAAAAAgAAAAAAAAADaHViAAAAABMAAAAAAAAABGtpbmQAAAfQAAAAB0h1YktpbmQAAAAAAA==",
+        "AAAAAAAAAAAAAAAMZ2V0X2h1Yl9raW5kAAAAAQAAAAAAAAADaHViAAAAABMAAAABAAAH0AAAAAdIdWJLaW5kAA==",
+        "AAAAAAAAAD1UaGUgbGVhZGVyYm9hcmQgZGVjaWRlZCBnYW1lcyBhcmUgcmVwb3J0ZWQgdG8sIGlmIG9uZSBpcyBzZXQuAAAAAAAAD2dldF9sZWFkZXJib2FyZAAAAAAAAAAAAQAAA+gAAAAT",
+        "AAAAAAAAAGxSZXBvcnQgZXZlcnkgZGVjaWRlZCBnYW1lIHRvIGBsZWFkZXJib2FyZGAgZnJvbSBub3cgb24uIFRoZQpsZWFkZXJib2FyZCBtdXN0IGxpc3QgdGhpcyBjb250cmFjdCBhcyBpdHMgZ2FtZS4AAAAPc2V0X2xlYWRlcmJvYXJkAAAAAAEAAAAAAAAAC2xlYWRlcmJvYXJkAAAAABMAAAAA",
+        "AAAAAAAAAFZUaGUgZWZmZWN0aXZlIGNvbmZpZ3VyYXRpb24sIHdpdGggZGVmYXVsdHMgZmlsbGVkIGluIGZvciBhbnkgbGltaXQKdGhhdCB3YXMgbmV2ZXIgc2V0LgAAAAAACmdldF9jb25maWcAAAAAAAAAAAABAAAH0AAAAAZDb25maWcAAA==",
+        "AAAAAAAAAMRSZXBsYWNlIGV2ZXJ5IGFkbWluLXR1bmFibGUgc2V0dGluZyBpbiBvbmUgY2FsbC4gTGltaXRzIG91dHNpZGUKdGhlaXIgYWxsb3dlZCByYW5nZSwgb3IgYSBjaGFuZ2UgdG8gYSBmaXhlZCBzZXR0aW5nLCBhcmUgcmVqZWN0ZWQKd2l0aCBgSW52YWxpZENvbmZpZ2A7IGEgZmVlIGFib3ZlIGBNQVhfRkVFX0JQU2Agd2l0aCBgRmVlVG9vSGlnaGAuAAAACnNldF9jb25maWcAAAAAAAEAAAAAAAAABmNvbmZpZwAAAAAH0AAAAAZDb25maWcAAAAAAAA=",
+        "AAAAAAAAAIBOYW1lIGEgYmFja3VwIHRoYXQgbWF5IHRha2Ugb3ZlciB0aGUgYWRtaW4gcm9sZSBvbmNlIHRoZSBhZG1pbiBoYXMKZ29uZSBgaW5hY3Rpdml0eV9sZWRnZXJzYCB3aXRob3V0IGFuIGF1dGhvcml6ZWQgYWRtaW4gYWN0aW9uLgAAABBzZXRfYWRtaW5fYmFja3VwAAAAAgAAAAAAAAAGYmFja3VwAAAAAAATAAAAAAAAABJpbmFjdGl2aXR5X2xlZGdlcnMAAAAAAAQAAAAA",
+        "AAAAAAAAAAAAAAAQZ2V0X2FkbWluX2JhY2t1cAAAAAAAAAABAAAD6AAAB9AAAAALQWRtaW5CYWNrdXAA",
+        "AAAAAAAAAIRUYWtlIG92ZXIgdGhlIGFkbWluIHJvbGUgYXMgdGhlIHJlZ2lzdGVyZWQgYmFja3VwLiBPbmx5IGFsbG93ZWQgb25jZQp0aGUgYWRtaW4ncyBpbmFjdGl2aXR5IHRocmVzaG9sZCBoYXMgcGFzc2VkOyBjbGVhcnMgdGhlIGJhY2t1cC4AAAALY2xhaW1fYWRtaW4AAAAAAQAAAAAAAAAGYmFja3VwAAAAAAATAAAAAA==",
+        "AAAAAAAAANtTZXR0bGUgYSBzdGFydGVkIHJvb20gd2l0aG91dCB0aGUgaHViLCBvbmNlIGFuIG91dGFnZSBpcyBjb25maXJtZWQuClRoZSBnYW1lIHBsYXlzIG9uIGFzIG5vcm1hbCwgYnV0IGl0cyByZXN1bHQgc3RheXMgbG9jYWw6IHRoZSBodWIncwpgZW5kX2dhbWVgIGlzIHNraXBwZWQgYW5kIHRoZSBodWItc2lkZSBwb2ludHMgYXJlIGxlZnQgZm9yIHRoZSBodWIKb3BlcmF0b3IgdG8gdm9pZC4AAAAAEHNldF9yb29tX2h1Ymxlc3MAAAABAAAAAAAAAAdyb29tX2lkAAAAAAQAAAAA",
+        "AAAAAAAAAQVGcmVlemUgYSByb29tIHRoYXQgaGFzIG5vdCBlbmRlZCwgb3IgdGhhdyBpdCBhZ2Fpbi4gV2hpbGUgZnJvemVuCmV2ZXJ5IHBsYXllciBhY3Rpb24gZmFpbHMgd2l0aCBgUm9vbUZyb3plbmA7IGFkbWluIGFjdGlvbnMgc3VjaCBhcwpgc2V0X3Jvb21faHVibGVzc2Agc3RpbGwgZ28gdGhyb3VnaC4gVGhlIGNsb2NrIHN0b3BzIHRvbzogb24gdGhhdwp0aGUgdHVybiBkZWFkbGluZSBhbmQgcmV2ZWFsIHdpbmRvdyByZXN1bWUgd2hlcmUgdGhleSBsZWZ0IG9mZi4AAAAAAAAPc2V0X3Jvb21fZnJvemVuAAAAAAIAAAAAAAAAB3Jvb21faWQAAAAABAAAAAAAAAAGZnJvemVuAAAAAAABAAAAAA==",
+        "AAAAAAAAAHNTdG9wIGV2ZXJ5IG5ldyByb29tIGZyb20gYmVpbmcgY3JlYXRlZCBvciBzdGFydGVkLCBmb3IgaW5jaWRlbnQKcmVzcG9uc2UuIFJvb21zIGFscmVhZHkgc3RhcnRlZCBwbGF5IG9uIHRvIHRoZSBlbmQuAAAAAAVwYXVzZQAAAAAAAAAAAAAA",
+        "AAAAAAAAAA9MaWZ0IGEgYHBhdXNlYC4AAAAAB3VucGF1c2UAAAAAAAAAAAA=",
+        "AAAAAAAAAAAAAAAJaXNfcGF1c2VkAAAAAAAAAAAAAAEAAAAB",
+        "AAAAAAAAAIBUYWtlIGBmZWVfYnBzYCBiYXNpcyBwb2ludHMgb2YgZXZlcnkgd29uIHBvdCBmcm9tIG5vdyBvbiwgdXAgdG8KYE1BWF9GRUVfQlBTYC4gUmVmdW5kcyBhZnRlciBkcmF3cyBhbmQgYWJvcnRzIGFyZSBuZXZlciBjaGFyZ2VkLgAAAAtzZXRfZmVlX2JwcwAAAAABAAAAAAAAAAdmZWVfYnBzAAAAAAQAAAAA",
+        "AAAAAAAAAAAAAAALZ2V0X2ZlZV9icHMAAAAAAAAAAAEAAAAE",
+        "AAAAAAAAAEVIYW5kIGZlZSB3aXRoZHJhd2FscyB0byBgdHJlYXN1cnlgLCBvciBiYWNrIHRvIHRoZSBhZG1pbiB3aGVuCmBOb25lYC4AAAAAAAAMc2V0X3RyZWFzdXJ5AAAAAQAAAAAAAAAIdHJlYXN1cnkAAAPoAAAAEwAAAAA=",
+        "AAAAAAAAAAAAAAAMZ2V0X3RyZWFzdXJ5AAAAAAAAAAEAAAPoAAAAEw==",
+        "AAAAAAAAADBGZWVzIGNvbGxlY3RlZCBpbiBgdG9rZW5gIGFuZCBub3QgeWV0IHdpdGhkcmF3bi4AAAAIZ2V0X2ZlZXMAAAABAAAAAAAAAAV0b2tlbgAAAAAAABMAAAABAAAACw==",
+        "AAAAAAAAAINTZW5kIGV2ZXJ5IGZlZSBjb2xsZWN0ZWQgc28gZmFyLCBpbiBlYWNoIHRva2VuLCB0byBgdG9gLiBOZWVkcyB0aGUKdHJlYXN1cnkncyBhdXRob3JpemF0aW9uIHdoZW4gb25lIGlzIHNldCwgdGhlIGFkbWluJ3Mgb3RoZXJ3aXNlLgAAAAANd2l0aGRyYXdfZmVlcwAAAAAAAAEAAAAAAAAAAnRvAAAAAAATAAAAAA==",
         "AAAAAAAAACtDcmVhdGUgYSBuZXcgcm9vbS4gQ2FsbGVyIGJlY29tZXMgUGxheWVyIEEuAAAAAAtjcmVhdGVfcm9vbQAAAAADAAAAAAAAAAdyb29tX2lkAAAAAAQAAAAAAAAACHBsYXllcl9hAAAAEwAAAAAAAAAPcGxheWVyX2FfcG9pbnRzAAAAAAsAAAABAAAH0AAAAARSb29t",
-        "AAAAAAAAAAAAAAANX19jb25zdHJ1Y3RvcgAAAAAAAAIAAAAAAAAABWFkbWluAAAAAAAAEwAAAAAAAAAIZ2FtZV9odWIAAAATAAAAAA==",
-        "AAAAAAAAAIpTdWJtaXQgYSBjb21taXRtZW50ID0gU0hBLTI1Nihyb29tX2lkIOKAliBpc2xhbmRfaWQg4oCWIHRpbGVfaWQg4oCWIHNhbHQpLgpUaGUgcHJlLWltYWdlIGlzIGtlcHQgc2VjcmV0OyBvbmx5IHRoZSBoYXNoIGlzIHN0b3JlZCBvbi1jaGFpbi4AAAAAAA1idXJ5X3RyZWFzdXJlAAAAAAAAAwAAAAAAAAAHcm9vbV9pZAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAACmNvbW1pdG1lbnQAAAAAA+4AAAAgAAAAAA==",
-        "AAAAAAAAANZSZXZlYWwgdGhlIE9QUE9ORU5UJ3MgdHJlYXN1cmUgdG8gY2xhaW0gdmljdG9yeS4KClRoZSBjYWxsZXIgcHJvdmlkZXMgKGlzbGFuZF9pZCwgdGlsZV9pZCwgc2FsdCkuIFRoZSBjb250cmFjdApyZWhhc2hlcyBhbmQgY2hlY2tzIGFnYWluc3QgdGhlICoqb3Bwb25lbnQncyoqIHN0b3JlZCBjb21taXRtZW50LgpJZiB0aGUgaGFzaCBtYXRjaGVzLCB0aGUgY2FsbGVyIHdpbnMuAAAAAAAPcmV2ZWFsX3RyZWFzdXJlAAAAAAUAAAAAAAAAB3Jvb21faWQAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAlpc2xhbmRfaWQAAAAAAAAEAAAAAAAAAAd0aWxlX2lkAAAAAAQAAAAAAAAABHNhbHQAAAPuAAAAIAAAAAA=" ]),
+        "AAAAAAAAAG5DcmVhdGUgYSByb29tIHVuZGVyIHRoZSBuZXh0IGZyZWUgc2VxdWVudGlhbCBpZCBhbmQgcmV0dXJuIGl0LCBzbwpjcmVhdG9ycyBuZWVkIG5vdCBwaWNrIGlkcyB0aGF0IG1heSBjb2xsaWRlLgAAAAAAEGNyZWF0ZV9yb29tX2F1dG8AAAACAAAAAAAAAAhwbGF5ZXJfYQAAABMAAAAAAAAAD3BsYXllcl9hX3BvaW50cwAAAAALAAAAAQAAAAQ=",
+        "AAAAAAAAAItDcmVhdGUgYSBuZXcgcm9vbSB3aXRoIHRoZSBnaXZlbiBjb21taXRtZW50IHNjaGVtZSwgZGlnIG1vZGUgYW5kCnJvbGVzLiBIaWRlci9zZWVrZXIgcm9vbXMgY2Fubm90IGRpZyBzaW11bHRhbmVvdXNseSBhbmQgbXVzdCBzZXQgYQpidWRnZXQuAAAAABdjcmVhdGVfcm9vbV93aXRoX2NvbmZpZwAAAAAEAAAAAAAAAAdyb29tX2lkAAAAAAQAAAAAAAAACHBsYXllcl9hAAAAEwAAAAAAAAAPcGxheWVyX2FfcG9pbnRzAAAAAAsAAAAAAAAABmNvbmZpZwAAAAAH0AAAAApSb29tQ29uZmlnAAAAAAABAAAH0AAAAARSb29t",
+        "AAAAAAAAAW5DcmVhdGUgYSByb3VuZCBvZiB0b3VybmFtZW50IHJvb21zIGluIG9uZSB0cmFuc2FjdGlvbiwgc28gdGhleSBhbGwKb3BlbiBvbiB0aGUgc2FtZSBsZWRnZXIuIEFkbWluIG9ubHkuIEVhY2ggYHBsYXllcl9hYCBpcyBzZWF0ZWQKd2l0aG91dCBzaWduaW5nIGFuZCBhdXRob3JpemVzIHRoZWlyIHBvaW50cyB3aXRoIHRoZWlyIG9wcG9uZW50IGluCmBzdGFydF9yb29tYDsgYSBzdGFrZSB0b2tlbiB3b3VsZCBoYXZlIHRvIGJlIGVzY3Jvd2VkIGZyb20gdGhlbQpoZXJlLCBzbyBzdWNoIGNvbmZpZ3MgYXJlIHJlamVjdGVkIHdpdGggYEludmFsaWRDb25maWdgLgpBbGwtb3Itbm90aGluZzogb25lIGJhZCBlbnRyeSByZWplY3RzIHRoZSBiYXRjaC4AAAAAABJjcmVhdGVfcm9vbXNfYmF0Y2gAAAAAAAEAAAAAAAAABXJvb21zAAAAAAAD6gAAB9AAAAALUm9vbVJlcXVlc3QAAAAAAQAAA+oAAAAE",
+        "AAAAAAAAACdQbGF5ZXIgQiBqb2lucyBhbiBleGlzdGluZyBwdWJsaWMgcm9vbS4AAAAACWpvaW5fcm9vbQAAAAAAAAMAAAAAAAAAB3Jvb21faWQAAAAABAAAAAAAAAAIcGxheWVyX2IAAAATAAAAAAAAAA9wbGF5ZXJfYl9wb2ludHMAAAAACwAAAAEAAAfQAAAABFJvb20=",
+        "AAAAAAAAADNQbGF5ZXIgQiBqb2lucyBhIHByaXZhdGUgcm9vbSB3aXRoIGl0cyBpbnZpdGUgY29kZS4AAAAAEWpvaW5fcHJpdmF0ZV9yb29tAAAAAAAABAAAAAAAAAAHcm9vbV9pZAAAAAAEAAAAAAAAAAhwbGF5ZXJfYgAAABMAAAAAAAAAD3BsYXllcl9iX3BvaW50cwAAAAALAAAAAAAAAAtpbnZpdGVfY29kZQAAAAAOAAAAAQAAB9AAAAAEUm9vbQ==",
+        "AAAAAAAAAKhXaXRoZHJhdyBhIHJvb20gdGhhdCBoYXMgbm90IHN0YXJ0ZWQgeWV0LiBPbmx5IGl0cyBjcmVhdG9yIG1heSBkbwp0aGlzOyBlc2Nyb3dlZCBzdGFrZXMgYXJlIHJlZnVuZGVkIGFuZCBldmVyeSBlbnRyeSB0aGUgcm9vbSBob2xkcwppcyByZW1vdmVkLCBzbyB0aGUgaWQgY2FuIGJlIHJldXNlZC4AAAALY2FuY2VsX3Jvb20AAAAAAgAAAAAAAAAHcm9vbV9pZAAAAAAEAAAAAAAAAAhwbGF5ZXJfYQAAABMAAAAA",
+        "AAAAAAAAANJQbGF5IGFuIGVuZGVkIHJvb20gYWdhaW46IGEgZnJlc2ggcm9vbSB1bmRlciB0aGUgbmV4dCBzZXF1ZW50aWFsIGlkCndpdGggdGhlIHNhbWUgcGxheWVycywgcG9pbnRzIGFuZCBjb25maWcsIHN0YXJ0ZWQgc3RyYWlnaHQgYXdheS4KQm90aCBwbGF5ZXJzIGF1dGhvcml6ZS4gVGhlIG5ldyByb29tIHJlY29yZHMgaXRzIHByZWRlY2Vzc29yIGluCmByZW1hdGNoX29mYC4AAAAAAAdyZW1hdGNoAAAAAAEAAAAAAAAAB3Jvb21faWQAAAAABAAAAAEAAAfQAAAABFJvb20=",
+        "AAAAAAAAADtCb3RoIHBsYXllcnMgY28tc2lnbiB0byBzdGFydC4gQ2FsbHMgR2FtZSBIdWIgYHN0YXJ0X2dhbWVgLgAAAAAKc3RhcnRfcm9vbQAAAAAABQAAAAAAAAAHcm9vbV9pZAAAAAAEAAAAAAAAAAhwbGF5ZXJfYQAAABMAAAAAAAAACHBsYXllcl9iAAAAEwAAAAAAAAAPcGxheWVyX2FfcG9pbnRzAAAAAAsAAAAAAAAAD3BsYXllcl9iX3BvaW50cwAAAAALAAAAAQAAB9AAAAAEUm9vbQ==",
+        "AAAAAAAAAMRSZWdpc3RlciBhIEJMUzEyLTM4MSBHMSBwdWJsaWMga2V5IGZvciBhZ2dyZWdhdGUgY28tc2lnbmluZy4KCmBwcm9vZl9vZl9wb3NzZXNzaW9uYCBpcyB0aGUgcGxheWVyJ3Mgc2lnbmF0dXJlIG92ZXIgdGhlaXIgb3duIHB1YmxpYwprZXksIHdoaWNoIHJ1bGVzIG91dCByb2d1ZS1rZXkgYXR0YWNrcyBvbiBhZ2dyZWdhdGVkIHNpZ25hdHVyZXMuAAAAEHJlZ2lzdGVyX2Jsc19rZXkAAAADAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAACnB1YmxpY19rZXkAAAAAA+4AAABgAAAAAAAAABNwcm9vZl9vZl9wb3NzZXNzaW9uAAAAA+4AAADAAAAAAA==",
+        "AAAAAAAAAKlSZWdpc3RlciB0aGUgZWQyNTUxOSBrZXkgdGhhdCBzaWducyB0aGlzIHBsYXllcidzIGJ1cmlhbHMgaW4KYHNpZ25lZF9idXJpYWxzYCByb29tcyBhbmQgZGVyaXZlcyB0aGVpciBzYWx0cyBpbiBgZGVyaXZlZF9zYWx0c2AKcm9vbXMuIFJlZ2lzdGVyaW5nIGFnYWluIHJlcGxhY2VzIHRoZSBrZXkuAAAAAAAAE3JlZ2lzdGVyX2J1cmlhbF9rZXkAAAAAAgAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAApwdWJsaWNfa2V5AAAAAAPuAAAAIAAAAAA=",
+        "AAAAAAAAAONUaGUgbWVzc2FnZSBib3RoIHBsYXllcnMgc2lnbiBmb3IgYHN0YXJ0X3Jvb21fYWdncmVnYXRlZGA6ClNIQS0yNTYoIHhkcihjb250cmFjdCkg4oCWIHJvb21faWQg4oCWIGNyZWF0ZWRfbm9uY2Ug4oCWIHhkcihwbGF5ZXJfYSkg4oCWCnhkcihwbGF5ZXJfYikg4oCWIHBsYXllcl9hX3BvaW50cyDigJYgcGxheWVyX2JfcG9pbnRzICkuIEFzayBmb3IgaXQgb25jZQpQbGF5ZXIgQiBoYXMgam9pbmVkLgAAAAARZ2V0X3N0YXJ0X21lc3NhZ2UAAAAAAAADAAAAAAAAAAdyb29tX2lkAAAAAAQAAAAAAAAAD3BsYXllcl9hX3BvaW50cwAAAAALAAAAAAAAAA9wbGF5ZXJfYl9wb2ludHMAAAAACwAAAAEAAAPuAAAAIA==",
+        "AAAAAAAAAK5TdGFydCBhIHJvb20gd2l0aCBvbmUgYWdncmVnYXRlZCBCTFMgc2lnbmF0dXJlIGZyb20gYm90aCBwbGF5ZXJzIGluCnBsYWNlIG9mIHR3byBzZXBhcmF0ZSBTb3JvYmFuIGF1dGhvcml6YXRpb25zLiBMZXRzIGEgcmVsYXllciBzdWJtaXQKdGhlIHN0YXJ0IHdpdGggYSBzaW5nbGUgMTkyLWJ5dGUgYmxvYi4AAAAAABVzdGFydF9yb29tX2FnZ3JlZ2F0ZWQAAAAAAAAEAAAAAAAAAAdyb29tX2lkAAAAAAQAAAAAAAAAD3BsYXllcl9hX3BvaW50cwAAAAALAAAAAAAAAA9wbGF5ZXJfYl9wb2ludHMAAAAACwAAAAAAAAAJc2lnbmF0dXJlAAAAAAAD7gAAAMAAAAABAAAH0AAAAARSb29t",
+        "AAAAAAAAAQRTdWJtaXQgYSBjb21taXRtZW50IGNvbXB1dGVkIHVuZGVyIHRoZSByb29tJ3MgYENvbW1pdG1lbnRTY2hlbWVgCihieSBkZWZhdWx0IFNIQS0yNTYocm9vbV9pZCDigJYgbm9uY2Ug4oCWIHJ1bGVzX2hhc2gg4oCWIGlzbGFuZF9pZCDigJYKdGlsZV9pZCDigJYgc2FsdCksIHdpdGggYHJ1bGVzX2hhc2hgIGZyb20gYGdldF9ydWxlc19oYXNoYCkuClRoZSBwcmUtaW1hZ2UgaXMga2VwdCBzZWNyZXQ7IG9ubHkgdGhlIGhhc2ggaXMgc3RvcmVkIG9uLWNoYWluLgAAAA1idXJ5X3RyZWFzdXJlAAAAAAAAAwAAAAAAAAAHcm9vbV9pZAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAACmNvbW1pdG1lbnQAAAAAA+4AAAAgAAAAAA==",
+        "AAAAAAAAAL5CdXJ5IHdpdGggYW4gZWQyNTUxOSBzaWduYXR1cmUgb3ZlciAocm9vbV9pZCDigJYgY29tbWl0bWVudCkgZnJvbSB0aGUKcGxheWVyJ3MgcmVnaXN0ZXJlZCBidXJpYWwga2V5LiBSZXF1aXJlZCBpbiByb29tcyB3aXRoCmBzaWduZWRfYnVyaWFsc2AsIHNvIGEgY29tcHJvbWlzZWQgc2Vzc2lvbiBrZXkgYWxvbmUgY2Fubm90IGJ1cnkuAAAAAAAUYnVyeV90cmVhc3VyZV9zaWduZWQAAAAEAAAAAAAAAAdyb29tX2lkAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAKY29tbWl0bWVudAAAAAAD7gAAACAAAAAAAAAACXNpZ25hdHVyZQAAAAAAA+4AAABAAAAAAA==",
+        "AAAAAAAAAOlSZXBsYWNlIGFuIGFscmVhZHktYnVyaWVkIGNvbW1pdG1lbnQuIE9ubHkgYWxsb3dlZCB3aGlsZSB0aGUgb3Bwb25lbnQKaGFzIG5vdCBidXJpZWQgeWV0ICh0aGUgcm9vbSBpcyBzdGlsbCBpbiB0aGUgQnVyeWluZyBwaGFzZSksIHNvIGEKbWlzdHlwZWQgY29tbWl0bWVudCBjYW4gYmUgZml4ZWQgYmVmb3JlIGl0IG1hdHRlcnMuIE5vdCBhdmFpbGFibGUgaW4Kcm9vbXMgd2l0aCBgc2lnbmVkX2J1cmlhbHNgLgAAAAAAAA9yZWJ1cnlfdHJlYXN1cmUAAAAAAwAAAAAAAAAHcm9vbV9pZAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAACmNvbW1pdG1lbnQAAAAAA+4AAAAgAAAAAA==",
+        "AAAAAAAAAH9EaWcgYSB0aWxlLiBNdXN0IGJlIHRoZSBjYWxsZXIncyB0dXJuLiBBbHRlcm5hdGluZyByb29tcyBvbmx5OyBzZWUKYGNvbW1pdF9kaWdgIGFuZCBgY29tbWl0X3JvdW5kX2RpZ2AgZm9yIHRoZSBvdGhlciBkaWcgbW9kZXMuAAAAAANkaWcAAAAABAAAAAAAAAAHcm9vbV9pZAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAACWlzbGFuZF9pZAAAAAAAAAQAAAAAAAAAB3RpbGVfaWQAAAAABAAAAAA=",
+        "AAAAAAAAAFtQYXNzIHRoZSB0dXJuIHdpdGggYWN0aW9uIHBvaW50cyBsZWZ0IG92ZXIuIE9ubHkgaW4gcm9vbXMgdGhhdApzcGVuZCBhY3Rpb24gcG9pbnRzIG9uIGRpZ3MuAAAAAAhlbmRfdHVybgAAAAIAAAAAAAAAB3Jvb21faWQAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAA==",
+        "AAAAAAAAAQNVbmRvIHRoZSBtb3N0IHJlY2VudCBkaWcgd2hlbiBib3RoIHBsYXllcnMgYXV0aG9yaXplIGl0LCBlLmcuIGFmdGVyCmEgVUkgZ2xpdGNoIHNlbnQgdGhlIHdyb25nIHRpbGUuIFRoZSB0dXJuIHJldHVybnMgdG8gdGhlIGRpZ2dlci4KTm90IGF2YWlsYWJsZSBpbiBzaW11bHRhbmVvdXMgb3IgYWN0aW9uLXBvaW50IHJvb21zLCB3aGVyZSB0aGUKdHVybiBiZWZvcmUgdGhlIGRpZyBjYW5ub3QgYmUgcmVjb3ZlcmVkIGZyb20gdGhlIGRpZyBsb2cgYWxvbmUuAAAAAAxyb2xsYmFja19kaWcAAAABAAAAAAAAAAdyb29tX2lkAAAAAAQAAAAA",
+        "AAAAAAAAAJ1Db21taXQgdG8gdGhpcyB0dXJuJ3MgZGlnIGluIGEgY29tbWl0dGVkLWRpZyByb29tLCBoaWRpbmcgdGhlIHRhcmdldAp1bnRpbCBgcmV2ZWFsX2RpZ2AgbGFuZHMgaXQuIEEgbGF0ZXIgY29tbWl0IG9uIHRoZSBzYW1lIHR1cm4gcmVwbGFjZXMKYW4gdW5yZXZlYWxlZCBvbmUuAAAAAAAACmNvbW1pdF9kaWcAAAAAAAMAAAAAAAAAB3Jvb21faWQAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAApjb21taXRtZW50AAAAAAPuAAAAIAAAAAA=",
+        "AAAAAAAAADZPcGVuIHRoZSBkaWcgY29tbWl0dGVkIHdpdGggYGNvbW1pdF9kaWdgIGFuZCBhcHBseSBpdC4AAAAAAApyZXZlYWxfZGlnAAAAAAAFAAAAAAAAAAdyb29tX2lkAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAJaXNsYW5kX2lkAAAAAAAABAAAAAAAAAAHdGlsZV9pZAAAAAAEAAAAAAAAAARzYWx0AAAD7gAAACAAAAAA",
+        "AAAAAAAAAIVDb21taXQgdG8gdGhpcyByb3VuZCdzIGRpZyBpbiBhIHNpbXVsdGFuZW91cyByb29tLiBPbmNlIGJvdGggcGxheWVycwpoYXZlIGNvbW1pdHRlZCwgZWFjaCBvcGVucyB0aGVpciBjaG9pY2Ugd2l0aCBgcmV2ZWFsX3JvdW5kX2RpZ2AuAAAAAAAAEGNvbW1pdF9yb3VuZF9kaWcAAAADAAAAAAAAAAdyb29tX2lkAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAKY29tbWl0bWVudAAAAAAD7gAAACAAAAAA",
+        "AAAAAAAAAQJPcGVuIHRoaXMgcm91bmQncyBkaWcgY29tbWl0bWVudC4gV2hlbiB0aGUgc2Vjb25kIHBsYXllciByZXZlYWxzLApib3RoIGRpZ3MgYXJlIGFwcGxpZWQsIFBsYXllciBBJ3MgZmlyc3QuIEEgdGlsZSB0aGF0IGlzIGFscmVhZHkgZHVnCmlzIHdhc3RlZCByYXRoZXIgdGhhbiByZWplY3RlZCwgc2luY2UgaXQgd2FzIGNob3NlbiBibGluZDsgaWYgYm90aApwbGF5ZXJzIHBpY2sgdGhlIHNhbWUgZnJlc2ggdGlsZSwgYm90aCBkaWdzIGFyZSByZWNvcmRlZC4AAAAAABByZXZlYWxfcm91bmRfZGlnAAAABQAAAAAAAAAHcm9vbV9pZAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAACWlzbGFuZF9pZAAAAAAAAAQAAAAAAAAAB3RpbGVfaWQAAAAABAAAAAAAAAAEc2FsdAAAA+4AAAAgAAAAAA==",
+        "AAAAAAAAAD1Db21taXQvcmV2ZWFsIHByb2dyZXNzIG9mIHRoZSBjdXJyZW50IHNpbXVsdGFuZW91cy1kaWcgcm91bmQuAAAAAAAAD2dldF9yb3VuZF9zdGF0ZQAAAAABAAAAAAAAAAdyb29tX2lkAAAAAAQAAAABAAAH0AAAAApSb3VuZFN0YXRlAAA=",
+        "AAAAAAAAAMBDbGFpbSB2aWN0b3J5IGFzIHRoZSBoaWRlciBvbmNlIHRoZSBzZWVrZXIgaGFzIHNwZW50IHRoZWlyIHdob2xlIGRpZwpidWRnZXQgd2l0aG91dCByZXZlYWxpbmcgdGhlIHRyZWFzdXJlLiBgbGVhdmVzYCBvcGVuIHRoZSBoaWRlcidzCmJ1cmlhbCwgdG8gc2hvdyB0aGVyZSB3ZXJlIHRyZWFzdXJlcyBvbiB0aGUgYm9hcmQgdG8gZmluZC4AAAAPY2xhaW1faGlkZXJfd2luAAAAAAMAAAAAAAAAB3Jvb21faWQAAAAABAAAAAAAAAAFaGlkZXIAAAAAAAATAAAAAAAAAAZsZWF2ZXMAAAAAA+oAAAfQAAAADFRyZWFzdXJlTGVhZgAAAAA=",
+        "AAAAAAAAAUJPcGVuIGFsbCBvZiBgcGxheWVyYCdzIHRyZWFzdXJlcyBpbiBhIG11bHRpLXRyZWFzdXJlIHJvb20sIG9uY2UgdGhlCmV4aGF1c3RlZCBib2FyZCdzIHJldmVhbCB3aW5kb3cgaGFzIGNsb3NlZCBhbmQgdGhleSBjYW4gbm8gbG9uZ2VyIGJlCmZvdW5kLiBgbGVhdmVzYCBhcmUgaW4gdGhlIG9yZGVyIHRoZSBNZXJrbGUgcm9vdCB3YXMgYnVpbHQgZnJvbToKYWRqYWNlbnQgbm9kZXMgcGFpcmVkIGxldmVsIGJ5IGxldmVsLCBhbiBvZGQgbGFzdCBub2RlIGNhcnJpZWQgdXAuClRoZXkgbXVzdCBiZSBgdHJlYXN1cmVzYCBkaXN0aW5jdCB0aWxlcyBvbiB0aGUgYm9hcmQuAAAAAAAOb3Blbl90cmVhc3VyZXMAAAAAAAMAAAAAAAAAB3Jvb21faWQAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAZsZWF2ZXMAAAAAA+oAAAfQAAAADFRyZWFzdXJlTGVhZgAAAAA=",
+        "AAAAAAAAAThTZXR0bGUgYW4gZXhoYXVzdGVkIGJvYXJkIGFzIGEgZHJhdyBvbmNlIGl0cyByZXZlYWwgd2luZG93IGhhcwpjbG9zZWQgd2l0aG91dCBhIHdpbm5pbmcgcmV2ZWFsLiBBbnlvbmUgbWF5IGNhbGwgdGhpcy4KCkluIGEgbXVsdGktdHJlYXN1cmUgcm9vbSBldmVyeSBidXJpYWwgbXVzdCBmaXJzdCBiZSBvcGVuZWQgdGhyb3VnaApgb3Blbl90cmVhc3VyZXNgLCB3aXRoaW4gYSBzZWNvbmQgd2luZG93IGFzIGxvbmcgYXMgdGhlIGZpcnN0LiBBCnBsYXllciB3aG8gbGVhdmVzIHRoZWlycyB1bm9wZW5lZCBmb3JmZWl0cyB0byBvbmUgd2hvIG9wZW5lZC4AAAANZmluYWxpemVfZHJhdwAAAAAAAAIAAAAAAAAAB3Jvb21faWQAAAAABAAAAAAAAAAGY2FsbGVyAAAAAAATAAAAAA==",
+        "AAAAAAAAAO1XaW4gYSBnYW1lIHRoZSBvcHBvbmVudCBoYXMgc3RhbGxlZDogdGhleSBhcmUgb24gdHVybiAob3IsIGluIGEKc2ltdWx0YW5lb3VzIHJvb20sIGJlaGluZCBpbiB0aGUgY3VycmVudCByb3VuZCkgYW5kIGhhdmUgbm90IG1vdmVkCmJ5IHRoZSB0dXJuIGRlYWRsaW5lLiBPbmNlIHRoZSBib2FyZCBpcyBleGhhdXN0ZWQgdGhlIHJldmVhbCB3aW5kb3cKYW5kIGBmaW5hbGl6ZV9kcmF3YCB0YWtlIG92ZXIgaW5zdGVhZC4AAAAAAAANY2xhaW1fdGltZW91dAAAAAAAAAIAAAAAAAAAB3Jvb21faWQAAAAABAAAAAAAAAAIY2xhaW1hbnQAAAATAAAAAA==",
+        "AAAAAAAAAEFDb25jZWRlIGEgc3RhcnRlZCBnYW1lLCBkdXJpbmcgYnVyaWFsIG9yIHBsYXkuIFRoZSBvcHBvbmVudCB3aW5zLgAAAAAAAAdmb3JmZWl0AAAAAAIAAAAAAAAAB3Jvb21faWQAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAA==",
+        "AAAAAAAAAHlXaW4gYSBnYW1lIHdob3NlIG9wcG9uZW50IG5ldmVyIGJ1cmllZDogYHBsYXllcmAgaGFzIGNvbW1pdHRlZCwKdGhlIG9wcG9uZW50IGhhcyBub3QsIGFuZCB0aGUgYnVyaWFsIGRlYWRsaW5lIGhhcyBwYXNzZWQuAAAAAAAAD2NsYWltX2FiYW5kb25lZAAAAAACAAAAAAAAAAdyb29tX2lkAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAA=",
+        "AAAAAAAAAStDYWxsIG9mZiBhIHN0YXJ0ZWQgZ2FtZSBuZWl0aGVyIHBsYXllciBidXJpZWQgaW4gYnkgdGhlIGJ1cmlhbApkZWFkbGluZS4gVGhlcmUgaXMgbm8gcmVzdWx0OiB0aGUgaHViIHNlc3Npb24gaXMgYWJvcnRlZCwgZXNjcm93ZWQKc3Rha2VzIGFyZSByZWZ1bmRlZCwgYW5kIHRoZSByb29tIGVuZHMgd2l0aG91dCBhIHdpbm5lciwgbGVhdmluZwpyYXRpbmdzIGFuZCBzdGF0cyB1bnRvdWNoZWQuIEFuIGFib3J0ZWQgc2VyaWVzIGdhbWUgY291bnRzIGZvcgpub2JvZHkgYW5kIHRoZSBzZXJpZXMgc2Vzc2lvbiBzdGF5cyBvcGVuLgAAAAAKYWJvcnRfcm9vbQAAAAAAAgAAAAAAAAAHcm9vbV9pZAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAA",
+        "AAAAAAAAAHFSZXNlbmQgYSByb29tJ3MgaHViIHJlcG9ydCB0aGF0IGZhaWxlZCB3aGVuIHRoZSBnYW1lIGZpbmlzaGVkLCB0bwp0aGUgaHViIGl0IHdhcyBtZWFudCBmb3IuIEFueW9uZSBtYXkgY2FsbCB0aGlzLgAAAAAAABByZXRyeV9odWJfcmVwb3J0AAAAAQAAAAAAAAAHcm9vbV9pZAAAAAAEAAAAAA==",
+        "AAAAAAAAAAAAAAAWZ2V0X3BlbmRpbmdfaHViX3JlcG9ydAAAAAAAAQAAAAAAAAAHcm9vbV9pZAAAAAAEAAAAAQAAA+gAAAfQAAAAEFBlbmRpbmdIdWJSZXBvcnQ=",
+        "AAAAAAAAAS1SZXZlYWwgdGhlIE9QUE9ORU5UJ3MgdHJlYXN1cmUgdG8gY2xhaW0gdmljdG9yeS4KClRoZSBjYWxsZXIgcHJvdmlkZXMgKGlzbGFuZF9pZCwgdGlsZV9pZCwgc2FsdCkuIFRoZSBjb250cmFjdApyZWhhc2hlcyBhbmQgY2hlY2tzIGFnYWluc3QgdGhlICoqb3Bwb25lbnQncyoqIHN0b3JlZCBjb21taXRtZW50LgpJZiB0aGUgaGFzaCBtYXRjaGVzLCB0aGUgY2FsbGVyIHdpbnMuIEluIHNpbXVsdGFuZW91cyByb29tcyB0aGVyZSBpcwpubyB0dXJuIG9yZGVyLCBzbyBlaXRoZXIgcGxheWVyIG1heSByZXZlYWwgYXQgYW55IHRpbWUuAAAAAAAAD3JldmVhbF90cmVhc3VyZQAAAAAFAAAAAAAAAAdyb29tX2lkAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAJaXNsYW5kX2lkAAAAAAAABAAAAAAAAAAHdGlsZV9pZAAAAAAEAAAAAAAAAARzYWx0AAAD7gAAACAAAAAA",
+        "AAAAAAAAAgxSZXZlYWwgb25lIG9mIHRoZSBvcHBvbmVudCdzIHRyZWFzdXJlcyBpbiBhIG11bHRpLXRyZWFzdXJlIHJvb20uCmBwcm9vZmAgaG9sZHMgdGhlIHNpYmxpbmcgaGFzaGVzIGZyb20gdGhlIHRyZWFzdXJlJ3MgY29tbWl0bWVudCB1cAp0byB0aGUgYnVyaWVkIE1lcmtsZSByb290LCB3aGVyZSBlYWNoIHBhcmVudCBpcyBTSEEtMjU2IG9mIGl0cyB0d28KY2hpbGRyZW4gaW4gYXNjZW5kaW5nIG9yZGVyLiBUaGUgY2FsbGVyIHdpbnMgb24gZmluZGluZyBhIG1ham9yaXR5Cm9mIHRoZSBvcHBvbmVudCdzIHRyZWFzdXJlczsgYSBmaW5kIHNob3J0IG9mIHRoYXQga2VlcHMgdGhlIHR1cm4uCgpJbiBhIGR1ZWwsIHRoZSBmaW5kIHRoYXQgd2lucyBtdXN0IGFsc28gb3BlbiBhbGwgb2YgdGhlIGNhbGxlcidzCm93biB0cmVhc3VyZXMgaW4gYGxlYXZlc2AgKHNlZSBgb3Blbl90cmVhc3VyZXNgKS4gUGFzcyBhbiBlbXB0eQpsaXN0IGZvciBhbnkgZWFybGllciBmaW5kLCBhcyBsZWF2ZXMgc2VudCB0aGVuIGdpdmUgdGhlbSBhd2F5LgAAABVyZXZlYWxfdHJlYXN1cmVfcHJvb2YAAAAAAAAHAAAAAAAAAAdyb29tX2lkAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAJaXNsYW5kX2lkAAAAAAAABAAAAAAAAAAHdGlsZV9pZAAAAAAEAAAAAAAAAARzYWx0AAAD7gAAACAAAAAAAAAABXByb29mAAAAAAAD6gAAA+4AAAAgAAAAAAAAAAZsZWF2ZXMAAAAAA+oAAAfQAAAADFRyZWFzdXJlTGVhZgAAAAA=",
+        "AAAAAAAAAXxSZXZlYWwgdGhlIG9wcG9uZW50J3MgdHJlYXN1cmUgaW4gYSBgZGVyaXZlZF9zYWx0c2Agcm9vbS4gSW5zdGVhZCBvZgp0aGUgc2FsdCwgdGhlIGNhbGxlciBwYXNzZXMgdGhlIG9wcG9uZW50J3MgZWQyNTUxOSBzaWduYXR1cmUgb3ZlcgpgZ2V0X3NhbHRfbWVzc2FnZWAsIG1hZGUgd2l0aCB0aGVpciByZWdpc3RlcmVkIGJ1cmlhbCBrZXk7IHRoZQpzYWx0IGlzIFNIQS0yNTYgb2YgdGhhdCBzaWduYXR1cmUuIEVkMjU1MTkgc2lnbmF0dXJlcyBhcmUKZGV0ZXJtaW5pc3RpYywgc28gdGhlIHNjaGVtZSB5aWVsZHMgYSBzdHJvbmcgc2FsdCBhIGNsaWVudCBjYW4KYWx3YXlzIHJlLWRlcml2ZSwgYW5kIHRoZSBjb250cmFjdCBjYW4gY2hlY2sgaXQgd2FzIGZvbGxvd2VkLgAAABdyZXZlYWxfdHJlYXN1cmVfZGVyaXZlZAAAAAAFAAAAAAAAAAdyb29tX2lkAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAJaXNsYW5kX2lkAAAAAAAABAAAAAAAAAAHdGlsZV9pZAAAAAAEAAAAAAAAAA5zYWx0X3NpZ25hdHVyZQAAAAAD7gAAAEAAAAAA",
+        "AAAAAAAAAK5TZW5kIGEgdm9sdW50YXJ5IHRpcCBvZiBgYW1vdW50YCBvZiBgdG9rZW5gIHRvIHRoZSBvcHBvbmVudCBhZnRlciB0aGUKZ2FtZSBoYXMgZW5kZWQuIFRoZSB0cmFuc2ZlciBnb2VzIGRpcmVjdGx5IGZyb20gYGZyb21gIHRvIHRoZQpvcHBvbmVudCBhbmQgaXMgcmVjb3JkZWQgYWdhaW5zdCB0aGUgcm9vbS4AAAAAAAN0aXAAAAAABAAAAAAAAAAHcm9vbV9pZAAAAAAEAAAAAAAAAARmcm9tAAAAEwAAAAAAAAAFdG9rZW4AAAAAAAATAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAA",
+        "AAAAAAAAAQhSZXBvcnQgYSBmaW5pc2hlZCBnYW1lIGFzIHN1c3BpY2lvdXMgKGNvbGx1c2lvbiwgYm90cywgLi4uKS4KCkFueW9uZSBtYXkgcmVwb3J0LCBvbmNlIHBlciByb29tIGFuZCBhdCBtb3N0CmBtYXhfcmVwb3J0c19wZXJfcmVwb3J0ZXJgIHRpbWVzIChzZWUgYENvbmZpZ2ApIHBlcgpgUkVQT1JUX1dJTkRPV19MRURHRVJTYC4gUm9vbXMga2VlcCBhdCBtb3N0IGBSRVBPUlRTX0NBUGAgcmVwb3J0cy4KT3BlcmF0b3JzIHJlYWQgdGhlbSB2aWEgYGdldF9yZXBvcnRzYC4AAAALcmVwb3J0X2dhbWUAAAAAAwAAAAAAAAAHcm9vbV9pZAAAAAAEAAAAAAAAAAhyZXBvcnRlcgAAABMAAAAAAAAABnJlYXNvbgAAAAAAEQAAAAA=",
+        "AAAAAAAAAclXYWl0IGZvciBhbiBvcHBvbmVudCBzdGFraW5nIHdpdGhpbiB0aGUgc2FtZSBwb3dlciBvZiB0d28gYXMKYHBvaW50c2AuIElmIG9uZSBpcyBhbHJlYWR5IHdhaXRpbmcsIGEgZGVmYXVsdCByb29tIGlzIGNyZWF0ZWQKdW5kZXIgdGhlIG5leHQgZnJlZSBpZCB3aXRoIHRoZW0gYXMgUGxheWVyIEEgYW5kIGBwbGF5ZXJgIGFzIFBsYXllcgpCLCBzdGFydGVkIGF0IG9uY2UsIGFuZCBpdHMgaWQgcmV0dXJuZWQ7IGVudGVyaW5nIHRoZSBxdWV1ZSBpcyBlYWNoCnBsYXllcidzIGNvbnNlbnQgdG8gdGhhdCBzdGFydC4gT3RoZXJ3aXNlIGBwbGF5ZXJgIHdhaXRzIGFuZCBgTm9uZWAKaXMgcmV0dXJuZWQuIFRoZSB3YWl0aW5nIHBsYXllciBkb2VzIG5vdCBzaWduIHRoZSBtYXRjaCwgc28gdGhpcwpmYWlscyB3aXRoIGBIdWJFc2Nyb3dzYCB3aGlsZSB0aGUgY29udHJhY3QncyBodWIgZXNjcm93cyBwb2ludHMuAAAAAAAAC2VudGVyX3F1ZXVlAAAAAAIAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAGcG9pbnRzAAAAAAALAAAAAQAAA+gAAAAE",
+        "AAAAAAAAAC5TdG9wIHdhaXRpbmcgaW4gdGhlIHF1ZXVlIGBwb2ludHNgIGZhbGxzIGludG8uAAAAAAALbGVhdmVfcXVldWUAAAAAAgAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAZwb2ludHMAAAAAAAsAAAAA",
+        "AAAAAAAAADtXaG8gaXMgd2FpdGluZyBpbiB0aGUgcXVldWUgYHBvaW50c2AgZmFsbHMgaW50bywgaWYgYW55b25lLgAAAAAJZ2V0X3F1ZXVlAAAAAAAAAQAAAAAAAAAGcG9pbnRzAAAAAAALAAAAAQAAA+gAAAfQAAAAClF1ZXVlRW50cnkAAA==",
+        "AAAAAAAAAGpDaGFsbGVuZ2UgYG9wcG9uZW50YCB0byBhIGdhbWUgYXQgYHBvaW50c2AuIFRoZSBjaGFsbGVuZ2Ugd2FpdHMKdW50aWwgdGhlIG9wcG9uZW50IGFjY2VwdHMgb3IgZGVjbGluZXMgaXQuAAAAAAAJY2hhbGxlbmdlAAAAAAAAAwAAAAAAAAAKY2hhbGxlbmdlcgAAAAAAEwAAAAAAAAAIb3Bwb25lbnQAAAATAAAAAAAAAAZwb2ludHMAAAAAAAsAAAAA",
+        "AAAAAAAAAU5BY2NlcHQgYSBwZW5kaW5nIGNoYWxsZW5nZSwgcHV0dGluZyB1cCBgb3Bwb25lbnRfcG9pbnRzYC4gQSBkZWZhdWx0CnJvb20gaXMgY3JlYXRlZCB1bmRlciB0aGUgbmV4dCBmcmVlIGlkIHdpdGggdGhlIGNoYWxsZW5nZXIgYXMKUGxheWVyIEEsIGFuZCBzdGFydGVkIGF0IG9uY2U7IGlzc3VpbmcgdGhlIGNoYWxsZW5nZSB3YXMgdGhlCmNoYWxsZW5nZXIncyBjb25zZW50IHRvIHRoYXQgc3RhcnQuIFRoZSBjaGFsbGVuZ2VyIGRvZXMgbm90IHNpZ24KaGVyZSwgc28gdGhpcyBmYWlscyB3aXRoIGBIdWJFc2Nyb3dzYCB3aGlsZSB0aGUgY29udHJhY3QncyBodWIKZXNjcm93cyBwb2ludHMuAAAAAAAQYWNjZXB0X2NoYWxsZW5nZQAAAAMAAAAAAAAACmNoYWxsZW5nZXIAAAAAABMAAAAAAAAACG9wcG9uZW50AAAAEwAAAAAAAAAPb3Bwb25lbnRfcG9pbnRzAAAAAAsAAAABAAAH0AAAAARSb29t",
+        "AAAAAAAAAB5UdXJuIGRvd24gYSBwZW5kaW5nIGNoYWxsZW5nZS4AAAAAABFkZWNsaW5lX2NoYWxsZW5nZQAAAAAAAAIAAAAAAAAACmNoYWxsZW5nZXIAAAAAABMAAAAAAAAACG9wcG9uZW50AAAAEwAAAAA=",
+        "AAAAAAAAAEhQb2ludHMgb2YgdGhlIHBlbmRpbmcgY2hhbGxlbmdlIGZyb20gYGNoYWxsZW5nZXJgIHRvIGBvcHBvbmVudGAsIGlmCmFueS4AAAANZ2V0X2NoYWxsZW5nZQAAAAAAAAIAAAAAAAAACmNoYWxsZW5nZXIAAAAAABMAAAAAAAAACG9wcG9uZW50AAAAEwAAAAEAAAPoAAAACw==",
+        "AAAAAAAAAGNPcGVuIGEgc2VyaWVzIGJldHdlZW4gdHdvIHBsYXllcnMsIHdvbiBieSB0aGUgZmlyc3QgdG8KYHdpbnNfbmVlZGVkYCBnYW1lcy4gQm90aCBwbGF5ZXJzIGF1dGhvcml6ZS4AAAAADWNyZWF0ZV9zZXJpZXMAAAAAAAAEAAAAAAAAAAlzZXJpZXNfaWQAAAAAAAAEAAAAAAAAAAhwbGF5ZXJfYQAAABMAAAAAAAAACHBsYXllcl9iAAAAEwAAAAAAAAALd2luc19uZWVkZWQAAAAABAAAAAEAAAfQAAAABlNlcmllcwAA",
+        "AAAAAAAAAMJDcmVhdGUgdGhlIHNlcmllcycgbmV4dCBnYW1lIGFzIGEgcm9vbSB3aXRoIGJvdGggcGxheWVycyBzZWF0ZWQsCnJlYWR5IGZvciBgc3RhcnRfcm9vbWAuIFRoZSBwcmV2aW91cyBnYW1lIG11c3QgaGF2ZSBlbmRlZC4gU2VyaWVzCnJvb21zIGNhbm5vdCBiZSBwcml2YXRlLCBzaW5jZSBib3RoIHBsYXllcnMgYXJlIGFscmVhZHkgc2VhdGVkLgAAAAAAEmNyZWF0ZV9zZXJpZXNfcm9vbQAAAAAABQAAAAAAAAAJc2VyaWVzX2lkAAAAAAAABAAAAAAAAAAHcm9vbV9pZAAAAAAEAAAAAAAAAA9wbGF5ZXJfYV9wb2ludHMAAAAACwAAAAAAAAAPcGxheWVyX2JfcG9pbnRzAAAAAAsAAAAAAAAABmNvbmZpZwAAAAAH0AAAAApSb29tQ29uZmlnAAAAAAABAAAH0AAAAARSb29t",
+        "AAAAAAAAAAAAAAAKZ2V0X3NlcmllcwAAAAAAAQAAAAAAAAAJc2VyaWVzX2lkAAAAAAAABAAAAAEAAAfQAAAABlNlcmllcwAA",
+        "AAAAAAAAAL1CYWNrIG9uZSBwbGF5ZXIgb2YgYSBzdGFydGVkIHJvb20gd2l0aCBgYW1vdW50YCBvZiB0aGUgcm9vbSdzCnN0YWtlIHRva2VuLCB1bnRpbCB0aGUgZ2FtZSBlbmRzLiBSb29tcyB3aXRob3V0IGEgc3Rha2UgdG9rZW4gdGFrZQpubyBiZXRzLiBBIGJldHRvciBtYXkgYWRkIHRvIHRoZWlyIGJldCBidXQgbm90IHN3aXRjaCBzaWRlcy4AAAAAAAAJcGxhY2VfYmV0AAAAAAAABAAAAAAAAAAHcm9vbV9pZAAAAAAEAAAAAAAAAAZiZXR0b3IAAAAAABMAAAAAAAAAC29uX3BsYXllcl9hAAAAAAEAAAAAAAAABmFtb3VudAAAAAAACwAAAAA=",
+        "AAAAAAAAAMtDb2xsZWN0IGEgYmV0IG9uY2UgdGhlIGdhbWUgaGFzIGVuZGVkLiBCYWNrZXJzIG9mIHRoZSB3aW5uZXIgc3BsaXQKdGhlIHdob2xlIGJvb2sgcHJvIHJhdGEgdG8gdGhlaXIgc3Rha2VzOyBhZnRlciBhIGRyYXcsIG9yIHdoZW4Kbm9ib2R5IGJhY2tlZCB0aGUgd2lubmVyLCBldmVyeSBiZXQgaXMgcmVmdW5kZWQuIFJldHVybnMgdGhlIGFtb3VudApwYWlkLgAAAAAJY2xhaW1fYmV0AAAAAAAAAgAAAAAAAAAHcm9vbV9pZAAAAAAEAAAAAAAAAAZiZXR0b3IAAAAAABMAAAABAAAACw==",
+        "AAAAAAAAACZUb3RhbCBzdGFrZXMgb24gZWFjaCBwbGF5ZXIgb2YgYSByb29tLgAAAAAADGdldF9iZXRfYm9vawAAAAEAAAAAAAAAB3Jvb21faWQAAAAABAAAAAEAAAfQAAAAB0JldEJvb2sA",
+        "AAAAAAAAAEpTdWJzY3JpYmUgYG9ic2VydmVyYCB0byBhIHJvb20ncyBldmVudHMuIFdhdGNoaW5nIGEgcm9vbSB0d2ljZSBpcyBhCm5vLW9wLgAAAAAACndhdGNoX3Jvb20AAAAAAAIAAAAAAAAAB3Jvb21faWQAAAAABAAAAAAAAAAIb2JzZXJ2ZXIAAAATAAAAAA==",
+        "AAAAAAAAAChTdG9wIHNlbmRpbmcgYG9ic2VydmVyYCBhIHJvb20ncyBldmVudHMuAAAADHVud2F0Y2hfcm9vbQAAAAIAAAAAAAAAB3Jvb21faWQAAAAABAAAAAAAAAAIb2JzZXJ2ZXIAAAATAAAAAA==",
+        "AAAAAAAAACRBZGRyZXNzZXMgY3VycmVudGx5IHdhdGNoaW5nIGEgcm9vbS4AAAANZ2V0X29ic2VydmVycwAAAAAAAAEAAAAAAAAAB3Jvb21faWQAAAAABAAAAAEAAAPqAAAAEw==",
+        "AAAAAAAAADRSZWFkIHJvb20gc3RhdGUgKHJldHVybnMgdGhlIFJvb20gc3RydWN0IG9yIHBhbmljcykuAAAACGdldF9yb29tAAAAAQAAAAAAAAAHcm9vbV9pZAAAAAAEAAAAAQAAB9AAAAAEUm9vbQ==",
+        "AAAAAAAAAClBbGlhcyB1c2VkIGJ5IHRoZSBmcm9udGVuZCBzZXJ2aWNlIGxheWVyLgAAAAAAAAhnZXRfZ2FtZQAAAAEAAAAAAAAAB3Jvb21faWQAAAAABAAAAAEAAAfQAAAABFJvb20=",
+        "AAAAAAAAACxBIHBhZ2Ugb2YgdGhlIHRpcHMgc2VudCBpbiBhIHJvb20sIGluIG9yZGVyLgAAAAhnZXRfdGlwcwAAAAIAAAAAAAAAB3Jvb21faWQAAAAABAAAAAAAAAAGY3Vyc29yAAAAAAfQAAAABkN1cnNvcgAAAAAAAQAAB9AAAAAHVGlwUGFnZQA=",
+        "AAAAAAAAAEFXaGVuIGVhY2ggY29tbWl0bWVudCB3YXMgYnVyaWVkLiBEb2VzIG5vdCBleHBvc2UgdGhlIGNvbW1pdG1lbnRzLgAAAAAAABJnZXRfYnVyaWFsX2xlZGdlcnMAAAAAAAEAAAAAAAAAB3Jvb21faWQAAAAABAAAAAEAAAfQAAAADUJ1cmlhbExlZGdlcnMAAAA=",
+        "AAAAAAAAAJZQdWJsaXNoIGJvdGggc3RvcmVkIGNvbW1pdG1lbnRzIGFuZCBhbnkgcmV2ZWFscyBvbmNlIHRoZSByb29tIGhhcwplbmRlZCwgc28gYW55b25lIGNhbiByZS12ZXJpZnkgdGhlIG91dGNvbWUuIFJlZnVzZXMgd2hpbGUgdGhlIGdhbWUgaXMKc3RpbGwgcnVubmluZy4AAAAAAA9nZXRfY29tbWl0bWVudHMAAAAAAQAAAAAAAAAHcm9vbV9pZAAAAAAEAAAAAQAAB9AAAAAUUHVibGlzaGVkQ29tbWl0bWVudHM=",
+        "AAAAAAAAADxBIHBhZ2Ugb2YgdGhlIHJlcG9ydHMgZmlsZWQgYWdhaW5zdCBhIHJvb20sIGluIGZpbGluZyBvcmRlci4AAAALZ2V0X3JlcG9ydHMAAAAAAgAAAAAAAAAHcm9vbV9pZAAAAAAEAAAAAAAAAAZjdXJzb3IAAAAAB9AAAAAGQ3Vyc29yAAAAAAABAAAH0AAAAApSZXBvcnRQYWdlAAA=",
+        "AAAAAAAAAC9BIHBhZ2Ugb2YgdGhlIHJvb20ncyBkaWcgbG9nLCBvbGRlc3QgZGlnIGZpcnN0LgAAAAAIZ2V0X2RpZ3MAAAACAAAAAAAAAAdyb29tX2lkAAAAAAQAAAAAAAAABmN1cnNvcgAAAAAH0AAAAAZDdXJzb3IAAAAAAAEAAAfQAAAAB0RpZ1BhZ2UA",
+        "AAAAAAAAAIdUaGUgbW9zdCByZWNlbnQgZXZlbnRzIGZvciBhIHJvb20sIG9sZGVzdCBmaXJzdCAoYXQgbW9zdCB0aGUKY29uZmlndXJlZCBgbWF4X3JlY2VudF9ldmVudHNgKS4gRW1wdHkgaWYgdGhlIHJvb20gaGFzIG5vIHJlY29yZGVkIGV2ZW50cy4AAAAAEWdldF9yZWNlbnRfZXZlbnRzAAAAAAAAAQAAAAAAAAAHcm9vbV9pZAAAAAAEAAAAAQAAA+oAAAfQAAAACVJvb21FdmVudAAAAA==",
+        "AAAAAAAAAEdBIHBhZ2Ugb2YgdGhlIGxvYmJ5OiByb29tcyBzdGlsbCB3YWl0aW5nIGZvciBhbiBvcHBvbmVudCwgb2xkZXN0CmZpcnN0LgAAAAAOZ2V0X29wZW5fcm9vbXMAAAAAAAEAAAAAAAAABmN1cnNvcgAAAAAH0AAAAAZDdXJzb3IAAAAAAAEAAAfQAAAACFJvb21QYWdl",
+        "AAAAAAAAAHJBIHBhZ2Ugb2YgdGhlIGlkcyBvZiB0aGUgcm9vbXMgYHBsYXllcmAgc2l0cyBpbiB0aGF0IGhhdmUgbm90IGVuZGVkCnlldCwgaW4gdGhlIG9yZGVyIHRoZXkgd2VyZSBjcmVhdGVkIG9yIGpvaW5lZC4AAAAAABNnZXRfcm9vbXNfYnlfcGxheWVyAAAAAAIAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAGY3Vyc29yAAAAAAfQAAAABkN1cnNvcgAAAAAAAQAAB9AAAAAKUm9vbUlkUGFnZQAA",
+        "AAAAAAAAAKhDaGVjayBhIGNsYWltZWQgcHJlLWltYWdlIGFnYWluc3QgYG93bmVyYCdzIHN0b3JlZCBjb21taXRtZW50IHVzaW5nIHRoZQpyb29tJ3Mgc2NoZW1lLiBMZXRzIGNsaWVudHMgKGFuZCBmdXR1cmUgcHJvb2YgdmVyaWZpZXJzKSBjb25maXJtIGEKcmV2ZWFsIHdpdGhvdXQgc3VibWl0dGluZyBpdC4AAAARdmVyaWZ5X2NvbW1pdG1lbnQAAAAAAAAFAAAAAAAAAAdyb29tX2lkAAAAAAQAAAAAAAAABW93bmVyAAAAAAAAEwAAAAAAAAAJaXNsYW5kX2lkAAAAAAAABAAAAAAAAAAHdGlsZV9pZAAAAAAEAAAAAAAAAARzYWx0AAAD7gAAACAAAAABAAAAAQ==",
+        "AAAAAAAAAM1FeHBvcnQgdGhlIGZpbmFsIHJlc3VsdCBvZiBhbiBlbmRlZCByb29tIGFzIFhEUi1lbmNvZGVkIGBBdHRlc3RhdGlvbmAuCgpUaGUgZW5jb2RpbmcgaXMgZGV0ZXJtaW5pc3RpYywgc28gYm90aCBwbGF5ZXJzIChvciBhbnkgcmVsYXllcikgY2FuCnNpZ24gdGhlIHJldHVybmVkIGJ5dGVzIGFuZCBhIHZlcmlmaWVyIGNhbiBkZWNvZGUgYW5kIGNoZWNrIHRoZW0uAAAAAAAAEmV4cG9ydF9hdHRlc3RhdGlvbgAAAAAAAQAAAAAAAAAHcm9vbV9pZAAAAAAEAAAAAQAAAA4=",
+        "AAAAAAAAAL9TSEEtMjU2KCB4ZHIocm9vbSkg4oCWIGNvbW1pdG1lbnRfYSDigJYgY29tbWl0bWVudF9iICkuCgpDb3ZlcnMgcGxheWVycywgcG9pbnRzLCBib2FyZCBjb25maWcsIGRpZ3MsIHR1cm4gYW5kIGNvbW1pdG1lbnRzLCBzbwp0d28gcGFydGllcyBjYW4gYWdyZWUgb24gdGhlIGZ1bGwgcm9vbSBzdGF0ZSB3aXRoIG9uZSBjb21wYXJpc29uLgAAAAAOZ2V0X3N0YXRlX2hhc2gAAAAAAAEAAAAAAAAAB3Jvb21faWQAAAAABAAAAAEAAAPuAAAAIA==",
+        "AAAAAAAAADlUaGUgcnVsZXMgaGFzaCBtaXhlZCBpbnRvIHRoaXMgcm9vbSdzIGJ1cmlhbCBjb21taXRtZW50cy4AAAAAAAAOZ2V0X3J1bGVzX2hhc2gAAAAAAAEAAAAAAAAAB3Jvb21faWQAAAAABAAAAAEAAAPuAAAAIA==",
+        "AAAAAAAAAMZUaGUgbWVzc2FnZSB3aG9zZSBlZDI1NTE5IHNpZ25hdHVyZSwgaGFzaGVkIHdpdGggU0hBLTI1NiwgaXMgYQpwbGF5ZXIncyBzYWx0IGluIGEgYGRlcml2ZWRfc2FsdHNgIHJvb206CiJwaXJhdGUtc2FsdCIg4oCWIHJvb21faWQg4oCWIG5vbmNlLiBJdCBjaGFuZ2VzIHdpdGggZXZlcnkgcm9vbSwgc28gbm8KdHdvIHJvb21zIHNoYXJlIGEgc2FsdC4AAAAAABBnZXRfc2FsdF9tZXNzYWdlAAAAAQAAAAAAAAAHcm9vbV9pZAAAAAAEAAAAAQAAAA4=",
+        "AAAAAAAAADlFdmVyeXRoaW5nIGEgY2xpZW50IG5lZWRzIHRvIHJlZnJlc2ggYSByb29tLCBpbiBvbmUgcmVhZC4AAAAAAAANZ2V0X2Z1bGxfdmlldwAAAAAAAAEAAAAAAAAAB3Jvb21faWQAAAAABAAAAAEAAAfQAAAACEZ1bGxWaWV3",
+        "AAAAAAAAAGRTdGFydGVkIGFuZCBjb21wbGV0ZWQgZ2FtZSBjb3VudHMgZm9yIGBwbGF5ZXJgLCBmb3IgbG9iYmllcyB0aGF0CndhbnQgdG8gc3VyZmFjZSByZWxpYWJsZSBvcHBvbmVudHMuAAAADmdldF9yZXB1dGF0aW9uAAAAAAABAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAABAAAH0AAAAApSZXB1dGF0aW9uAAA=",
+        "AAAAAAAAAEFgcGxheWVyYCdzIEVsbyByYXRpbmcsIHVwZGF0ZWQgYWZ0ZXIgZXZlcnkgZGVjaWRlZCBvciBkcmF3biBnYW1lLgAAAAAAAApnZXRfcmF0aW5nAAAAAAABAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAABAAAABA==",
+        "AAAAAAAAAEBMaWZldGltZSByZXN1bHRzIG9mIGBwbGF5ZXJgOyB0aGV5IG91dGxpdmUgdGhlIHJvb21zIHRoZW1zZWx2ZXMuAAAAEGdldF9wbGF5ZXJfc3RhdHMAAAABAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAABAAAH0AAAAAtQbGF5ZXJTdGF0cwA=",
+        "AAAAAAAAAF5Db3VudCBhbmQgc2l6ZSB0aGUgdGVtcG9yYXJ5IGVudHJpZXMga2VwdCBmb3IgYSByb29tLCB0byBoZWxwCmVzdGltYXRlIGl0cyByZW50IGFuZCB0dW5lIFRUTHMuAAAAAAASZ2V0X3N0b3JhZ2VfcmVwb3J0AAAAAAABAAAAAAAAAAdyb29tX2lkAAAAAAQAAAABAAAH0AAAAA1TdG9yYWdlUmVwb3J0AAAA",
+        "AAAAAAAAApdDaGVjayBhIHJvb20ncyBpbnRlcm5hbCBjb25zaXN0ZW5jeSBhbmQgbmFtZSBldmVyeSBpbnZhcmlhbnQgaXQKYnJlYWtzOyBhbiBlbXB0eSBsaXN0IG1lYW5zIHRoZSByb29tIGlzIGNvaGVyZW50LiBGb3IgbW9uaXRvcmluZwphbmQgZm9yIHZlcmlmeWluZyBzdGF0ZSBhZnRlciBhbiB1cGdyYWRlLgoKLSBgcGhhc2VgOiBwaGFzZSBpcyBvdXRzaWRlIFdhaXRpbmcuLj1FbmRlZAotIGBhY3RpdmVgOiBgZ2FtZV9hY3RpdmVgIGRpc2FncmVlcyB3aXRoIHRoZSBwaGFzZQotIGBvcHBvbmVudGA6IG5vIGRpc3RpbmN0IFBsYXllciBCIGFmdGVyIFdhaXRpbmcKLSBgd2lubmVyYDogd2lubmVyIGlzIG5vdCBhIHBsYXllciBvbmNlIGVuZGVkLCBvciBzZXQgYmVmb3JlCi0gYGNvbW1pdF9hYCAvIGBjb21taXRfYmA6IGNvbW1pdG1lbnQgZmxhZyBhbmQgc3RvcmVkIGVudHJ5IGRpc2FncmVlLApvciBhIGhpZGVyJ3MgY29tbWl0bWVudCBpcyBtaXNzaW5nIG9uY2UgcGxheSBoYXMgYmVndW4KLSBgZGlnc2A6IGEgZGlnIGlzIG91dCBvZiBib3VuZHMsIHJlcGVhdGVkLCBtYWRlIG91dHNpZGUgcGxheSwgYnkgYQpzdHJhbmdlciwgb3IgcGFzdCB0aGUgc2Vla2VyJ3MgYnVkZ2V0Ci0gYGFjdGlvbnNgOiBtb3JlIGFjdGlvbiBwb2ludHMgbGVmdCB0aGFuIGEgdHVybiBncmFudHMAAAAACmF1ZGl0X3Jvb20AAAAAAAEAAAAAAAAAB3Jvb21faWQAAAAABAAAAAEAAAPqAAAAEQ==",
+        "AAAAAAAAAAAAAAAHdXBncmFkZQAAAAABAAAAAAAAAA1uZXdfd2FzbV9oYXNoAAAAAAAD7gAAACAAAAAA",
+        "AAAAAAAAARhCcmluZyBzdGF0ZSB3cml0dGVuIGJ5IGFuIGVhcmxpZXIgd2FzbSB1cCB0byBgU1RBVEVfVkVSU0lPTmA7IGNhbGwKaXQgcmlnaHQgYWZ0ZXIgYHVwZ3JhZGVgLiBSb29tcyBsaXZlIGluIHRlbXBvcmFyeSBzdG9yYWdlLCB3aGljaApjYW5ub3QgYmUgbGlzdGVkLCBzbyB0aGUgaWRzIG9mIHJvb21zIHRvIGNhcnJ5IG92ZXIgYXJlIHBhc3NlZCBpbgphbmQgbWF5IGJlIHNwbGl0IGFjcm9zcyBjYWxscy4gUm9vbXMgYWxyZWFkeSBpbiB0aGUgY3VycmVudCBsYXlvdXQKYXJlIGxlZnQgYWxvbmUuAAAAB21pZ3JhdGUAAAAAAQAAAAAAAAAIcm9vbV9pZHMAAAPqAAAABAAAAAA=",
+        "AAAAAAAAAHtXaGF0IGBtaWdyYXRlYCB3b3VsZCBkbyB3aXRoIGByb29tX2lkc2AsIHdpdGhvdXQgd3JpdGluZyBhbnl0aGluZywKc28gYW4gdXBncmFkZSBjYW4gYmUgcmVoZWFyc2VkIGFnYWluc3QgbGl2ZSBzdGF0ZSBmaXJzdC4AAAAAEXByZXZpZXdfbWlncmF0aW9uAAAAAAAAAQAAAAAAAAAIcm9vbV9pZHMAAAPqAAAABAAAAAEAAAfQAAAAEE1pZ3JhdGlvblByZXZpZXc=",
+        "AAAAAAAAADNMYXlvdXQgdmVyc2lvbiBvZiB0aGUgc3RvcmVkIHN0YXRlIChzZWUgYG1pZ3JhdGVgKS4AAAAAC2dldF92ZXJzaW9uAAAAAAAAAAABAAAABA==" ]),
       options
     )
   }
   public readonly fromJSON = {
-    dig: this.txFromJSON<null>,
-    get_hub: this.txFromJSON<string>,
-    set_hub: this.txFromJSON<null>,
-    upgrade: this.txFromJSON<null>,
-    get_game: this.txFromJSON<Room>,
-    get_room: this.txFromJSON<Room>,
     get_admin: this.txFromJSON<string>,
+    propose_admin: this.txFromJSON<null>,
+    accept_admin: this.txFromJSON<null>,
+    get_pending_admin: this.txFromJSON<Option<string>>,
+    get_hub: this.txFromJSON<Option<string>>,
+    set_hub: this.txFromJSON<null>,
+    approve_hub: this.txFromJSON<null>,
+    revoke_hub: this.txFromJSON<null>,
+    get_approved_hubs: this.txFromJSON<Array<string>>,
+    set_hub_kind: this.txFromJSON<null>,
+    get_hub_kind: this.txFromJSON<HubKind>,
+    get_leaderboard: this.txFromJSON<Option<string>>,
+    set_leaderboard: this.txFromJSON<null>,
+    get_config: this.txFromJSON<Config>,
+    set_config: this.txFromJSON<null>,
+    set_admin_backup: this.txFromJSON<null>,
+    get_admin_backup: this.txFromJSON<Option<AdminBackup>>,
+    claim_admin: this.txFromJSON<null>,
+    set_room_hubless: this.txFromJSON<null>,
+    set_room_frozen: this.txFromJSON<null>,
+    pause: this.txFromJSON<null>,
+    unpause: this.txFromJSON<null>,
+    is_paused: this.txFromJSON<boolean>,
+    set_fee_bps: this.txFromJSON<null>,
+    get_fee_bps: this.txFromJSON<u32>,
+    set_treasury: this.txFromJSON<null>,
+    get_treasury: this.txFromJSON<Option<string>>,
+    get_fees: this.txFromJSON<i128>,
+    withdraw_fees: this.txFromJSON<null>,
+    create_room: this.txFromJSON<Room>,
+    create_room_auto: this.txFromJSON<u32>,
+    create_room_with_config: this.txFromJSON<Room>,
+    create_rooms_batch: this.txFromJSON<Array<u32>>,
     join_room: this.txFromJSON<Room>,
-    set_admin: this.txFromJSON<null>,
+    join_private_room: this.txFromJSON<Room>,
+    cancel_room: this.txFromJSON<null>,
+    rematch: this.txFromJSON<Room>,
     start_room: this.txFromJSON<Room>,
-    create_room: this.txFromJSON<Room>,
+    register_bls_key: this.txFromJSON<null>,
+    register_burial_key: this.txFromJSON<null>,
+    get_start_message: this.txFromJSON<Buffer>,
+    start_room_aggregated: this.txFromJSON<Room>,
     bury_treasure: this.txFromJSON<null>,
+    bury_treasure_signed: this.txFromJSON<null>,
+    rebury_treasure: this.txFromJSON<null>,
+    dig: this.txFromJSON<null>,
+    end_turn: this.txFromJSON<null>,
+    rollback_dig: this.txFromJSON<null>,
+    commit_dig: this.txFromJSON<null>,
+    reveal_dig: this.txFromJSON<null>,
+    commit_round_dig: this.txFromJSON<null>,
+    reveal_round_dig: this.txFromJSON<null>,
+    get_round_state: this.txFromJSON<RoundState>,
+    claim_hider_win: this.txFromJSON<null>,
+    open_treasures: this.txFromJSON<null>,
+    finalize_draw: this.txFromJSON<null>,
+    claim_timeout: this.txFromJSON<null>,
+    forfeit: this.txFromJSON<null>,
+    claim_abandoned: this.txFromJSON<null>,
+    abort_room: this.txFromJSON<null>,
+    retry_hub_report: this.txFromJSON<null>,
+    get_pending_hub_report: this.txFromJSON<Option<PendingHubReport>>,
     reveal_treasure: this.txFromJSON<null>,
+    reveal_treasure_proof: this.txFromJSON<null>,
+    reveal_treasure_derived: this.txFromJSON<null>,
+    tip: this.txFromJSON<null>,
+    report_game: this.txFromJSON<null>,
+    enter_queue: this.txFromJSON<Option<u32>>,
+    leave_queue: this.txFromJSON<null>,
+    get_queue: this.txFromJSON<Option<QueueEntry>>,
+    challenge: this.txFromJSON<null>,
+    accept_challenge: this.txFromJSON<Room>,
+    decline_challenge: this.txFromJSON<null>,
+    get_challenge: this.txFromJSON<Option<i128>>,
+    create_series: this.txFromJSON<Series>,
+    create_series_room: this.txFromJSON<Room>,
+    get_series: this.txFromJSON<Series>,
+    place_bet: this.txFromJSON<null>,
+    claim_bet: this.txFromJSON<i128>,
+    get_bet_book: this.txFromJSON<BetBook>,
+    watch_room: this.txFromJSON<null>,
+    unwatch_room: this.txFromJSON<null>,
+    get_observers: this.txFromJSON<Array<string>>,
+    get_room: this.txFromJSON<Room>,
+    get_game: this.txFromJSON<Room>,
+    get_tips: this.txFromJSON<TipPage>,
+    get_burial_ledgers: this.txFromJSON<BurialLedgers>,
+    get_commitments: this.txFromJSON<PublishedCommitments>,
+    get_reports: this.txFromJSON<ReportPage>,
+    get_digs: this.txFromJSON<DigPage>,
+    get_recent_events: this.txFromJSON<Array<RoomEvent>>,
+    get_open_rooms: this.txFromJSON<RoomPage>,
+    get_rooms_by_player: this.txFromJSON<RoomIdPage>,
+    verify_commitment: this.txFromJSON<boolean>,
+    export_attestation: this.txFromJSON<Buffer>,
+    get_state_hash: this.txFromJSON<Buffer>,
+    get_rules_hash: this.txFromJSON<Buffer>,
+    get_salt_message: this.txFromJSON<Buffer>,
+    get_full_view: this.txFromJSON<FullView>,
+    get_reputation: this.txFromJSON<Reputation>,
+    get_rating: this.txFromJSON<u32>,
+    get_player_stats: this.txFromJSON<PlayerStats>,
+    get_storage_report: this.txFromJSON<StorageReport>,
+    audit_room: this.txFromJSON<Array<string>>,
+    upgrade: this.txFromJSON<null>,
+    migrate: this.txFromJSON<null>,
+    preview_migration: this.txFromJSON<MigrationPreview>,
+    get_version: this.txFromJSON<u32>,
   }
 }
\ No newline at end of file
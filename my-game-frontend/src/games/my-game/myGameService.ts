@@ -70,6 +70,16 @@ export class MyGameService {
     }
   }
 
+  /**
+   * Get the room's rules hash, which every burial commitment binds
+   * (see zkUtils.generateCommitment).
+   */
+  async getRulesHash(roomId: number): Promise<Buffer> {
+    const tx = await this.baseClient.get_rules_hash({ room_id: roomId });
+    const result = await tx.simulate();
+    return Buffer.from(result.result);
+  }
+
   /** Alias kept for compatibility with code that references getGame */
   async getGame(sessionId: number): Promise<Room | null> {
     return this.getRoom(sessionId);
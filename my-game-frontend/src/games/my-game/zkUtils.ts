@@ -19,9 +19,11 @@
  * Generate a SHA-256 commitment for treasure burial.
  * This MUST match the contract's compute_commitment exactly:
  * 
- *   SHA-256( room_id (4 BE bytes) ‖ island_id (4 BE bytes) ‖ tile_id (4 BE bytes) ‖ salt (32 bytes) )
+ *   SHA-256( room_id (4 BE bytes) ‖ nonce (32 bytes) ‖ rules_hash (32 bytes) ‖ island_id (4 BE bytes) ‖ tile_id (4 BE bytes) ‖ salt (32 bytes) )
  * 
  * @param roomId - Room identifier
+ * @param nonce - The room's contract-generated nonce (`Room.nonce`, 32 bytes)
+ * @param rulesHash - The room's rules hash, as returned by `get_rules_hash` (32 bytes)
  * @param islandId - Island index (0-2)
  * @param tileId - Tile index (0-29)
  * @param _ownerHash - Unused (kept for interface compatibility)
@@ -30,6 +32,8 @@
  */
 export async function generateCommitment(
   roomId: number,
+  nonce: Uint8Array,   // Room.nonce, drawn by the contract when the room starts
+  rulesHash: Uint8Array, // get_rules_hash(room_id): the room's config and board
   islandId: number,
   tileId: number,
   _ownerHash: string,  // kept for interface compat but not used in on-chain scheme
@@ -39,14 +43,16 @@ export async function generateCommitment(
     throw new Error('Browser-only crypto');
   }
   // Must match the contract's compute_commitment:
-  //   SHA-256( room_id (4 BE bytes) ‖ island_id (4 BE bytes) ‖ tile_id (4 BE bytes) ‖ salt (32 bytes) )
-  const buf = new Uint8Array(4 + 4 + 4 + 32);
+  //   SHA-256( room_id (4 BE bytes) ‖ nonce (32 bytes) ‖ rules_hash (32 bytes) ‖ island_id (4 BE bytes) ‖ tile_id (4 BE bytes) ‖ salt (32 bytes) )
+  const buf = new Uint8Array(4 + 32 + 32 + 4 + 4 + 32);
   const view = new DataView(buf.buffer as ArrayBuffer);
   view.setUint32(0, roomId, false);    // big-endian
-  view.setUint32(4, islandId, false);
-  view.setUint32(8, tileId, false);
+  buf.set(nonce, 4);
+  buf.set(rulesHash, 36);
+  view.setUint32(68, islandId, false);
+  view.setUint32(72, tileId, false);
   const saltBytes = hexToBytes(salt);
-  buf.set(saltBytes, 12);
+  buf.set(saltBytes, 76);
   const hashBuffer = await crypto.subtle.digest('SHA-256', buf.buffer as ArrayBuffer);
   return bytesToHex(new Uint8Array(hashBuffer));
 }
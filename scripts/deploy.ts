@@ -267,9 +267,14 @@ for (const contract of contracts) {
 
   console.log(`Deploying ${contract.packageName}...`);
   console.log(`  Uploading and deploying ${contract.wasmPath}...`);
+  // The hub, leaderboard and tournament take only an admin; the admin wires
+  // them to the game afterwards (register_game / set_game).
+  const ctorArgs = ["game-hub", "leaderboard", "tournament"].includes(contract.packageName)
+    ? ["--admin", adminAddress]
+    : ["--admin", adminAddress, "--game-hub", mockGameHubId];
   try {
     const contractId = (await runWithRetry(() =>
-      $`stellar contract deploy --wasm ${contract.wasmPath} --source-account ${adminSecret} --network ${NETWORK} -- --admin ${adminAddress} --game-hub ${mockGameHubId}`.text()
+      $`stellar contract deploy --wasm ${contract.wasmPath} --source-account ${adminSecret} --network ${NETWORK} -- ${ctorArgs}`.text()
     )).trim();
     deployed[contract.packageName] = contractId;
     console.log(`✅ ${contract.packageName} deployed: ${contractId}\n`);